@@ -0,0 +1,151 @@
+//! Leaderboard benchmarks: sorting the balance maps per request, as the bot did
+//! before, against the maintained ranking index.
+//!
+//! Run with `cargo bench --bench ranking`. The user count defaults to 1,000,000 and
+//! can be changed with the `RANKING_BENCH_USERS` environment variable.
+
+use andy_coin::ranking::RankingIndex;
+use dashmap::DashMap;
+use poise::serenity_prelude as serenity;
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const GUILDS: u64 = 4;
+const PAGE_SIZE: usize = 10;
+
+/// Balances spread over a few guilds, with plenty of ties
+fn balances(users: u64) -> DashMap<serenity::GuildId, DashMap<serenity::UserId, u32>> {
+    let guilds = DashMap::new();
+    let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+    for user in 1..=users {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let guild = serenity::GuildId::new(1 + seed % GUILDS);
+        let balance = u32::try_from(seed % 100_000).unwrap_or_default();
+        guilds
+            .entry(guild)
+            .or_insert_with(DashMap::new)
+            .insert(serenity::UserId::new(user), balance);
+    }
+    guilds
+}
+
+/// Time `iterations` runs of `f` and print the average
+fn bench<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let average = start.elapsed() / iterations;
+    println!("{name:<40} {average:>12.2?}");
+    average
+}
+
+/// The previous guild leaderboard: collect and sort the whole guild map
+fn sorted_guild(guild: &DashMap<serenity::UserId, u32>) -> Vec<(serenity::UserId, u32)> {
+    let mut users: Vec<_> = guild
+        .iter()
+        .map(|entry| (*entry.key(), *entry.value()))
+        .collect();
+    users.sort_by_key(|&(user_id, balance)| (Reverse(balance), user_id));
+    users
+}
+
+/// The previous global leaderboard: total every guild, then sort
+fn sorted_global(
+    guilds: &DashMap<serenity::GuildId, DashMap<serenity::UserId, u32>>,
+) -> Vec<(serenity::UserId, u32)> {
+    let mut totals: HashMap<serenity::UserId, u32> = HashMap::new();
+    for guild in guilds {
+        for user in guild.value() {
+            let total = totals.entry(*user.key()).or_default();
+            *total = total.saturating_add(*user.value());
+        }
+    }
+    let mut users: Vec<_> = totals.into_iter().collect();
+    users.sort_by_key(|&(user_id, balance)| (Reverse(balance), user_id));
+    users
+}
+
+fn main() {
+    let users = std::env::var("RANKING_BENCH_USERS")
+        .ok()
+        .and_then(|users| users.parse().ok())
+        .unwrap_or(1_000_000);
+    let guilds = balances(users);
+    let guild_id = serenity::GuildId::new(1);
+    let guild = guilds.get(&guild_id).expect("guild 1 has users");
+    let probe = *guild
+        .iter()
+        .nth(guild.len() / 2)
+        .expect("guild has users")
+        .key();
+
+    println!("{users} users in {GUILDS} guilds");
+    let build = Instant::now();
+    let mut index = RankingIndex::new();
+    for entry in &guilds {
+        for user in entry.value() {
+            index.update(*entry.key(), *user.key(), None, Some(*user.value()));
+        }
+    }
+    println!("{:<40} {:>12.2?}", "build index", build.elapsed());
+
+    let sorted_page = bench("sort guild, top page", 5, || {
+        sorted_guild(&guild).into_iter().take(PAGE_SIZE).count()
+    });
+    let index_page = bench("index guild, top page", 10_000, || {
+        index
+            .board(Some(guild_id))
+            .map(|board| board.page(0, PAGE_SIZE))
+    });
+    bench("sort guild, last page", 5, || {
+        let users = sorted_guild(&guild);
+        users.len().saturating_sub(PAGE_SIZE)
+    });
+    bench("index guild, last page", 10_000, || {
+        index
+            .board(Some(guild_id))
+            .map(|board| board.page(board.len().saturating_sub(PAGE_SIZE), PAGE_SIZE))
+    });
+
+    let sorted_rank = bench("sort guild, rank lookup", 5, || {
+        sorted_guild(&guild)
+            .iter()
+            .position(|&(user_id, _)| user_id == probe)
+    });
+    let index_rank = bench("index guild, rank lookup", 10_000, || {
+        index
+            .board(Some(guild_id))
+            .and_then(|board| board.rank_info(probe))
+    });
+
+    let sorted_global_page = bench("sort global, top page", 3, || {
+        sorted_global(&guilds).into_iter().take(PAGE_SIZE).count()
+    });
+    let index_global_page = bench("index global, top page", 10_000, || {
+        index.board(None).map(|board| board.page(0, PAGE_SIZE))
+    });
+
+    let mut balance = guild.get(&probe).map_or(0, |balance| *balance);
+    bench("index balance update", 10_000, || {
+        let previous = balance;
+        balance = (balance + 7_919) % 100_000;
+        index.update(guild_id, probe, Some(previous), Some(balance));
+    });
+
+    println!();
+    for (name, sorted, indexed) in [
+        ("guild top page", sorted_page, index_page),
+        ("guild rank lookup", sorted_rank, index_rank),
+        ("global top page", sorted_global_page, index_global_page),
+    ] {
+        let speedup = sorted.as_secs_f64() / indexed.as_secs_f64().max(f64::EPSILON);
+        println!("{name:<40} {speedup:>11.0}x faster");
+    }
+}
@@ -0,0 +1,687 @@
+# AndyCoin bot responses in German.
+
+## Shared
+
+none = keine
+unknown = Unbekannt
+no-limit = kein Limit
+unknown-user = Nutzer { $id }
+error-server-only = Dieser Befehl kann nur auf einem Server verwendet werden!
+error-member-info = Deine Mitgliedsinformationen konnten nicht abgerufen werden.
+error-prefix = Fehler: { $error }
+save-failed = Warnung: Die Einstellungen konnten nicht gespeichert werden: { $error }
+
+## Permissions
+
+access-member = Servermitglied
+access-giver = Geber
+access-economy-admin = Wirtschaftsadmin
+access-administrator = Serveradministrator
+access-guild-owner = Servereigentümer
+access-bot-owner = Bot-Eigentümer
+access-required = Du musst { $level } sein, um diesen Befehl zu verwenden.
+
+economy-role-giver = Geber
+economy-role-admin = Wirtschaftsadmin
+
+## Minting limits
+
+budget-period-daily = tägliches
+budget-period-weekly = wöchentliches
+mint-exceeds-max = Du kannst höchstens { $max } auf einmal vergeben.
+mint-budget-exhausted = Damit würdest du dein { $period } Budget überschreiten. Du kannst noch { $remaining } vergeben.
+
+## /give
+
+give-no-permission = Du darfst keine { $currency } vergeben! Nur der Servereigentümer, Wirtschaftsadmins, freigegebene Nutzer und Nutzer mit einer Geberrolle können das.
+give-success = { $amount } an { $user } vergeben. Der neue Kontostand auf diesem Server beträgt { $balance }.
+give-remaining-budget = Dein { $period } Budget: noch { $remaining } übrig.
+
+## /balance
+
+balance-self-server = Du hast { $amount } auf diesem Server.
+balance-self-global = Du hast { $amount } auf allen Servern.
+balance-other-server = { $user } hat { $amount } auf diesem Server.
+balance-other-global = { $user } hat { $amount } auf allen Servern.
+
+## /leaderboard
+
+leaderboard-empty = Noch niemand hat { $currency }!
+leaderboard-empty-period = In diesem Zeitraum wurden noch keine { $currency } bewegt!
+leaderboard-gain = { $net } (verdient { $earned }, erhalten { $received })
+leaderboard-heading-gains = Nettogewinne { $period ->
+        [week] der letzten 7 Tage
+        [month] der letzten 30 Tage
+       *[other] seit Beginn der Aufzeichnung
+    }
+leaderboard-heading-givers = Top-Geber { $period ->
+        [week] der letzten 7 Tage
+        [month] der letzten 30 Tage
+       *[other] seit Beginn der Aufzeichnung
+    }
+leaderboard-title-server = { $currency }-Rangliste des Servers
+leaderboard-title-global = Globale { $currency }-Rangliste
+leaderboard-heading-since = Änderungen seit dem letzten Beitrag { $since }
+leaderboard-footer = Seite { $page }/{ $pages } · { $rank ->
+        [0] Du bist noch nicht platziert
+       *[other] Dein Rang: #{ $rank }
+    }
+button-prev = ◀ Zurück
+button-next = Weiter ▶
+
+## /rank
+
+rank-position = { $user } steht auf Platz #{ $rank } von { $users } mit { $amount } (vor { $percentile }% der Nutzer).
+rank-next = Noch { $amount } bis Platz #{ $rank }.
+rank-top = Das ist der erste Platz!
+rank-none = { $user } steht noch nicht in der Rangliste.
+
+## /seasons
+
+seasons-title = Ruhmeshalle
+seasons-current = Saison { $number } läuft und endet { $end }.
+seasons-current-open = Saison { $number } läuft, ohne geplantes Ende.
+seasons-off = Auf diesem Server laufen keine Saisons.
+seasons-none = Es ist noch keine Saison zu Ende gegangen.
+seasons-name = Saison { $number }
+seasons-no-champions = keine Platzierten
+seasons-ended-title = Saison { $number } ist vorbei!
+seasons-awarded = { $role } geht an { $users }.
+seasons-next = Saison { $number } hat begonnen und endet { $end }.
+seasons-next-open = Saison { $number } hat begonnen.
+
+## /vote
+
+vote-error-already-active = Auf diesem Server läuft bereits ein Vorschlag dieser Art
+vote-error-cooldown = Vor Kurzem wurde ein Vorschlag dieser Art beendet. Bitte warte, bis die Sperrzeit abgelaufen ist
+vote-error-not-active = Auf diesem Server läuft keine Abstimmung
+vote-error-ended = Die Abstimmung ist beendet
+vote-error-unknown-proposal = Es gibt keinen offenen Vorschlag mit dieser Nummer
+vote-error-choose-proposal = Es sind mehrere Vorschläge offen. Wähle einen mit der Option `proposal`
+vote-error-account-too-new = Dein Discord-Konto muss mindestens { $days } Tage alt sein, um über diesen Vorschlag abzustimmen
+vote-error-joined-too-recently = Du musst seit mindestens { $days } Tagen auf diesem Server sein, um über diesen Vorschlag abzustimmen
+vote-error-missing-role = Du brauchst die Rolle { $role }, um über diesen Vorschlag abzustimmen
+vote-error-balance-too-low = Du brauchst ein Guthaben von mindestens { $min_balance }, um über diesen Vorschlag abzustimmen
+vote-cast-yes = Du hast bei Vorschlag #{ $id } mit JA gestimmt.
+vote-cast-no = Du hast bei Vorschlag #{ $id } mit NEIN gestimmt.
+vote-status-cooldown = Keine laufende Abstimmung. Vor Kurzem wurde eine Abstimmung zum Zurücksetzen beendet. Die nächste kann ab { $cooldown_end } gestartet werden.
+vote-status-none = Keine laufende Abstimmung. Starte mit `/vote start` oder `/propose` eine neue.
+vote-history = 📜 **Letzte Abstimmungen**
+vote-history-none = Auf diesem Server wurde noch keine Abstimmung abgeschlossen.
+vote-history-vote = **#{ $id }** { $action } ({ $date }): { $result ->
+        [passed] ✅ angenommen
+        [cancelled] 🚫 abgebrochen
+       *[failed] ❌ abgelehnt
+    } mit { $yes } Ja / { $no } Nein
+vote-history-resets = 📜 **Bisherige Zurücksetzungen**
+vote-history-entry = **{ $name }** (Vorschlag #{ $id }, { $date }): { $mode }; { $total } bei { $users } Mitgliedern, { $state ->
+        [restored] wiederhergestellt
+        [restorable] wiederherstellbar bis { $until }
+       *[final] endgültig
+    }
+vote-history-treasury = 🏦 Schatzkammer: { $amount }
+vote-stats = 📊 **Abstimmungsstatistik**
+    { $votes } Abstimmungen: { $passed } angenommen, { $failed } abgelehnt, { $cancelled } abgebrochen
+    Annahmequote: { $pass_rate } %
+    Durchschnittliche Beteiligung: { $turnout } Stimmen pro Abstimmung
+vote-stats-voters = **Aktivste Abstimmende**
+vote-stats-voter = { $rank }. { $user }: { $ballots ->
+        [one] { $ballots } Stimme
+       *[other] { $ballots } Stimmen
+    }
+vote-restore-done = ♻️ **{ $name }** wiederhergestellt: { $users } Mitglieder haben ihr Guthaben von vor dem Zurücksetzen zurück.
+vote-cancelled = 🚫 Vorschlag #{ $id } wurde von { $admin } abgebrochen. Es ändert sich nichts.
+vote-extended = ⏳ Vorschlag #{ $id } wurde um { $minutes } Minuten verlängert und endet jetzt um { $end_time }.
+vote-extend-invalid = Verlängere eine Abstimmung um mindestens 1 Minute.
+vote-ended-now = ⏹️ Vorschlag #{ $id } wurde von { $admin } vorzeitig beendet.
+restore-error-no-snapshot = Auf diesem Server gibt es keine Zurücksetzung zum Wiederherstellen
+restore-error-unknown-snapshot = Es gibt keine Zurücksetzung mit diesem Namen. Siehe `/vote history`
+restore-error-already-restored = Diese Zurücksetzung wurde bereits wiederhergestellt
+restore-error-grace-expired = Diese Zurücksetzung kann nicht mehr wiederhergestellt werden
+vote-status-ended = Die Abstimmung ist beendet. Das Ergebnis wird gleich im Kanal der Abstimmung veröffentlicht.
+vote-status-list = 🗳️ **Offene Vorschläge**
+vote-status-active =
+    🗳️ **Status von Vorschlag #{ $id }**
+    { $action }
+    Gestartet von: { $initiator }
+    Endzeit: { $end_time }
+    Stimmen: { $yes } JA / { $no } NEIN (Gesamt: { $total })
+    Aktueller JA-Anteil: { $percentage }%
+    Benötigt: Mindestens { $min_votes } Stimmen mit { $majority }% Mehrheit
+    Status: { $outlook ->
+        [pass] Würde mit den aktuellen Stimmen ANGENOMMEN
+        [fail] Würde mit den aktuellen Stimmen ABGELEHNT
+       *[not-enough] Noch nicht genug Stimmen
+    }
+vote-status-active-secret =
+    🗳️ **Status von Vorschlag #{ $id }** (geheime Abstimmung)
+    { $action }
+    Gestartet von: { $initiator }
+    Endzeit: { $end_time }
+    Abgegebene Stimmen: { $ballots }
+    Benötigt: Mindestens { $min_votes } Stimmen mit { $majority }% Mehrheit
+    Das Ergebnis bleibt bis zum Ende der Abstimmung verborgen.
+vote-config-invalid-majority = Die Mehrheit kann nicht größer als 100% sein.
+vote-config-too-large = { $field } kann nicht größer als { $max } sein.
+vote-config-invalid-grace = Die Wiederherstellungsfrist kann nicht größer als { $max } Stunden sein.
+vote-config-updated =
+    ✅ **Abstimmungseinstellungen aktualisiert** ({ $kind })
+    Sperrzeit zwischen Abstimmungen: { $cooldown_hours } Stunden
+    Dauer der Abstimmung: { $duration_minutes } Minuten
+    Mindestanzahl an Stimmen: { $min_votes }
+    Benötigte Mehrheit: { $majority }%
+    Wiederherstellung nach Zurücksetzen: { $restore_grace_hours } Stunden
+    Abgebrochene Abstimmungen starten die Sperrzeit: { $cancel_cooldown ->
+        [yes] ja
+       *[no] nein
+    }
+    Geheime Abstimmung: { $secret ->
+        [yes] ja
+       *[no] nein
+    }
+vote-eligibility-updated =
+    ✅ **Abstimmungsregeln aktualisiert** ({ $kind })
+    Mindestalter des Kontos: { $account_age_days } Tage
+    Mindestzeit auf dem Server: { $member_days } Tage
+    Benötigte Rolle: { $role }
+    Mindestguthaben: { $min_balance }
+    Gewichtung: { $weighting }
+vote-weighting = { $mode ->
+        [balance] nach Guthaben
+        [quadratic] quadratisch
+       *[one-person] eine Stimme pro Person
+    }
+
+## /propose
+
+proposal-kind = { $kind ->
+        [setting] Abstimmungseinstellung
+        [grant] Zuteilung
+        [giver-role] Geberrolle
+        [poll] Umfrage
+       *[reset] Zurücksetzen
+    }
+vote-field = { $field ->
+        [cooldown-hours] Sperrzeit in Stunden
+        [duration-minutes] Dauer in Minuten
+        [min-votes] Mindeststimmen
+       *[majority] Mehrheit in Prozent
+    }
+proposal-reset = Alle { $plural } auf diesem Server auf 0 setzen
+proposal-setting = { $field } für Vorschläge der Art „{ $kind }“ auf { $value } setzen
+proposal-grant = { $user } { $amount } geben
+proposal-giver-role-add = { $role } als Geberrolle hinzufügen
+proposal-giver-role-remove = { $role } aus den Geberrollen entfernen
+proposal-poll = Umfrage: { $question }
+proposal-partial-reset = Guthaben auf diesem Server zurücksetzen: { $mode }
+reset-mode-zero = alle { $plural } auf 0 setzen
+reset-mode-scale = { $percent }% jedes Guthabens behalten
+reset-mode-cap = Guthaben auf { $max } begrenzen
+reset-mode-redistribute = alle { $plural } gleichmäßig verteilen
+reset-mode-tax = { $percent }% jedes Guthabens in die Schatzkammer verschieben
+reset-mode-invalid = Skalieren und Steuer brauchen einen `amount` von 0 bis 100, die Obergrenze braucht einen `amount`.
+reset-mode-updated = ✅ Zurücksetzungen werden jetzt: { $mode }.
+quorum-votes = mindestens { $min_votes } Stimmen
+quorum-active = { $percent }% der Mitglieder, die in den letzten { $days } Tagen aktiv waren
+vote-quorum-invalid = Ein Quorum aktiver Mitglieder braucht `percent` von 1 bis 100 und `days` von 1 bis { $max_days }.
+vote-quorum-updated = ✅ Abstimmungen ({ $kind }) brauchen jetzt { $quorum }. Bei { $active } aktiven Mitgliedern sind das { $required } Stimmen.
+vote-status-quorum = Quorum: { $percent }% von { $active } Mitgliedern, die in den letzten { $days } Tagen aktiv waren
+vote-reminder = ⏰ Vorschlag #{ $id } endet { $end_time }: { $action }. Stimme mit den Knöpfen an der Abstimmungsnachricht oder mit `/vote yes` und `/vote no` ab.
+vote-reminder-dm = ⏰ Vorschlag #{ $id } endet { $end_time } und du hast noch nicht abgestimmt: { $action } { $link }
+vote-remind-on = 🔔 Du bekommst kurz vor dem Ende von Abstimmungen auf diesem Server eine DM, falls du noch nicht abgestimmt hast.
+vote-remind-off = 🔕 Du bekommst keine Abstimmungserinnerungen von diesem Server mehr.
+vote-reminder-updated = ✅ Abstimmungen ({ $kind }) senden { $minutes } Minuten vor dem Ende eine Erinnerung. Erwähnte Rolle: { $role }
+vote-reminder-off = ✅ Abstimmungen ({ $kind }) senden keine Erinnerungen.
+proposal-grant-invalid = Der Betrag muss mindestens 1 sein.
+poll-question-invalid = Umfragefragen müssen 1 bis { $max } Zeichen lang sein.
+vote-message =
+    🗳️ **Vorschlag #{ $id }** von { $initiator }
+    { $action }
+    Voraussetzungen: Mindestens { $min_votes } Stimmen mit { $majority }% Mehrheit.
+vote-message-tally = Stimmen: { $yes } ja / { $no } nein · Endet { $end_time }
+vote-message-secret-tally = 🔒 Geheime Abstimmung · Abgegebene Stimmen: { $ballots } · Endet { $end_time }
+vote-message-hint = Stimme mit den Buttons unten ab oder mit `/vote yes` oder `/vote no` und `proposal: { $id }`.
+vote-message-weighting = Stimmen werden gewichtet: { $weighting }.
+vote-weighted-tally = Gewichtet ({ $weighting }): { $yes } ja / { $no } nein
+vote-message-result-passed = ✅ **ANGENOMMEN** mit { $yes } ja / { $no } nein.
+vote-message-result-failed = ❌ **ABGELEHNT** mit { $yes } ja / { $no } nein.
+vote-message-result-poll = 🏁 **Geschlossen** mit { $yes } ja / { $no } nein.
+vote-message-cancelled = 🚫 Von einem Admin **abgebrochen**.
+button-yes = Ja
+button-no = Nein
+proposal-reset-warning = ⚠️ Wenn die Abstimmung angenommen wird, werden alle { $plural } auf diesem Server auf 0 gesetzt!
+proposal-summary = #{ $id }: { $action } (endet { $end_time }, { $yes } ja / { $no } nein)
+proposal-summary-secret = #{ $id }: { $action } (endet { $end_time }, geheime Abstimmung, abgegebene Stimmen: { $ballots })
+proposal-result-passed = 🗳️ Vorschlag #{ $id } ist beendet und wurde ANGENOMMEN ({ $yes } ja / { $no } nein): { $action }
+proposal-result-failed = 🗳️ Vorschlag #{ $id } ist beendet und wurde ABGELEHNT ({ $yes } ja / { $no } nein): { $action }. Zu wenige Stimmen oder keine Mehrheit.
+proposal-result-poll = 🗳️ Umfrage #{ $id } ist geschlossen: { $question } ({ $yes } ja / { $no } nein)
+
+## /config_admin role and /config_admin user
+
+role-added = { $role } wurde als { $kind }-Rolle hinzugefügt.
+role-already-added = { $role } ist bereits eine { $kind }-Rolle.
+role-removed = { $role } wurde aus den { $kind }-Rollen entfernt.
+role-not-added = { $role } ist keine { $kind }-Rolle.
+role-list-title = Wirtschaftsrollen
+role-list-owner-note = Der Servereigentümer kann immer { $plural } vergeben.
+user-access-allowed = { $user } kann jetzt unabhängig von den eigenen Rollen { $plural } vergeben.
+user-access-denied = { $user } kann unabhängig von den eigenen Rollen keine { $plural } mehr vergeben.
+user-access-cleared = Die Ausnahme für { $user } wurde entfernt. Die Rollen entscheiden, ob { $plural } vergeben werden können.
+
+## /config show
+
+config-help = Verwende einen der Unterbefehle: show, roles, rewards. Einstellungen änderst du mit /config_admin
+config-title = AndyCoin-Konfiguration
+config-field-currency = Währung
+config-field-language = Sprache
+config-field-roles = Rollen und Nutzer
+config-field-limits = Vergabelimits
+config-field-vote-settings = Abstimmungseinstellungen
+config-field-vote-status = Abstimmungsstatus
+config-roles =
+    Wirtschaftsadmins: { $admins }
+    Geber: { $givers }
+    Freigegebene Nutzer: { $allowed }
+    Gesperrte Nutzer: { $denied }
+config-limits-default = Standard: höchstens { $max_per_give } pro Vergabe, { $period } Budget { $budget }
+config-limits-role = { $role }: höchstens { $max_per_give } pro Vergabe, { $period } Budget { $budget }
+config-vote-settings =
+    Sperrzeit: { $cooldown_hours } Stunden
+    Dauer: { $duration_minutes } Minuten
+    Mindeststimmen: { $min_votes }
+    Mehrheit: { $majority }%
+config-vote-cooldown = Gesperrt bis { $cooldown_end }
+config-vote-idle = Keine laufende Abstimmung
+config-currency =
+    { $name } / { $plural }
+    Symbol: { $symbol }
+    Emoji: { $emoji }
+config-language-auto = Die Discord-Sprache des Nutzers, sonst Englisch
+
+## /config_admin export and /config_admin import
+
+config-exported = Hier sind die AndyCoin-Einstellungen dieses Servers. Mit `/config_admin import` kannst du sie auf einen anderen Server übertragen.
+import-too-large = Die Datei ist zu groß. Einstellungsdateien haben höchstens { $max_kib } KiB.
+import-not-yaml = Diese Datei ist keine YAML-Einstellungsdatei.
+import-invalid = Die Einstellungsdatei konnte nicht gelesen werden: { $error }
+import-success = ✅ AndyCoin-Einstellungen importiert. Prüfe sie mit `/config show`.
+import-missing-roles = ⚠️ { $count ->
+        [one] { $count } Rolle aus der Datei gibt es auf diesem Server nicht.
+       *[other] { $count } Rollen aus der Datei gibt es auf diesem Server nicht.
+    } Passe sie mit `/config_admin role`, `/config_admin budget` und `/config_admin reward` an.
+
+## /config_admin currency, /config_admin budget and /config_admin language
+
+currency-invalid = Währungsnamen, Symbole und Emojis müssen 1 bis { $max } Zeichen lang sein.
+currency-mention = Währungsnamen, Symbole und Emojis dürfen kein @ enthalten, damit sie niemanden anpingen.
+currency-updated = ✅ Währung aktualisiert. Beträge sehen jetzt so aus: { $one } / { $many } ({ $short })
+budget-scope-role = Rolle { $role }
+budget-scope-all = alle Geber
+budget-updated = ✅ **Vergabelimits aktualisiert** ({ $scope })
+language-name = { $code ->
+        [de] Deutsch
+        [es-ES] Spanisch
+        [pt-BR] Portugiesisch (Brasilien)
+       *[other] Englisch
+    }
+language-updated = ✅ Antworten sind jetzt auf { $language } für Nutzer, deren Discord-Sprache nicht unterstützt wird.
+language-cleared = ✅ Antworten sind jetzt auf Englisch für Nutzer, deren Discord-Sprache nicht unterstützt wird.
+
+## /config_admin leaderboard
+
+leaderboard-schedule-set = ✅ Die Rangliste wird in { $channel } nach `{ $spec }` (UTC) gepostet. Nächster Beitrag: { $next }
+leaderboard-schedule-show = Die Rangliste wird in { $channel } nach `{ $spec }` (UTC) gepostet. Nächster Beitrag: { $next }
+leaderboard-schedule-off = ✅ Geplante Ranglisten-Beiträge sind aus.
+leaderboard-schedule-none = Die Rangliste wird nicht nach Zeitplan gepostet. Lege einen mit einer Cron-Angabe wie `0 18 * * 5` (freitags um 18:00 UTC) oder `@weekly` fest.
+leaderboard-schedule-invalid = `{ $spec }` ist kein gültiger Zeitplan. Verwende fünf Felder, `Minute Stunde Tag Monat Wochentag`, wie `0 18 * * 5`, oder eines von @hourly, @daily, @weekly und @monthly.
+leaderboard-schedule-never = nie
+
+## /config_admin season
+
+season-updated = ✅ **Saisons aktualisiert**
+season-show = Saison { $number } läuft nach `{ $spec }` (UTC) und endet { $end }. Am Ende werden die Guthaben auf 0 gesetzt.
+season-show-award = Die besten { $winners } erhalten { $role }.
+season-show-channel = Der Endstand wird in { $channel } verkündet.
+season-off = ✅ Saisons sind aus. Vergangene Saisons bleiben in `/seasons`.
+season-none = Es laufen keine Saisons. Starte sie mit einem Zeitplan wie `0 0 1 * *` (am 1. jedes Monats) oder `@monthly`.
+season-needs-schedule = Saisons brauchen zuerst einen Zeitplan. Lege ihn mit der Option Zeitplan fest, z. B. `@monthly`.
+
+## /config_admin reward
+
+reward-added = ✅ Mitglieder mit mindestens { $amount } erhalten jetzt **{ $role }**.
+reward-sync-hint = Führe `/config_admin reward sync` aus, um sie Mitgliedern zu geben, die schon so viel haben.
+reward-removed = ✅ **{ $role }** ist keine Belohnungsrolle mehr. Wer sie hat, behält sie.
+reward-not-found = **{ $role }** ist keine Belohnungsrolle.
+reward-list-title = Belohnungsrollen
+reward-list-empty = Es sind keine Belohnungsrollen eingerichtet. Füge eine mit `/config_admin reward add` hinzu.
+reward-list-entry = { $role } ab { $amount } · { $remove ->
+        [yes] wird darunter entfernt
+       *[other] bleibt darunter erhalten
+    }
+reward-synced = ✅ { $members ->
+        [one] { $members } Mitglied
+       *[other] { $members } Mitglieder
+    } geprüft und { $changes ->
+        [one] { $changes } Rollenänderung
+       *[other] { $changes } Rollenänderungen
+    } eingeplant. Sie werden nach und nach vorgenommen.
+
+## /flip
+
+flip-side = { $side ->
+        [heads] Kopf
+       *[tails] Zahl
+    }
+flip-result = Die Münze zeigt **{ $side }**!
+flip-invalid-guess = Ungültiger Tipp! Bitte verwende „Kopf“ oder „Zahl“.
+flip-bet-server-only = Das Wettspiel kann nur auf einem Server gespielt werden!
+flip-bet-insufficient = Du brauchst mindestens { $amount }, um zu wetten!
+flip-bet-won = Die Münze zeigt **{ $side }**! Richtig geraten, du gewinnst { $amount }! Dein neuer Kontostand ist { $balance }.
+flip-bet-lost = Die Münze zeigt **{ $side }**! Falsch geraten, du verlierst { $amount }. Dein neuer Kontostand ist { $balance }.
+flip-guess-correct = Die Münze zeigt **{ $side }**! Richtig geraten!
+flip-guess-wrong = Die Münze zeigt **{ $side }**! Falsch geraten.
+
+## Slash command localizations
+
+cmd-give = geben
+    .description = AndyCoins an einen Nutzer vergeben (nur Geber)
+    .amount = betrag
+    .amount-description = Anzahl der zu vergebenden AndyCoins
+    .user = nutzer
+    .user-description = Nutzer, der die AndyCoins erhält
+cmd-balance = kontostand
+    .description = Deinen AndyCoin-Kontostand oder den eines anderen Nutzers anzeigen
+    .user = nutzer
+    .user-description = Nutzer, dessen Kontostand angezeigt wird (Standard: du)
+    .global = global
+    .global-description = Gesamtstand über alle Server anzeigen (Standard: nur dieser Server)
+cmd-leaderboard = rangliste
+    .description = Die AndyCoin-Rangliste anzeigen
+    .page = seite
+    .page-description = Anzuzeigende Seite (Standard: 1)
+    .global = global
+    .global-description = Globale Rangliste über alle Server anzeigen (Standard: nur dieser Server)
+    .period = zeitraum
+    .period-description = Gewinne der letzten Woche oder des letzten Monats anzeigen (Standard: gesamt)
+    .board = tafel
+    .board-description = Münzen oder Mitglieder nach verschenkten Münzen ranken (Standard: Münzen)
+cmd-rank = rang
+    .description = Deinen Rang oder den eines anderen Nutzers in der Rangliste anzeigen
+    .user = nutzer
+    .user-description = Nachzuschlagender Nutzer (Standard: du selbst)
+    .global = global
+    .global-description = Rang über alle Server (Standard: nur dieser Server)
+cmd-seasons = saisons
+    .description = Die aktuelle Saison und frühere Sieger anzeigen
+cmd-flip = münzwurf
+    .description = Eine Münze werfen
+    .guess = tipp
+    .guess-description = Kopf oder Zahl, wenn du raten willst
+    .bet = wette
+    .bet-description = Einen Coin auf deinen Tipp setzen
+cmd-vote = abstimmen
+    .description = Eine Abstimmung zum Zurücksetzen aller AndyCoins starten oder abstimmen
+cmd-vote-start = starten
+    .description = Eine Abstimmung zum Zurücksetzen aller AndyCoins auf diesem Server starten
+    .mode = modus
+    .mode-description = Wie sich Guthaben ändern, wenn die Abstimmung angenommen wird (Standard: Modus des Servers)
+    .amount = betrag
+    .amount-description = Prozentsatz zum Behalten oder Besteuern, oder die Obergrenze, für Modi, die einen brauchen
+cmd-vote-yes = ja
+    .description = Für einen offenen Vorschlag stimmen
+    .proposal = vorschlag
+    .proposal-description = Nummer des Vorschlags, über den abgestimmt wird (Standard: der einzige offene)
+cmd-vote-no = nein
+    .description = Gegen einen offenen Vorschlag stimmen
+    .proposal = vorschlag
+    .proposal-description = Nummer des Vorschlags, über den abgestimmt wird (Standard: der einzige offene)
+cmd-vote-status = status
+    .description = Den Status der aktuellen Abstimmung prüfen
+    .proposal = vorschlag
+    .proposal-description = Nummer des zu prüfenden Vorschlags (Standard: alle offenen)
+cmd-vote-history = verlauf
+    .description = Bisherige Abstimmungen und Zurücksetzungen anzeigen
+    .kind = art
+    .kind-description = Nur Vorschläge dieser Art anzeigen
+cmd-vote-stats = statistik
+    .description = Annahmequote, Beteiligung und die aktivsten Abstimmenden anzeigen
+cmd-vote-remind = erinnern
+    .description = Kurz vor dem Ende von Abstimmungen auf diesem Server eine DM bekommen
+    .enabled = aktiviert
+    .enabled-description = Ob du Erinnerungen für Abstimmungen bekommst, bei denen du noch nicht abgestimmt hast
+cmd-vote_admin = abstimmung_admin
+    .description = Abstimmungen verwalten
+cmd-vote_admin-config = einstellungen
+    .description = Abstimmungseinstellungen ändern
+    .cooldown_hours = sperrzeit_stunden
+    .cooldown_hours-description = Stunden zwischen Abstimmungen (Standard: 24)
+    .duration_minutes = dauer_minuten
+    .duration_minutes-description = Dauer der Abstimmung in Minuten (Standard: 30)
+    .min_votes = mindeststimmen
+    .min_votes-description = Mindestanzahl an Stimmen (Standard: 10)
+    .majority_percentage = mehrheit_prozent
+    .majority_percentage-description = Benötigter Anteil an JA-Stimmen in Prozent (Standard: 70)
+    .kind = art
+    .kind-description = Art der Vorschläge, die eingestellt wird (Standard: Zurücksetzen)
+    .restore_grace_hours = wiederherstellung_stunden
+    .restore_grace_hours-description = Stunden nach einem Zurücksetzen, in denen Admins es rückgängig machen können (Standard: 24)
+    .cancel_cooldown = abbruch_sperrzeit
+    .cancel_cooldown-description = Ob abgebrochene Abstimmungen die Sperrzeit starten (Standard: nein)
+    .secret = geheim
+    .secret-description = Ob Abstimmungen geheim sind und Zwischenstände verborgen bleiben (Standard: nein)
+cmd-vote_admin-eligibility = berechtigung
+    .description = Festlegen, wer abstimmen darf und wie Stimmen gewichtet werden
+    .kind = art
+    .kind-description = Art der Vorschläge, die eingestellt wird (Standard: Zurücksetzen)
+    .min_account_age_days = kontoalter_tage
+    .min_account_age_days-description = Mindestalter des Discord-Kontos in Tagen (Standard: 0)
+    .min_member_days = mitglied_tage
+    .min_member_days-description = Mindestanzahl an Tagen auf dem Server (Standard: 0)
+    .required_role = benötigte_rolle
+    .required_role-description = Rolle, die zum Abstimmen nötig ist
+    .clear_required_role = rolle_entfernen
+    .clear_required_role-description = Die benötigte Rolle entfernen
+    .min_balance = mindestguthaben
+    .min_balance-description = Mindestguthaben zum Abstimmen (Standard: 0)
+    .weighting = gewichtung
+    .weighting-description = Wie Stimmen gewichtet werden (Standard: eine Stimme pro Person)
+cmd-vote_admin-reset_mode = zurücksetzmodus
+    .description = Festlegen, wie Zurücksetzungen Guthaben ändern
+    .mode = modus
+    .mode-description = Wie sich Guthaben ändern, wenn eine Zurücksetzung angenommen wird
+    .amount = betrag
+    .amount-description = Prozentsatz zum Behalten oder Besteuern, oder die Obergrenze, für Modi, die einen brauchen
+cmd-vote_admin-quorum = quorum
+    .description = Festlegen, wie viele Stimmen Abstimmungen brauchen
+    .kind = art
+    .kind-description = Art des Vorschlags (Standard: Zurücksetzen)
+    .mode = modus
+    .mode-description = Eine feste Anzahl an Stimmen (min_votes) oder ein Anteil der aktiven Mitglieder
+    .percent = prozent
+    .percent-description = Prozentsatz der aktiven Mitglieder, deren Stimmen nötig sind
+    .days = tage
+    .days-description = Mitglieder gelten als aktiv, wenn sie in so vielen Tagen gesehen wurden
+cmd-vote_admin-reminder = erinnerung
+    .description = Kurz vor dem Ende von Abstimmungen Erinnerungen senden
+    .kind = art
+    .kind-description = Art des Vorschlags (Standard: Zurücksetzen)
+    .minutes = minuten
+    .minutes-description = Minuten vor dem Ende, zu denen erinnert wird (0 schaltet Erinnerungen aus)
+    .role = rolle
+    .role-description = Rolle, die im Kanal der Abstimmung erwähnt wird
+    .clear_role = rolle_entfernen
+    .clear_role-description = Keine Rolle mehr erwähnen
+cmd-vote_admin-restore = wiederherstellen
+    .description = Die Guthaben einer kürzlichen Zurücksetzung zurückgeben
+    .snapshot = sicherung
+    .snapshot-description = Name der Sicherung aus /vote history (Standard: die neueste)
+cmd-vote_admin-cancel = abbrechen
+    .description = Einen offenen Vorschlag abbrechen, ohne ihn umzusetzen
+    .proposal = vorschlag
+    .proposal-description = Nummer des abzubrechenden Vorschlags (Standard: der einzige offene)
+cmd-vote_admin-extend = verlängern
+    .description = Einem offenen Vorschlag mehr Zeit geben
+    .minutes = minuten
+    .minutes-description = Hinzuzufügende Minuten
+    .proposal = vorschlag
+    .proposal-description = Nummer des zu verlängernden Vorschlags (Standard: der einzige offene)
+cmd-vote_admin-end-now = jetzt-beenden
+    .description = Einen offenen Vorschlag jetzt beenden und das Ergebnis umsetzen
+    .proposal = vorschlag
+    .proposal-description = Nummer des zu beendenden Vorschlags (Standard: der einzige offene)
+cmd-propose = vorschlagen
+    .description = Eine Änderung vorschlagen, über die der Server abstimmt
+cmd-propose-setting = einstellung
+    .description = Eine Änderung einer Abstimmungseinstellung vorschlagen
+    .kind = art
+    .kind-description = Art der Vorschläge, deren Einstellung sich ändert
+    .field = einstellung
+    .field-description = Zu ändernde Einstellung
+    .value = wert
+    .value-description = Neuer Wert
+cmd-propose-grant = zuteilung
+    .description = Vorschlagen, einem Nutzer AndyCoins zu geben
+    .user = nutzer
+    .user-description = Nutzer, der die AndyCoins erhält
+    .amount = betrag
+    .amount-description = Anzahl der zu vergebenden AndyCoins
+cmd-propose-giver_role = geberrolle
+    .description = Vorschlagen, eine Geberrolle hinzuzufügen oder zu entfernen
+    .role = rolle
+    .role-description = Hinzuzufügende oder zu entfernende Rolle
+    .remove = entfernen
+    .remove-description = Die Rolle entfernen statt hinzufügen
+cmd-propose-poll = umfrage
+    .description = Dem Server eine Ja-Nein-Frage stellen
+    .question = frage
+    .question-description = Zu stellende Frage
+cmd-config = einstellungen
+    .description = Die Konfiguration des Bots in diesem Server anzeigen
+cmd-config-show = anzeigen
+    .description = Die gesamte AndyCoin-Konfiguration dieses Servers anzeigen
+cmd-config-roles = rollen
+    .description = Wirtschaftsrollen sowie freigegebene und gesperrte Nutzer auflisten
+cmd-config-rewards = belohnungen
+    .description = Die Rollen auflisten, die ab einem Guthaben vergeben werden
+cmd-config_admin = einstellungen_admin
+    .description = Den Bot für diesen Server konfigurieren
+cmd-config_admin-role = rolle
+    .description = Die Rollen verwalten, die AndyCoins vergeben können
+cmd-config_admin-role-add = hinzufügen
+    .description = Eine Geber- oder Wirtschaftsadmin-Rolle hinzufügen
+    .role = rolle
+    .role-description = Hinzuzufügende Rolle
+    .kind = art
+    .kind-description = Art der Rolle (Standard: Geber)
+cmd-config_admin-role-remove = entfernen
+    .description = Eine Geber- oder Wirtschaftsadmin-Rolle entfernen
+    .role = rolle
+    .role-description = Zu entfernende Rolle
+    .kind = art
+    .kind-description = Art der Rolle (Standard: Geber)
+cmd-config_admin-user = nutzer
+    .description = Einem Nutzer unabhängig von seinen Rollen das Vergeben erlauben oder verbieten
+    .user = nutzer
+    .user-description = Nutzer, der freigegeben oder gesperrt wird
+    .access = zugriff
+    .access-description = Erlauben oder verbieten (leer lassen zum Zurücksetzen)
+cmd-config_admin-budget = budget
+    .description = Vergabelimits für alle Geber oder eine bestimmte Rolle festlegen
+    .role = rolle
+    .role-description = Rolle, für die die Limits gelten (Standard: alle Geber)
+    .max_per_give = max_pro_vergabe
+    .max_per_give-description = Höchstens vergebene AndyCoins pro Vergabe (0 für kein Limit)
+    .budget = budget
+    .budget-description = AndyCoins, die jeder Geber pro Zeitraum vergeben kann (0 für kein Limit)
+    .period = zeitraum
+    .period-description = Budgetzeitraum für alle Geber (Standard: täglich)
+    .clear = zurücksetzen
+    .clear-description = Eigene Limits der Rolle entfernen, damit die Standardwerte gelten
+cmd-config_admin-currency = währung
+    .description = Name, Symbol und Emoji der Währung dieses Servers festlegen
+    .name = name
+    .name-description = Name der Währung, z. B. Goldstück
+    .plural = plural
+    .plural-description = Pluralform (Standard: Name mit s)
+    .symbol = symbol
+    .symbol-description = Kurzes Symbol für kompakte Ansichten, z. B. gs („none“ zum Entfernen)
+    .emoji = emoji
+    .emoji-description = Emoji, das bei Beträgen angezeigt wird („none“ zum Entfernen)
+    .reset = zurücksetzen
+    .reset-description = Zurück zu AndyCoins
+cmd-config_admin-language = sprache
+    .description = Sprache für Nutzer festlegen, deren Discord-Sprache nicht unterstützt wird
+    .language = sprache
+    .language-description = Zu verwendende Sprache (leer lassen für Englisch)
+cmd-config_admin-leaderboard = rangliste
+    .description = Die Server-Rangliste nach Zeitplan in einem Kanal posten
+    .channel = kanal
+    .channel-description = Kanal für die Beiträge (Standard: dieser Kanal)
+    .schedule = zeitplan
+    .schedule-description = Wann gepostet wird, als Cron-Angabe in UTC wie "0 18 * * 5" oder @weekly
+    .off = aus
+    .off-description = Das Posten der Rangliste beenden
+cmd-config_admin-season = saison
+    .description = Saisons, die Guthaben nach Zeitplan zurücksetzen und den Besten eine Rolle geben
+    .schedule = zeitplan
+    .schedule-description = Wann jede Saison endet, als Cron-Angabe in UTC wie "0 0 1 * *" oder @monthly
+    .role = rolle
+    .role-description = Rolle für die Besten jeder Saison
+    .winners = sieger
+    .winners-description = Wie viele der Besten die Rolle erhalten (0 für keine Rolle)
+    .channel = kanal
+    .channel-description = Kanal, in dem der Endstand verkündet wird
+    .off = aus
+    .off-description = Keine Saisons mehr; vergangene Saisons bleiben erhalten
+cmd-config_admin-reward = belohnung
+    .description = Die Rollen verwalten, die ab einem Guthaben vergeben werden
+cmd-config_admin-reward-add = hinzufügen
+    .description = Mitgliedern ab einem Guthaben eine Rolle geben
+    .role = rolle
+    .role-description = Zu vergebende Rolle
+    .threshold = schwelle
+    .threshold-description = Guthaben, ab dem Mitglieder die Rolle erhalten
+    .remove_below = darunter_entfernen
+    .remove_below-description = Die Rolle entfernen, wenn das Guthaben darunter fällt (Standard: behalten)
+cmd-config_admin-reward-remove = entfernen
+    .description = Eine Rolle nicht mehr ab einem Guthaben vergeben
+    .role = rolle
+    .role-description = Belohnungsrolle, die nicht mehr vergeben wird
+cmd-config_admin-reward-sync = abgleichen
+    .description = Belohnungsrollen vergeben und entfernen, sodass sie zu den aktuellen Guthaben passen
+cmd-config_admin-export = exportieren
+    .description = Die Einstellungen dieses Servers als YAML-Datei exportieren
+cmd-config_admin-import = importieren
+    .description = Servereinstellungen aus einer mit /config_admin export erstellten YAML-Datei importieren
+    .file = datei
+    .file-description = Einstellungsdatei von /config_admin export
+choice-giver = Geber
+choice-economy-admin = Wirtschaftsadmin
+choice-allow = erlauben
+choice-deny = verbieten
+choice-daily = Täglich
+choice-weekly = Wöchentlich
+choice-reset = Zurücksetzen
+choice-vote-setting = Abstimmungseinstellung
+choice-grant = Zuteilung
+choice-giver-role = Geberrolle
+choice-poll = Umfrage
+choice-cooldown-hours = Sperrzeit in Stunden
+choice-duration-minutes = Dauer in Minuten
+choice-minimum-votes = Mindeststimmen
+choice-majority-percentage = Mehrheit in Prozent
+choice-one-vote-per-person = Eine Stimme pro Person
+choice-balance-weighted = Nach Guthaben gewichtet
+choice-quadratic = Quadratisch
+choice-reset-to-zero = Auf null setzen
+choice-scale = Skalieren
+choice-cap = Obergrenze
+choice-redistribute = Umverteilen
+choice-tax = Steuer
+choice-fixed-number-of-votes = Feste Anzahl an Stimmen
+choice-percentage-of-active-members = Anteil der aktiven Mitglieder
+choice-week = Woche
+choice-month = Monat
+choice-all-time = Gesamt
+choice-coins = Münzen
+choice-top-givers = Top-Geber
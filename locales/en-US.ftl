@@ -0,0 +1,385 @@
+# AndyCoin bot responses in English (US), the fallback for every other locale.
+#
+# Slash command names and descriptions come from the doc comments in the source,
+# so this catalog only holds responses.
+
+## Shared
+
+none = none
+unknown = Unknown
+no-limit = no limit
+unknown-user = User { $id }
+error-server-only = This command can only be used in a server!
+error-member-info = Failed to get your member information.
+error-prefix = Error: { $error }
+save-failed = Warning: Failed to save settings: { $error }
+
+## Permissions
+
+access-member = a server member
+access-giver = a giver
+access-economy-admin = an economy admin
+access-administrator = a server administrator
+access-guild-owner = the server owner
+access-bot-owner = a bot owner
+access-required = You need to be { $level } to use this command.
+
+economy-role-giver = giver
+economy-role-admin = economy admin
+
+## Minting limits
+
+budget-period-daily = daily
+budget-period-weekly = weekly
+mint-exceeds-max = You can give at most { $max } at a time.
+mint-budget-exhausted = That would exceed your { $period } minting budget. You have { $remaining } left to give.
+
+## /give
+
+give-no-permission = You don't have permission to give { $currency }! Only the server owner, economy admins, allowed users and users with a giver role can do this.
+give-success = Gave { $amount } to { $user }. Their new balance in this server is { $balance }.
+give-remaining-budget = You have { $remaining } left in your { $period } minting budget.
+
+## /balance
+
+balance-self-server = You have { $amount } in this server.
+balance-self-global = You have { $amount } across all servers.
+balance-other-server = { $user } has { $amount } in this server.
+balance-other-global = { $user } has { $amount } across all servers.
+
+## /leaderboard
+
+leaderboard-empty = No one has any { $currency } yet!
+leaderboard-empty-period = No { $currency } changed hands in this period yet!
+leaderboard-gain = { $net } (earned { $earned }, received { $received })
+leaderboard-heading-gains = Net gains { $period ->
+        [week] over the last 7 days
+        [month] over the last 30 days
+       *[other] since tracking began
+    }
+leaderboard-heading-givers = Top givers { $period ->
+        [week] over the last 7 days
+        [month] over the last 30 days
+       *[other] since tracking began
+    }
+leaderboard-title-server = Server { $currency } Leaderboard
+leaderboard-title-global = Global { $currency } Leaderboard
+leaderboard-heading-since = Changes since the last post { $since }
+leaderboard-footer = Page { $page }/{ $pages } · { $rank ->
+        [0] You're not ranked yet
+       *[other] Your rank: #{ $rank }
+    }
+button-prev = ◀ Prev
+button-next = Next ▶
+
+## /rank
+
+rank-position = { $user } is ranked #{ $rank } of { $users } with { $amount } (ahead of { $percentile }% of users).
+rank-next = { $amount } more to reach #{ $rank }.
+rank-top = That's the top spot!
+rank-none = { $user } isn't on the leaderboard yet.
+
+## /seasons
+
+seasons-title = Hall of Fame
+seasons-current = Season { $number } is underway and ends { $end }.
+seasons-current-open = Season { $number } is underway, with no end scheduled.
+seasons-off = Seasons aren't running in this server.
+seasons-none = No season has finished yet.
+seasons-name = Season { $number }
+seasons-no-champions = no finishers
+seasons-ended-title = Season { $number } has ended!
+seasons-awarded = { $role } goes to { $users }.
+seasons-next = Season { $number } has begun and ends { $end }.
+seasons-next-open = Season { $number } has begun.
+
+## /vote
+
+vote-error-already-active = A proposal of this type is already open in this server
+vote-error-cooldown = A proposal of this type was recently completed. Please wait for the cooldown period to end
+vote-error-not-active = No vote is active in this server
+vote-error-ended = The vote has ended
+vote-error-unknown-proposal = There is no open proposal with that number
+vote-error-choose-proposal = Several proposals are open. Pick one with the `proposal` option
+vote-error-account-too-new = Your Discord account must be at least { $days } days old to vote on this proposal
+vote-error-joined-too-recently = You must have been in this server for at least { $days } days to vote on this proposal
+vote-error-missing-role = You need the { $role } role to vote on this proposal
+vote-error-balance-too-low = You need a balance of at least { $min_balance } to vote on this proposal
+vote-cast-yes = You have voted YES on proposal #{ $id }.
+vote-cast-no = You have voted NO on proposal #{ $id }.
+vote-status-cooldown = No active vote. A reset vote was recently completed. The next one can be started at { $cooldown_end }.
+vote-status-none = No active vote. Use `/vote start` or `/propose` to start a new vote.
+vote-history = 📜 **Recent votes**
+vote-history-none = No votes have finished in this server yet.
+vote-history-vote = **#{ $id }** { $action } ({ $date }): { $result ->
+        [passed] ✅ passed
+        [cancelled] 🚫 cancelled
+       *[failed] ❌ failed
+    } with { $yes } yes / { $no } no
+vote-history-resets = 📜 **Past resets**
+vote-history-entry = **{ $name }** (proposal #{ $id }, { $date }): { $mode }; { $total } across { $users } members, { $state ->
+        [restored] restored
+        [restorable] restorable until { $until }
+       *[final] final
+    }
+vote-history-treasury = 🏦 Treasury: { $amount }
+vote-stats = 📊 **Vote stats**
+    { $votes } votes: { $passed } passed, { $failed } failed, { $cancelled } cancelled
+    Pass rate: { $pass_rate }%
+    Average turnout: { $turnout } ballots per vote
+vote-stats-voters = **Most active voters**
+vote-stats-voter = { $rank }. { $user }: { $ballots ->
+        [one] { $ballots } ballot
+       *[other] { $ballots } ballots
+    }
+vote-restore-done = ♻️ Restored **{ $name }**: { $users } members have their balances from before the reset back.
+vote-cancelled = 🚫 Proposal #{ $id } was cancelled by { $admin }. Nothing will change.
+vote-extended = ⏳ Proposal #{ $id } was extended by { $minutes } minutes and now ends at { $end_time }.
+vote-extend-invalid = Extend a vote by at least 1 minute.
+vote-ended-now = ⏹️ Proposal #{ $id } was ended early by { $admin }.
+restore-error-no-snapshot = There is no reset to restore in this server
+restore-error-unknown-snapshot = There is no reset with that name. See `/vote history`
+restore-error-already-restored = That reset was already restored
+restore-error-grace-expired = That reset can no longer be restored
+vote-status-ended = The vote has ended. The result will be posted in the vote's channel shortly.
+vote-status-list = 🗳️ **Open Proposals**
+vote-status-active =
+    🗳️ **Proposal #{ $id } Status**
+    { $action }
+    Initiator: { $initiator }
+    End Time: { $end_time }
+    Votes: { $yes } YES / { $no } NO (Total: { $total })
+    Current YES Percentage: { $percentage }%
+    Required: At least { $min_votes } votes with { $majority }% majority
+    Status: { $outlook ->
+        [pass] Would PASS with current votes
+        [fail] Would FAIL with current votes
+       *[not-enough] Not enough votes yet
+    }
+vote-status-active-secret =
+    🗳️ **Proposal #{ $id } Status** (secret ballot)
+    { $action }
+    Initiator: { $initiator }
+    End Time: { $end_time }
+    Ballots cast: { $ballots }
+    Required: At least { $min_votes } votes with { $majority }% majority
+    The tally stays hidden until the vote ends.
+vote-config-invalid-majority = Majority percentage cannot be greater than 100%.
+vote-config-too-large = The { $field } cannot be greater than { $max }.
+vote-config-invalid-grace = Restore grace hours cannot be greater than { $max }.
+vote-config-updated =
+    ✅ **Vote Settings Updated** ({ $kind })
+    Cooldown between votes: { $cooldown_hours } hours
+    Vote duration: { $duration_minutes } minutes
+    Minimum votes required: { $min_votes }
+    Majority percentage required: { $majority }%
+    Reset restore window: { $restore_grace_hours } hours
+    Cancelled votes start the cooldown: { $cancel_cooldown ->
+        [yes] yes
+       *[no] no
+    }
+    Secret ballot: { $secret ->
+        [yes] yes
+       *[no] no
+    }
+vote-eligibility-updated =
+    ✅ **Voting Rules Updated** ({ $kind })
+    Minimum account age: { $account_age_days } days
+    Minimum time in the server: { $member_days } days
+    Required role: { $role }
+    Minimum balance: { $min_balance }
+    Weighting: { $weighting }
+vote-weighting = { $mode ->
+        [balance] balance-weighted
+        [quadratic] quadratic
+       *[one-person] one vote per person
+    }
+
+## /propose
+
+proposal-kind = { $kind ->
+        [setting] vote setting
+        [grant] grant
+        [giver-role] giver role
+        [poll] poll
+       *[reset] reset
+    }
+vote-field = { $field ->
+        [cooldown-hours] cooldown hours
+        [duration-minutes] duration minutes
+        [min-votes] minimum votes
+       *[majority] majority percentage
+    }
+proposal-reset = Reset all { $plural } in this server to 0
+proposal-setting = Set { $field } for { $kind } proposals to { $value }
+proposal-grant = Give { $amount } to { $user }
+proposal-giver-role-add = Add { $role } as a giver role
+proposal-giver-role-remove = Remove { $role } from the giver roles
+proposal-poll = Poll: { $question }
+proposal-partial-reset = Reset balances in this server: { $mode }
+reset-mode-zero = reset all { $plural } to 0
+reset-mode-scale = keep { $percent }% of every balance
+reset-mode-cap = cap balances at { $max }
+reset-mode-redistribute = share all { $plural } equally
+reset-mode-tax = move { $percent }% of every balance into the treasury
+reset-mode-invalid = Scale and tax need an `amount` from 0 to 100, and cap needs an `amount`.
+reset-mode-updated = ✅ Reset votes will now { $mode }.
+quorum-votes = at least { $min_votes } votes
+quorum-active = { $percent }% of the members active in the last { $days } days
+vote-quorum-invalid = A quorum of active members needs a `percent` from 1 to 100 and `days` from 1 to { $max_days }.
+vote-quorum-updated = ✅ { $kind } votes now need { $quorum }. With { $active } members active, that's { $required } votes.
+vote-status-quorum = Quorum: { $percent }% of { $active } members active in the last { $days } days
+vote-reminder = ⏰ Proposal #{ $id } closes { $end_time }: { $action }. Cast your vote with the buttons on the vote message or `/vote yes` and `/vote no`.
+vote-reminder-dm = ⏰ Proposal #{ $id } closes { $end_time } and you haven't voted yet: { $action } { $link }
+vote-remind-on = 🔔 You'll get a DM shortly before votes in this server close, if you haven't voted.
+vote-remind-off = 🔕 You won't get vote reminders from this server anymore.
+vote-reminder-updated = ✅ { $kind } votes will send a reminder { $minutes } minutes before they close. Role pinged: { $role }
+vote-reminder-off = ✅ { $kind } votes won't send reminders.
+proposal-grant-invalid = The amount must be at least 1.
+poll-question-invalid = Poll questions must be 1 to { $max } characters.
+vote-message =
+    🗳️ **Proposal #{ $id }** by { $initiator }
+    { $action }
+    Requirements: At least { $min_votes } votes with { $majority }% majority to pass.
+vote-message-tally = Votes: { $yes } yes / { $no } no · Ends { $end_time }
+vote-message-secret-tally = 🔒 Secret ballot · Ballots cast: { $ballots } · Ends { $end_time }
+vote-message-hint = Vote with the buttons below, or with `/vote yes` or `/vote no` and `proposal: { $id }`.
+vote-message-weighting = Ballots are weighted: { $weighting }.
+vote-weighted-tally = Weighted ({ $weighting }): { $yes } yes / { $no } no
+vote-message-result-passed = ✅ **PASSED** with { $yes } yes / { $no } no.
+vote-message-result-failed = ❌ **FAILED** with { $yes } yes / { $no } no.
+vote-message-result-poll = 🏁 **Closed** with { $yes } yes / { $no } no.
+vote-message-cancelled = 🚫 **Cancelled** by an admin.
+button-yes = Yes
+button-no = No
+proposal-reset-warning = ⚠️ If the vote passes, all { $plural } in this server will be reset to 0!
+proposal-summary = #{ $id }: { $action } (ends { $end_time }, { $yes } yes / { $no } no)
+proposal-summary-secret = #{ $id }: { $action } (ends { $end_time }, secret ballot, ballots cast: { $ballots })
+proposal-result-passed = 🗳️ Proposal #{ $id } has ended and PASSED ({ $yes } yes / { $no } no): { $action }
+proposal-result-failed = 🗳️ Proposal #{ $id } has ended and FAILED ({ $yes } yes / { $no } no): { $action }. Not enough votes or majority not reached.
+proposal-result-poll = 🗳️ Poll #{ $id } has closed: { $question } ({ $yes } yes / { $no } no)
+
+## /config_admin role and /config_admin user
+
+role-added = Added { $role } as a { $kind } role.
+role-already-added = { $role } is already a { $kind } role.
+role-removed = Removed { $role } from the { $kind } roles.
+role-not-added = { $role } is not a { $kind } role.
+role-list-title = Economy Roles
+role-list-owner-note = The server owner can always give { $plural }.
+user-access-allowed = { $user } can now give { $plural } regardless of their roles.
+user-access-denied = { $user } can no longer give { $plural }, regardless of their roles.
+user-access-cleared = Cleared the override for { $user }. Their roles decide if they can give { $plural }.
+
+## /config show
+
+config-help = Use one of the subcommands: show, roles, rewards. Settings are changed with /config_admin
+config-title = AndyCoin Configuration
+config-field-currency = Currency
+config-field-language = Language
+config-field-roles = Roles and Users
+config-field-limits = Minting Limits
+config-field-vote-settings = Vote Settings
+config-field-vote-status = Vote Status
+config-roles =
+    Economy admins: { $admins }
+    Givers: { $givers }
+    Allowed users: { $allowed }
+    Denied users: { $denied }
+config-limits-default = Default: max per give { $max_per_give }, { $period } budget { $budget }
+config-limits-role = { $role }: max per give { $max_per_give }, { $period } budget { $budget }
+config-vote-settings =
+    Cooldown: { $cooldown_hours } hours
+    Duration: { $duration_minutes } minutes
+    Minimum votes: { $min_votes }
+    Majority: { $majority }%
+config-vote-cooldown = Cooling down until { $cooldown_end }
+config-vote-idle = No active vote
+config-currency =
+    { $name } / { $plural }
+    Symbol: { $symbol }
+    Emoji: { $emoji }
+config-language-auto = Each user's Discord language, English otherwise
+
+## /config_admin export and /config_admin import
+
+config-exported = Here are this server's AndyCoin settings. Use `/config_admin import` to apply them to another server.
+import-too-large = That file is too large. Settings files are at most { $max_kib } KiB.
+import-not-yaml = That file is not a YAML settings file.
+import-invalid = Could not read the settings file: { $error }
+import-success = ✅ Imported AndyCoin settings. Use `/config show` to review them.
+import-missing-roles = ⚠️ { $count ->
+        [one] { $count } role in the file doesn't exist in this server.
+       *[other] { $count } roles in the file don't exist in this server.
+    } Update them with `/config_admin role`, `/config_admin budget` and `/config_admin reward`.
+
+## /config_admin currency, /config_admin budget and /config_admin language
+
+currency-invalid = Currency names, symbols and emoji must be 1 to { $max } characters.
+currency-mention = Currency names, symbols and emoji can't contain @, so they never ping anyone.
+currency-updated = ✅ Currency updated. Amounts now look like: { $one } / { $many } ({ $short })
+budget-scope-role = role { $role }
+budget-scope-all = all givers
+budget-updated = ✅ **Minting Limits Updated** ({ $scope })
+language-name = { $code ->
+        [de] German
+        [es-ES] Spanish
+        [pt-BR] Portuguese (Brazil)
+       *[other] English
+    }
+language-updated = ✅ Responses now use { $language } for users whose Discord language isn't supported.
+language-cleared = ✅ Responses now use English for users whose Discord language isn't supported.
+
+## /config_admin leaderboard
+
+leaderboard-schedule-set = ✅ The leaderboard will be posted in { $channel } on `{ $spec }` (UTC). Next post: { $next }
+leaderboard-schedule-show = The leaderboard is posted in { $channel } on `{ $spec }` (UTC). Next post: { $next }
+leaderboard-schedule-off = ✅ Scheduled leaderboard posts are off.
+leaderboard-schedule-none = The leaderboard isn't posted on a schedule. Set one with a cron spec like `0 18 * * 5` (Fridays at 18:00 UTC) or `@weekly`.
+leaderboard-schedule-invalid = `{ $spec }` isn't a valid schedule. Use five fields, `minute hour day month weekday`, like `0 18 * * 5`, or one of @hourly, @daily, @weekly and @monthly.
+leaderboard-schedule-never = never
+
+## /config_admin season
+
+season-updated = ✅ **Seasons Updated**
+season-show = Season { $number } runs on `{ $spec }` (UTC) and ends { $end }. Balances reset to 0 when it ends.
+season-show-award = The top { $winners } get { $role }.
+season-show-channel = Final standings are announced in { $channel }.
+season-off = ✅ Seasons are off. Past seasons stay in `/seasons`.
+season-none = Seasons aren't running. Start them with a schedule like `0 0 1 * *` (the 1st of each month) or `@monthly`.
+season-needs-schedule = Seasons need a schedule first. Set one with the schedule option, like `@monthly`.
+
+## /config_admin reward
+
+reward-added = ✅ Members with at least { $amount } now get **{ $role }**.
+reward-sync-hint = Run `/config_admin reward sync` to grant it to members who already have that much.
+reward-removed = ✅ **{ $role }** is no longer a reward role. Members who have it keep it.
+reward-not-found = **{ $role }** isn't a reward role.
+reward-list-title = Reward Roles
+reward-list-empty = No reward roles are set up. Add one with `/config_admin reward add`.
+reward-list-entry = { $role } at { $amount } · { $remove ->
+        [yes] removed below it
+       *[other] kept below it
+    }
+reward-synced = ✅ Checked { $members ->
+        [one] { $members } member
+       *[other] { $members } members
+    } and queued { $changes ->
+        [one] { $changes } role change
+       *[other] { $changes } role changes
+    }. They're made a few at a time.
+
+## /flip
+
+flip-side = { $side ->
+        [heads] heads
+       *[tails] tails
+    }
+flip-result = The coin landed on **{ $side }**!
+flip-invalid-guess = Invalid guess! Please use 'heads' or 'tails'.
+flip-bet-server-only = The betting game can only be played in a server!
+flip-bet-insufficient = You need at least { $amount } to play the betting game!
+flip-bet-won = The coin landed on **{ $side }**! You guessed correctly and won { $amount }! Your new balance is { $balance }.
+flip-bet-lost = The coin landed on **{ $side }**! You guessed wrong and lost { $amount }. Your new balance is { $balance }.
+flip-guess-correct = The coin landed on **{ $side }**! You guessed correctly!
+flip-guess-wrong = The coin landed on **{ $side }**! You guessed wrong.
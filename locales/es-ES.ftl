@@ -0,0 +1,687 @@
+# AndyCoin bot responses in Spanish (Spain), also used for Latin American Spanish.
+
+## Shared
+
+none = ninguno
+unknown = Desconocido
+no-limit = sin límite
+unknown-user = Usuario { $id }
+error-server-only = ¡Este comando solo se puede usar en un servidor!
+error-member-info = No se pudo obtener tu información de miembro.
+error-prefix = Error: { $error }
+save-failed = Aviso: no se pudieron guardar los ajustes: { $error }
+
+## Permissions
+
+access-member = miembro del servidor
+access-giver = donante
+access-economy-admin = administrador de economía
+access-administrator = administrador del servidor
+access-guild-owner = el propietario del servidor
+access-bot-owner = propietario del bot
+access-required = Tienes que ser { $level } para usar este comando.
+
+economy-role-giver = donante
+economy-role-admin = administrador de economía
+
+## Minting limits
+
+budget-period-daily = diario
+budget-period-weekly = semanal
+mint-exceeds-max = Puedes dar como máximo { $max } a la vez.
+mint-budget-exhausted = Eso superaría tu presupuesto { $period } de emisión. Te quedan { $remaining } por dar.
+
+## /give
+
+give-no-permission = ¡No tienes permiso para dar { $currency }! Solo el propietario del servidor, los administradores de economía, los usuarios permitidos y los usuarios con un rol de donante pueden hacerlo.
+give-success = Has dado { $amount } a { $user }. Su nuevo saldo en este servidor es { $balance }.
+give-remaining-budget = Te quedan { $remaining } en tu presupuesto { $period } de emisión.
+
+## /balance
+
+balance-self-server = Tienes { $amount } en este servidor.
+balance-self-global = Tienes { $amount } en todos los servidores.
+balance-other-server = { $user } tiene { $amount } en este servidor.
+balance-other-global = { $user } tiene { $amount } en todos los servidores.
+
+## /leaderboard
+
+leaderboard-empty = ¡Nadie tiene { $currency } todavía!
+leaderboard-empty-period = ¡Aún no se han movido { $currency } en este periodo!
+leaderboard-gain = { $net } (ganado { $earned }, recibido { $received })
+leaderboard-heading-gains = Ganancias netas { $period ->
+        [week] de los últimos 7 días
+        [month] de los últimos 30 días
+       *[other] desde que se registran
+    }
+leaderboard-heading-givers = Mayores donantes { $period ->
+        [week] de los últimos 7 días
+        [month] de los últimos 30 días
+       *[other] desde que se registran
+    }
+leaderboard-title-server = Clasificación de { $currency } del servidor
+leaderboard-title-global = Clasificación global de { $currency }
+leaderboard-heading-since = Cambios desde la última publicación { $since }
+leaderboard-footer = Página { $page }/{ $pages } · { $rank ->
+        [0] Aún no estás en la clasificación
+       *[other] Tu puesto: #{ $rank }
+    }
+button-prev = ◀ Anterior
+button-next = Siguiente ▶
+
+## /rank
+
+rank-position = { $user } está en el puesto #{ $rank } de { $users } con { $amount } (por delante del { $percentile }% de los usuarios).
+rank-next = Faltan { $amount } para llegar al puesto #{ $rank }.
+rank-top = ¡Es el primer puesto!
+rank-none = { $user } aún no está en la clasificación.
+
+## /seasons
+
+seasons-title = Salón de la fama
+seasons-current = La temporada { $number } está en curso y termina { $end }.
+seasons-current-open = La temporada { $number } está en curso, sin final programado.
+seasons-off = En este servidor no hay temporadas.
+seasons-none = Aún no ha terminado ninguna temporada.
+seasons-name = Temporada { $number }
+seasons-no-champions = sin clasificados
+seasons-ended-title = ¡La temporada { $number } ha terminado!
+seasons-awarded = { $role } es para { $users }.
+seasons-next = La temporada { $number } ha empezado y termina { $end }.
+seasons-next-open = La temporada { $number } ha empezado.
+
+## /vote
+
+vote-error-already-active = Ya hay una propuesta de este tipo abierta en este servidor
+vote-error-cooldown = Hace poco que terminó una propuesta de este tipo. Espera a que acabe el periodo de espera
+vote-error-not-active = No hay ninguna votación activa en este servidor
+vote-error-ended = La votación ha terminado
+vote-error-unknown-proposal = No hay ninguna propuesta abierta con ese número
+vote-error-choose-proposal = Hay varias propuestas abiertas. Elige una con la opción `proposal`
+vote-error-account-too-new = Tu cuenta de Discord debe tener al menos { $days } días para votar esta propuesta
+vote-error-joined-too-recently = Debes llevar al menos { $days } días en este servidor para votar esta propuesta
+vote-error-missing-role = Necesitas el rol { $role } para votar esta propuesta
+vote-error-balance-too-low = Necesitas un saldo de al menos { $min_balance } para votar esta propuesta
+vote-cast-yes = Has votado SÍ a la propuesta #{ $id }.
+vote-cast-no = Has votado NO a la propuesta #{ $id }.
+vote-status-cooldown = No hay ninguna votación activa. Hace poco que terminó una votación de reinicio. La siguiente se podrá iniciar a partir de { $cooldown_end }.
+vote-status-none = No hay ninguna votación activa. Usa `/vote start` o `/propose` para iniciar una nueva.
+vote-history = 📜 **Votaciones recientes**
+vote-history-none = Todavía no ha terminado ninguna votación en este servidor.
+vote-history-vote = **#{ $id }** { $action } ({ $date }): { $result ->
+        [passed] ✅ aprobada
+        [cancelled] 🚫 cancelada
+       *[failed] ❌ rechazada
+    } con { $yes } sí / { $no } no
+vote-history-resets = 📜 **Reinicios anteriores**
+vote-history-entry = **{ $name }** (propuesta #{ $id }, { $date }): { $mode }; { $total } entre { $users } miembros, { $state ->
+        [restored] restaurado
+        [restorable] restaurable hasta { $until }
+       *[final] definitivo
+    }
+vote-history-treasury = 🏦 Tesoro: { $amount }
+vote-stats = 📊 **Estadísticas de votación**
+    { $votes } votaciones: { $passed } aprobadas, { $failed } rechazadas, { $cancelled } canceladas
+    Tasa de aprobación: { $pass_rate } %
+    Participación media: { $turnout } votos por votación
+vote-stats-voters = **Votantes más activos**
+vote-stats-voter = { $rank }. { $user }: { $ballots ->
+        [one] { $ballots } voto
+       *[other] { $ballots } votos
+    }
+vote-restore-done = ♻️ **{ $name }** restaurado: { $users } miembros recuperan sus saldos de antes del reinicio.
+vote-cancelled = 🚫 { $admin } canceló la propuesta #{ $id }. No cambiará nada.
+vote-extended = ⏳ La propuesta #{ $id } se amplió { $minutes } minutos y ahora termina a las { $end_time }.
+vote-extend-invalid = Amplía una votación al menos 1 minuto.
+vote-ended-now = ⏹️ { $admin } terminó antes de tiempo la propuesta #{ $id }.
+restore-error-no-snapshot = No hay ningún reinicio que restaurar en este servidor
+restore-error-unknown-snapshot = No hay ningún reinicio con ese nombre. Consulta `/vote history`
+restore-error-already-restored = Ese reinicio ya se restauró
+restore-error-grace-expired = Ese reinicio ya no se puede restaurar
+vote-status-ended = La votación ha terminado. El resultado se publicará en breve en el canal de la votación.
+vote-status-list = 🗳️ **Propuestas abiertas**
+vote-status-active =
+    🗳️ **Estado de la propuesta #{ $id }**
+    { $action }
+    Iniciada por: { $initiator }
+    Hora de fin: { $end_time }
+    Votos: { $yes } SÍ / { $no } NO (Total: { $total })
+    Porcentaje actual de SÍ: { $percentage }%
+    Requisito: al menos { $min_votes } votos con una mayoría del { $majority }%
+    Estado: { $outlook ->
+        [pass] Se APROBARÍA con los votos actuales
+        [fail] Se RECHAZARÍA con los votos actuales
+       *[not-enough] Todavía no hay suficientes votos
+    }
+vote-status-active-secret =
+    🗳️ **Estado de la propuesta #{ $id }** (voto secreto)
+    { $action }
+    Iniciada por: { $initiator }
+    Hora de fin: { $end_time }
+    Votos emitidos: { $ballots }
+    Requisito: al menos { $min_votes } votos con una mayoría del { $majority }%
+    El recuento permanece oculto hasta que termine la votación.
+vote-config-invalid-majority = El porcentaje de mayoría no puede ser superior al 100%.
+vote-config-too-large = El valor de { $field } no puede ser superior a { $max }.
+vote-config-invalid-grace = Las horas de gracia para restaurar no pueden ser superiores a { $max }.
+vote-config-updated =
+    ✅ **Ajustes de votación actualizados** ({ $kind })
+    Espera entre votaciones: { $cooldown_hours } horas
+    Duración de la votación: { $duration_minutes } minutos
+    Votos mínimos necesarios: { $min_votes }
+    Porcentaje de mayoría necesario: { $majority }%
+    Plazo para restaurar un reinicio: { $restore_grace_hours } horas
+    Las votaciones canceladas inician la espera: { $cancel_cooldown ->
+        [yes] sí
+       *[no] no
+    }
+    Voto secreto: { $secret ->
+        [yes] sí
+       *[no] no
+    }
+vote-eligibility-updated =
+    ✅ **Reglas de votación actualizadas** ({ $kind })
+    Antigüedad mínima de la cuenta: { $account_age_days } días
+    Tiempo mínimo en el servidor: { $member_days } días
+    Rol requerido: { $role }
+    Saldo mínimo: { $min_balance }
+    Ponderación: { $weighting }
+vote-weighting = { $mode ->
+        [balance] según el saldo
+        [quadratic] cuadrática
+       *[one-person] un voto por persona
+    }
+
+## /propose
+
+proposal-kind = { $kind ->
+        [setting] ajuste de votación
+        [grant] concesión
+        [giver-role] rol de donante
+        [poll] encuesta
+       *[reset] reinicio
+    }
+vote-field = { $field ->
+        [cooldown-hours] horas de espera
+        [duration-minutes] minutos de duración
+        [min-votes] votos mínimos
+       *[majority] porcentaje de mayoría
+    }
+proposal-reset = Reiniciar a 0 todos los { $plural } de este servidor
+proposal-setting = Cambiar { $field } de las propuestas de tipo { $kind } a { $value }
+proposal-grant = Dar { $amount } a { $user }
+proposal-giver-role-add = Añadir { $role } como rol de donante
+proposal-giver-role-remove = Quitar { $role } de los roles de donante
+proposal-poll = Encuesta: { $question }
+proposal-partial-reset = Reiniciar los saldos de este servidor: { $mode }
+reset-mode-zero = poner todos los { $plural } a 0
+reset-mode-scale = conservar el { $percent }% de cada saldo
+reset-mode-cap = limitar los saldos a { $max }
+reset-mode-redistribute = repartir todos los { $plural } a partes iguales
+reset-mode-tax = mover el { $percent }% de cada saldo al tesoro
+reset-mode-invalid = Escalar e impuesto necesitan un `amount` de 0 a 100, y el límite necesita un `amount`.
+reset-mode-updated = ✅ Los reinicios ahora van a { $mode }.
+quorum-votes = al menos { $min_votes } votos
+quorum-active = el { $percent }% de los miembros activos en los últimos { $days } días
+vote-quorum-invalid = Un quórum de miembros activos necesita `percent` de 1 a 100 y `days` de 1 a { $max_days }.
+vote-quorum-updated = ✅ Las votaciones ({ $kind }) ahora necesitan { $quorum }. Con { $active } miembros activos, son { $required } votos.
+vote-status-quorum = Quórum: el { $percent }% de { $active } miembros activos en los últimos { $days } días
+vote-reminder = ⏰ La propuesta #{ $id } termina { $end_time }: { $action }. Vota con los botones del mensaje de la votación o con `/vote yes` y `/vote no`.
+vote-reminder-dm = ⏰ La propuesta #{ $id } termina { $end_time } y aún no has votado: { $action } { $link }
+vote-remind-on = 🔔 Recibirás un MD poco antes de que terminen las votaciones de este servidor, si aún no has votado.
+vote-remind-off = 🔕 Ya no recibirás recordatorios de votaciones de este servidor.
+vote-reminder-updated = ✅ Las votaciones ({ $kind }) enviarán un recordatorio { $minutes } minutos antes de terminar. Rol mencionado: { $role }
+vote-reminder-off = ✅ Las votaciones ({ $kind }) no enviarán recordatorios.
+proposal-grant-invalid = La cantidad debe ser al menos 1.
+poll-question-invalid = Las preguntas de encuesta deben tener de 1 a { $max } caracteres.
+vote-message =
+    🗳️ **Propuesta #{ $id }** de { $initiator }
+    { $action }
+    Requisitos: al menos { $min_votes } votos con una mayoría del { $majority }% para aprobarse.
+vote-message-tally = Votos: { $yes } sí / { $no } no · Termina { $end_time }
+vote-message-secret-tally = 🔒 Voto secreto · Votos emitidos: { $ballots } · Termina { $end_time }
+vote-message-hint = Vota con los botones de abajo, o con `/vote yes` o `/vote no` y `proposal: { $id }`.
+vote-message-weighting = Los votos están ponderados: { $weighting }.
+vote-weighted-tally = Ponderado ({ $weighting }): { $yes } sí / { $no } no
+vote-message-result-passed = ✅ **APROBADA** con { $yes } sí / { $no } no.
+vote-message-result-failed = ❌ **RECHAZADA** con { $yes } sí / { $no } no.
+vote-message-result-poll = 🏁 **Cerrada** con { $yes } sí / { $no } no.
+vote-message-cancelled = 🚫 **Cancelada** por un administrador.
+button-yes = Sí
+button-no = No
+proposal-reset-warning = ⚠️ ¡Si la votación se aprueba, todos los { $plural } de este servidor volverán a 0!
+proposal-summary = #{ $id }: { $action } (termina { $end_time }, { $yes } sí / { $no } no)
+proposal-summary-secret = #{ $id }: { $action } (termina { $end_time }, voto secreto, votos emitidos: { $ballots })
+proposal-result-passed = 🗳️ La propuesta #{ $id } ha terminado y se ha APROBADO ({ $yes } sí / { $no } no): { $action }
+proposal-result-failed = 🗳️ La propuesta #{ $id } ha terminado y se ha RECHAZADO ({ $yes } sí / { $no } no): { $action }. No hubo suficientes votos o no se alcanzó la mayoría.
+proposal-result-poll = 🗳️ La encuesta #{ $id } se ha cerrado: { $question } ({ $yes } sí / { $no } no)
+
+## /config_admin role and /config_admin user
+
+role-added = Se ha añadido { $role } como rol de { $kind }.
+role-already-added = { $role } ya es un rol de { $kind }.
+role-removed = Se ha quitado { $role } de los roles de { $kind }.
+role-not-added = { $role } no es un rol de { $kind }.
+role-list-title = Roles de economía
+role-list-owner-note = El propietario del servidor siempre puede dar { $plural }.
+user-access-allowed = { $user } ahora puede dar { $plural } sin importar sus roles.
+user-access-denied = { $user } ya no puede dar { $plural }, sin importar sus roles.
+user-access-cleared = Se ha quitado la excepción de { $user }. Sus roles deciden si puede dar { $plural }.
+
+## /config show
+
+config-help = Usa uno de los subcomandos: show, roles, rewards. Los ajustes se cambian con /config_admin
+config-title = Configuración de AndyCoin
+config-field-currency = Moneda
+config-field-language = Idioma
+config-field-roles = Roles y usuarios
+config-field-limits = Límites de emisión
+config-field-vote-settings = Ajustes de votación
+config-field-vote-status = Estado de la votación
+config-roles =
+    Administradores de economía: { $admins }
+    Donantes: { $givers }
+    Usuarios permitidos: { $allowed }
+    Usuarios bloqueados: { $denied }
+config-limits-default = Por defecto: máximo por entrega { $max_per_give }, presupuesto { $period } { $budget }
+config-limits-role = { $role }: máximo por entrega { $max_per_give }, presupuesto { $period } { $budget }
+config-vote-settings =
+    Espera: { $cooldown_hours } horas
+    Duración: { $duration_minutes } minutos
+    Votos mínimos: { $min_votes }
+    Mayoría: { $majority }%
+config-vote-cooldown = En espera hasta { $cooldown_end }
+config-vote-idle = No hay ninguna votación activa
+config-currency =
+    { $name } / { $plural }
+    Símbolo: { $symbol }
+    Emoji: { $emoji }
+config-language-auto = El idioma de Discord de cada usuario, o inglés
+
+## /config_admin export and /config_admin import
+
+config-exported = Estos son los ajustes de AndyCoin de este servidor. Usa `/config_admin import` para aplicarlos en otro servidor.
+import-too-large = El archivo es demasiado grande. Los archivos de ajustes ocupan como máximo { $max_kib } KiB.
+import-not-yaml = Ese archivo no es un archivo de ajustes YAML.
+import-invalid = No se pudo leer el archivo de ajustes: { $error }
+import-success = ✅ Ajustes de AndyCoin importados. Usa `/config show` para revisarlos.
+import-missing-roles = ⚠️ { $count ->
+        [one] { $count } rol del archivo no existe en este servidor.
+       *[other] { $count } roles del archivo no existen en este servidor.
+    } Actualízalos con `/config_admin role`, `/config_admin budget` y `/config_admin reward`.
+
+## /config_admin currency, /config_admin budget and /config_admin language
+
+currency-invalid = Los nombres, símbolos y emojis de la moneda deben tener entre 1 y { $max } caracteres.
+currency-mention = Los nombres, símbolos y emojis de la moneda no pueden contener @, para que nunca mencionen a nadie.
+currency-updated = ✅ Moneda actualizada. Las cantidades ahora se ven así: { $one } / { $many } ({ $short })
+budget-scope-role = rol { $role }
+budget-scope-all = todos los donantes
+budget-updated = ✅ **Límites de emisión actualizados** ({ $scope })
+language-name = { $code ->
+        [de] alemán
+        [es-ES] español
+        [pt-BR] portugués (Brasil)
+       *[other] inglés
+    }
+language-updated = ✅ Las respuestas ahora se dan en { $language } a los usuarios cuyo idioma de Discord no es compatible.
+language-cleared = ✅ Las respuestas ahora se dan en inglés a los usuarios cuyo idioma de Discord no es compatible.
+
+## /config_admin leaderboard
+
+leaderboard-schedule-set = ✅ La clasificación se publicará en { $channel } según `{ $spec }` (UTC). Próxima publicación: { $next }
+leaderboard-schedule-show = La clasificación se publica en { $channel } según `{ $spec }` (UTC). Próxima publicación: { $next }
+leaderboard-schedule-off = ✅ Las publicaciones programadas de la clasificación están desactivadas.
+leaderboard-schedule-none = La clasificación no se publica de forma programada. Configura una con una expresión cron como `0 18 * * 5` (los viernes a las 18:00 UTC) o `@weekly`.
+leaderboard-schedule-invalid = `{ $spec }` no es una programación válida. Usa cinco campos, `minuto hora día mes día-de-la-semana`, como `0 18 * * 5`, o uno de @hourly, @daily, @weekly y @monthly.
+leaderboard-schedule-never = nunca
+
+## /config_admin season
+
+season-updated = ✅ **Temporadas actualizadas**
+season-show = La temporada { $number } sigue `{ $spec }` (UTC) y termina { $end }. Al terminar, los saldos vuelven a 0.
+season-show-award = Los { $winners } primeros reciben { $role }.
+season-show-channel = La clasificación final se anuncia en { $channel }.
+season-off = ✅ Las temporadas están desactivadas. Las temporadas pasadas siguen en `/seasons`.
+season-none = No hay temporadas. Empiézalas con una programación como `0 0 1 * *` (el día 1 de cada mes) o `@monthly`.
+season-needs-schedule = Las temporadas necesitan primero una programación. Fíjala con la opción de programación, como `@monthly`.
+
+## /config_admin reward
+
+reward-added = ✅ Los miembros con al menos { $amount } ahora reciben **{ $role }**.
+reward-sync-hint = Ejecuta `/config_admin reward sync` para dárselo a los miembros que ya tienen esa cantidad.
+reward-removed = ✅ **{ $role }** ya no es un rol de recompensa. Quien lo tenga lo conserva.
+reward-not-found = **{ $role }** no es un rol de recompensa.
+reward-list-title = Roles de recompensa
+reward-list-empty = No hay roles de recompensa. Añade uno con `/config_admin reward add`.
+reward-list-entry = { $role } desde { $amount } · { $remove ->
+        [yes] se quita por debajo
+       *[other] se conserva por debajo
+    }
+reward-synced = ✅ Se { $members ->
+        [one] revisó { $members } miembro
+       *[other] revisaron { $members } miembros
+    } y se { $changes ->
+        [one] programó { $changes } cambio de rol
+       *[other] programaron { $changes } cambios de rol
+    }. Se aplican poco a poco.
+
+## /flip
+
+flip-side = { $side ->
+        [heads] cara
+       *[tails] cruz
+    }
+flip-result = ¡La moneda ha caído en **{ $side }**!
+flip-invalid-guess = ¡Apuesta no válida! Usa «cara» o «cruz».
+flip-bet-server-only = ¡El juego de apuestas solo se puede jugar en un servidor!
+flip-bet-insufficient = ¡Necesitas al menos { $amount } para jugar al juego de apuestas!
+flip-bet-won = ¡La moneda ha caído en **{ $side }**! ¡Has acertado y ganas { $amount }! Tu nuevo saldo es { $balance }.
+flip-bet-lost = ¡La moneda ha caído en **{ $side }**! Has fallado y pierdes { $amount }. Tu nuevo saldo es { $balance }.
+flip-guess-correct = ¡La moneda ha caído en **{ $side }**! ¡Has acertado!
+flip-guess-wrong = ¡La moneda ha caído en **{ $side }**! Has fallado.
+
+## Slash command localizations
+
+cmd-give = dar
+    .description = Dar AndyCoins a un usuario (solo donantes)
+    .amount = cantidad
+    .amount-description = Cantidad de AndyCoins que dar
+    .user = usuario
+    .user-description = Usuario que recibe los AndyCoins
+cmd-balance = saldo
+    .description = Consultar tu saldo de AndyCoins o el de otro usuario
+    .user = usuario
+    .user-description = Usuario cuyo saldo consultar (por defecto: tú)
+    .global = global
+    .global-description = Mostrar el saldo total en todos los servidores (por defecto: solo este servidor)
+cmd-leaderboard = clasificacion
+    .description = Mostrar la clasificación de AndyCoins
+    .page = pagina
+    .page-description = Página que mostrar (por defecto: 1)
+    .global = global
+    .global-description = Mostrar la clasificación global de todos los servidores (por defecto: solo este servidor)
+    .period = periodo
+    .period-description = Clasificar ganancias de la última semana o mes (por defecto: todo)
+    .board = tabla
+    .board-description = Clasificar monedas o miembros por monedas dadas (por defecto: monedas)
+cmd-rank = puesto
+    .description = Mostrar tu puesto o el de otro usuario en la clasificación
+    .user = usuario
+    .user-description = Usuario que consultar (por defecto: tú)
+    .global = global
+    .global-description = Puesto en todos los servidores (por defecto: solo este servidor)
+cmd-seasons = temporadas
+    .description = Ver la temporada actual y los campeones anteriores
+cmd-flip = lanzar_moneda
+    .description = Lanzar una moneda
+    .guess = apuesta
+    .guess-description = Cara o cruz, si quieres adivinar
+    .bet = apostar
+    .bet-description = Apostar una moneda a tu predicción
+cmd-vote = votar
+    .description = Iniciar una votación para reiniciar todos los AndyCoins del servidor o votar
+cmd-vote-start = iniciar
+    .description = Iniciar una votación para reiniciar todos los AndyCoins del servidor
+    .mode = modo
+    .mode-description = Cómo cambian los saldos si se aprueba la votación (predeterminado: el modo del servidor)
+    .amount = cantidad
+    .amount-description = Porcentaje a conservar o gravar, o el límite, para los modos que lo necesitan
+cmd-vote-yes = si
+    .description = Votar a favor de una propuesta abierta
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta que votas (por defecto: la única abierta)
+cmd-vote-no = no
+    .description = Votar en contra de una propuesta abierta
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta que votas (por defecto: la única abierta)
+cmd-vote-status = estado
+    .description = Consultar el estado de la votación actual
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta que consultas (por defecto: todas las abiertas)
+cmd-vote-history = historial
+    .description = Ver las votaciones y los reinicios anteriores
+    .kind = tipo
+    .kind-description = Mostrar solo las propuestas de este tipo
+cmd-vote-stats = estadisticas
+    .description = Ver la tasa de aprobación, la participación y los votantes más activos
+cmd-vote-remind = recordar
+    .description = Recibir un MD poco antes de que terminen las votaciones de este servidor
+    .enabled = activado
+    .enabled-description = Si quieres recordatorios de las votaciones en las que aún no has votado
+cmd-vote_admin = votacion_admin
+    .description = Administrar las votaciones
+cmd-vote_admin-config = ajustes
+    .description = Configurar las votaciones
+    .cooldown_hours = horas_espera
+    .cooldown_hours-description = Horas de espera entre votaciones (por defecto: 24)
+    .duration_minutes = minutos_duracion
+    .duration_minutes-description = Duración de la votación en minutos (por defecto: 30)
+    .min_votes = votos_minimos
+    .min_votes-description = Número mínimo de votos necesarios (por defecto: 10)
+    .majority_percentage = porcentaje_mayoria
+    .majority_percentage-description = Porcentaje de votos SÍ necesario para aprobarse (por defecto: 70)
+    .kind = tipo
+    .kind-description = Tipo de propuesta que se configura (por defecto: reinicio)
+    .restore_grace_hours = horas_restauracion
+    .restore_grace_hours-description = Horas tras un reinicio en las que los administradores pueden restaurarlo (predeterminado: 24)
+    .cancel_cooldown = espera_al_cancelar
+    .cancel_cooldown-description = Si las votaciones canceladas inician la espera (predeterminado: no)
+    .secret = secreto
+    .secret-description = Si las votaciones son secretas y ocultan el recuento parcial (predeterminado: no)
+cmd-vote_admin-eligibility = requisitos
+    .description = Configura quién puede votar y cómo se ponderan los votos
+    .kind = tipo
+    .kind-description = Tipo de propuesta a configurar (predeterminado: reinicio)
+    .min_account_age_days = antiguedad_cuenta_dias
+    .min_account_age_days-description = Antigüedad mínima de la cuenta de Discord en días (predeterminado: 0)
+    .min_member_days = dias_en_servidor
+    .min_member_days-description = Días mínimos en el servidor (predeterminado: 0)
+    .required_role = rol_requerido
+    .required_role-description = Rol necesario para votar
+    .clear_required_role = quitar_rol
+    .clear_required_role-description = Quitar el rol requerido
+    .min_balance = saldo_minimo
+    .min_balance-description = Saldo mínimo para votar (predeterminado: 0)
+    .weighting = ponderacion
+    .weighting-description = Cómo se ponderan los votos (predeterminado: un voto por persona)
+cmd-vote_admin-reset_mode = modo_reinicio
+    .description = Elegir cómo cambian los saldos los reinicios
+    .mode = modo
+    .mode-description = Cómo cambian los saldos cuando se aprueba un reinicio
+    .amount = cantidad
+    .amount-description = Porcentaje a conservar o gravar, o el límite, para los modos que lo necesitan
+cmd-vote_admin-quorum = quorum
+    .description = Elegir cuántos votos necesitan las votaciones
+    .kind = tipo
+    .kind-description = Tipo de propuesta a configurar (predeterminado: reinicio)
+    .mode = modo
+    .mode-description = Un número fijo de votos (min_votes) o una parte de los miembros activos
+    .percent = porcentaje
+    .percent-description = Porcentaje de miembros activos cuyos votos se necesitan
+    .days = dias
+    .days-description = Los miembros cuentan como activos si se les vio en estos días
+cmd-vote_admin-reminder = recordatorio
+    .description = Enviar recordatorios poco antes de que terminen las votaciones
+    .kind = tipo
+    .kind-description = Tipo de propuesta a configurar (predeterminado: reinicio)
+    .minutes = minutos
+    .minutes-description = Minutos antes del final para enviar recordatorios (0 los desactiva)
+    .role = rol
+    .role-description = Rol a mencionar en el canal de la votación
+    .clear_role = quitar_rol
+    .clear_role-description = Dejar de mencionar un rol
+cmd-vote_admin-restore = restaurar
+    .description = Devolver los saldos de un reinicio reciente
+    .snapshot = copia
+    .snapshot-description = Nombre de la copia de /vote history (predeterminado: la más reciente)
+cmd-vote_admin-cancel = cancelar
+    .description = Cancelar una propuesta abierta sin aplicarla
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta a cancelar (por defecto: la única abierta)
+cmd-vote_admin-extend = ampliar
+    .description = Dar más tiempo a una propuesta abierta
+    .minutes = minutos
+    .minutes-description = Minutos a añadir
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta a ampliar (por defecto: la única abierta)
+cmd-vote_admin-end-now = terminar-ya
+    .description = Terminar ahora una propuesta abierta y aplicar el resultado
+    .proposal = propuesta
+    .proposal-description = Número de la propuesta a terminar (por defecto: la única abierta)
+cmd-propose = proponer
+    .description = Proponer un cambio para que el servidor lo vote
+cmd-propose-setting = ajuste
+    .description = Proponer cambiar un ajuste de votación
+    .kind = tipo
+    .kind-description = Tipo de propuesta cuyo ajuste cambia
+    .field = ajuste
+    .field-description = Ajuste que se cambia
+    .value = valor
+    .value-description = Nuevo valor
+cmd-propose-grant = conceder
+    .description = Proponer dar AndyCoins a un usuario
+    .user = usuario
+    .user-description = Usuario que recibe los AndyCoins
+    .amount = cantidad
+    .amount-description = Cantidad de AndyCoins que se dan
+cmd-propose-giver_role = rol_donante
+    .description = Proponer añadir o quitar un rol de donante
+    .role = rol
+    .role-description = Rol que se añade o se quita
+    .remove = quitar
+    .remove-description = Quitar el rol en lugar de añadirlo
+cmd-propose-poll = encuesta
+    .description = Hacer al servidor una pregunta de sí o no
+    .question = pregunta
+    .question-description = Pregunta que se hace
+cmd-config = ajustes
+    .description = Ver la configuración del bot en este servidor
+cmd-config-show = mostrar
+    .description = Mostrar toda la configuración de AndyCoin de este servidor
+cmd-config-roles = roles
+    .description = Listar los roles de economía y los usuarios permitidos y bloqueados
+cmd-config-rewards = recompensas
+    .description = Ver los roles que se dan al alcanzar un saldo
+cmd-config_admin = ajustes_admin
+    .description = Configurar el bot en este servidor
+cmd-config_admin-role = rol
+    .description = Administrar los roles que pueden dar AndyCoins
+cmd-config_admin-role-add = añadir
+    .description = Añadir un rol de donante o de administrador de economía
+    .role = rol
+    .role-description = Rol que añadir
+    .kind = tipo
+    .kind-description = Tipo de rol (por defecto: donante)
+cmd-config_admin-role-remove = quitar
+    .description = Quitar un rol de donante o de administrador de economía
+    .role = rol
+    .role-description = Rol que quitar
+    .kind = tipo
+    .kind-description = Tipo de rol (por defecto: donante)
+cmd-config_admin-user = usuario
+    .description = Permitir o impedir que un usuario dé AndyCoins, sin importar sus roles
+    .user = usuario
+    .user-description = Usuario que permitir o bloquear
+    .access = acceso
+    .access-description = Permitir o bloquear al usuario (déjalo vacío para quitar la excepción)
+cmd-config_admin-budget = presupuesto
+    .description = Fijar límites de emisión para todos los donantes o para un rol
+    .role = rol
+    .role-description = Rol al que aplicar los límites (por defecto: todos los donantes)
+    .max_per_give = maximo_por_entrega
+    .max_per_give-description = Máximo de AndyCoins por entrega (0 para sin límite)
+    .budget = presupuesto
+    .budget-description = AndyCoins que cada donante puede emitir por periodo (0 para sin límite)
+    .period = periodo
+    .period-description = Periodo del presupuesto, para todos los donantes (por defecto: diario)
+    .clear = quitar
+    .clear-description = Quitar los límites propios del rol para que se apliquen los de por defecto
+cmd-config_admin-currency = moneda
+    .description = Fijar el nombre, símbolo y emoji de la moneda de este servidor
+    .name = nombre
+    .name-description = Nombre de la moneda, p. ej. Pieza de oro
+    .plural = plural
+    .plural-description = Nombre en plural (por defecto: el nombre con una s)
+    .symbol = simbolo
+    .symbol-description = Símbolo corto para vistas compactas, p. ej. po («none» para quitarlo)
+    .emoji = emoji
+    .emoji-description = Emoji que se muestra con las cantidades («none» para quitarlo)
+    .reset = restablecer
+    .reset-description = Volver a AndyCoins
+cmd-config_admin-language = idioma
+    .description = Fijar el idioma para los usuarios cuyo idioma de Discord no es compatible
+    .language = idioma
+    .language-description = Idioma que usar (déjalo vacío para usar inglés)
+cmd-config_admin-leaderboard = clasificacion
+    .description = Publicar la clasificación del servidor en un canal de forma programada
+    .channel = canal
+    .channel-description = Canal donde publicar (por defecto: este canal)
+    .schedule = programacion
+    .schedule-description = Cuándo publicar, como expresión cron en UTC como "0 18 * * 5" o @weekly
+    .off = desactivar
+    .off-description = Dejar de publicar la clasificación
+cmd-config_admin-season = temporada
+    .description = Temporadas que reinician los saldos de forma programada y dan un rol a los primeros
+    .schedule = programacion
+    .schedule-description = Cuándo termina cada temporada, como expresión cron en UTC como "0 0 1 * *" o @monthly
+    .role = rol
+    .role-description = Rol para los primeros de cada temporada
+    .winners = ganadores
+    .winners-description = Cuántos de los primeros reciben el rol (0 para ningún rol)
+    .channel = canal
+    .channel-description = Canal donde anunciar la clasificación final
+    .off = desactivar
+    .off-description = Dejar de tener temporadas; las pasadas se conservan
+cmd-config_admin-reward = recompensa
+    .description = Gestionar los roles que se dan al alcanzar un saldo
+cmd-config_admin-reward-add = añadir
+    .description = Dar un rol a los miembros cuyo saldo alcance un umbral
+    .role = rol
+    .role-description = Rol que dar
+    .threshold = umbral
+    .threshold-description = Saldo a partir del cual los miembros reciben el rol
+    .remove_below = quitar_por_debajo
+    .remove_below-description = Quitar el rol cuando el saldo baje del umbral (por defecto: conservarlo)
+cmd-config_admin-reward-remove = quitar
+    .description = Dejar de dar un rol al alcanzar un saldo
+    .role = rol
+    .role-description = Rol de recompensa que dejar de dar
+cmd-config_admin-reward-sync = sincronizar
+    .description = Dar y quitar roles de recompensa para que coincidan con los saldos actuales
+cmd-config_admin-export = exportar
+    .description = Exportar los ajustes de este servidor como archivo YAML
+cmd-config_admin-import = importar
+    .description = Importar ajustes desde un archivo YAML creado con /config_admin export
+    .file = archivo
+    .file-description = Archivo de ajustes de /config_admin export
+choice-giver = donante
+choice-economy-admin = administrador de economía
+choice-allow = permitir
+choice-deny = bloquear
+choice-daily = Diario
+choice-weekly = Semanal
+choice-reset = Reinicio
+choice-vote-setting = Ajuste de votación
+choice-grant = Concesión
+choice-giver-role = Rol de donante
+choice-poll = Encuesta
+choice-cooldown-hours = Horas de espera
+choice-duration-minutes = Minutos de duración
+choice-minimum-votes = Votos mínimos
+choice-majority-percentage = Porcentaje de mayoría
+choice-one-vote-per-person = Un voto por persona
+choice-balance-weighted = Ponderado por saldo
+choice-quadratic = Cuadrática
+choice-reset-to-zero = Poner a cero
+choice-scale = Escalar
+choice-cap = Límite
+choice-redistribute = Redistribuir
+choice-tax = Impuesto
+choice-fixed-number-of-votes = Número fijo de votos
+choice-percentage-of-active-members = Porcentaje de miembros activos
+choice-week = Semana
+choice-month = Mes
+choice-all-time = Todo
+choice-coins = Monedas
+choice-top-givers = Mayores donantes
@@ -0,0 +1,687 @@
+# AndyCoin bot responses in Brazilian Portuguese.
+
+## Shared
+
+none = nenhum
+unknown = Desconhecido
+no-limit = sem limite
+unknown-user = Usuário { $id }
+error-server-only = Este comando só pode ser usado em um servidor!
+error-member-info = Não foi possível obter suas informações de membro.
+error-prefix = Erro: { $error }
+save-failed = Aviso: não foi possível salvar as configurações: { $error }
+
+## Permissions
+
+access-member = membro do servidor
+access-giver = doador
+access-economy-admin = administrador de economia
+access-administrator = administrador do servidor
+access-guild-owner = o dono do servidor
+access-bot-owner = dono do bot
+access-required = Você precisa ser { $level } para usar este comando.
+
+economy-role-giver = doador
+economy-role-admin = administrador de economia
+
+## Minting limits
+
+budget-period-daily = diário
+budget-period-weekly = semanal
+mint-exceeds-max = Você pode dar no máximo { $max } de cada vez.
+mint-budget-exhausted = Isso excederia seu orçamento { $period } de emissão. Você ainda pode dar { $remaining }.
+
+## /give
+
+give-no-permission = Você não tem permissão para dar { $currency }! Só o dono do servidor, administradores de economia, usuários permitidos e usuários com um cargo de doador podem fazer isso.
+give-success = Você deu { $amount } para { $user }. O novo saldo nesse servidor é { $balance }.
+give-remaining-budget = Restam { $remaining } no seu orçamento { $period } de emissão.
+
+## /balance
+
+balance-self-server = Você tem { $amount } neste servidor.
+balance-self-global = Você tem { $amount } em todos os servidores.
+balance-other-server = { $user } tem { $amount } neste servidor.
+balance-other-global = { $user } tem { $amount } em todos os servidores.
+
+## /leaderboard
+
+leaderboard-empty = Ninguém tem { $currency } ainda!
+leaderboard-empty-period = Nenhuma { $currency } foi movimentada neste período ainda!
+leaderboard-gain = { $net } (ganhou { $earned }, recebeu { $received })
+leaderboard-heading-gains = Ganhos líquidos { $period ->
+        [week] dos últimos 7 dias
+        [month] dos últimos 30 dias
+       *[other] desde o início do registro
+    }
+leaderboard-heading-givers = Maiores doadores { $period ->
+        [week] dos últimos 7 dias
+        [month] dos últimos 30 dias
+       *[other] desde o início do registro
+    }
+leaderboard-title-server = Ranking de { $currency } do servidor
+leaderboard-title-global = Ranking global de { $currency }
+leaderboard-heading-since = Mudanças desde a última publicação { $since }
+leaderboard-footer = Página { $page }/{ $pages } · { $rank ->
+        [0] Você ainda não está no ranking
+       *[other] Sua posição: #{ $rank }
+    }
+button-prev = ◀ Anterior
+button-next = Próxima ▶
+
+## /rank
+
+rank-position = { $user } está em #{ $rank } de { $users } com { $amount } (à frente de { $percentile }% dos usuários).
+rank-next = Faltam { $amount } para chegar a #{ $rank }.
+rank-top = Esse é o primeiro lugar!
+rank-none = { $user } ainda não está no ranking.
+
+## /seasons
+
+seasons-title = Hall da fama
+seasons-current = A temporada { $number } está em andamento e termina { $end }.
+seasons-current-open = A temporada { $number } está em andamento, sem fim agendado.
+seasons-off = Não há temporadas neste servidor.
+seasons-none = Nenhuma temporada terminou ainda.
+seasons-name = Temporada { $number }
+seasons-no-champions = sem classificados
+seasons-ended-title = A temporada { $number } terminou!
+seasons-awarded = { $role } vai para { $users }.
+seasons-next = A temporada { $number } começou e termina { $end }.
+seasons-next-open = A temporada { $number } começou.
+
+## /vote
+
+vote-error-already-active = Já existe uma proposta deste tipo aberta neste servidor
+vote-error-cooldown = Uma proposta deste tipo terminou há pouco tempo. Aguarde o fim do período de espera
+vote-error-not-active = Não há nenhuma votação ativa neste servidor
+vote-error-ended = A votação terminou
+vote-error-unknown-proposal = Não há nenhuma proposta aberta com esse número
+vote-error-choose-proposal = Há várias propostas abertas. Escolha uma com a opção `proposal`
+vote-error-account-too-new = Sua conta do Discord precisa ter pelo menos { $days } dias para votar nesta proposta
+vote-error-joined-too-recently = Você precisa estar neste servidor há pelo menos { $days } dias para votar nesta proposta
+vote-error-missing-role = Você precisa do cargo { $role } para votar nesta proposta
+vote-error-balance-too-low = Você precisa de um saldo de pelo menos { $min_balance } para votar nesta proposta
+vote-cast-yes = Você votou SIM na proposta #{ $id }.
+vote-cast-no = Você votou NÃO na proposta #{ $id }.
+vote-status-cooldown = Nenhuma votação ativa. Uma votação de reinício terminou há pouco tempo. A próxima pode ser iniciada a partir de { $cooldown_end }.
+vote-status-none = Nenhuma votação ativa. Use `/vote start` ou `/propose` para iniciar uma nova.
+vote-history = 📜 **Votações recentes**
+vote-history-none = Nenhuma votação terminou neste servidor ainda.
+vote-history-vote = **#{ $id }** { $action } ({ $date }): { $result ->
+        [passed] ✅ aprovada
+        [cancelled] 🚫 cancelada
+       *[failed] ❌ rejeitada
+    } com { $yes } sim / { $no } não
+vote-history-resets = 📜 **Redefinições anteriores**
+vote-history-entry = **{ $name }** (proposta #{ $id }, { $date }): { $mode }; { $total } entre { $users } membros, { $state ->
+        [restored] restaurada
+        [restorable] restaurável até { $until }
+       *[final] definitiva
+    }
+vote-history-treasury = 🏦 Tesouro: { $amount }
+vote-stats = 📊 **Estatísticas de votação**
+    { $votes } votações: { $passed } aprovadas, { $failed } rejeitadas, { $cancelled } canceladas
+    Taxa de aprovação: { $pass_rate }%
+    Participação média: { $turnout } votos por votação
+vote-stats-voters = **Votantes mais ativos**
+vote-stats-voter = { $rank }. { $user }: { $ballots ->
+        [one] { $ballots } voto
+       *[other] { $ballots } votos
+    }
+vote-restore-done = ♻️ **{ $name }** restaurada: { $users } membros têm de volta os saldos de antes da redefinição.
+vote-cancelled = 🚫 { $admin } cancelou a proposta #{ $id }. Nada vai mudar.
+vote-extended = ⏳ A proposta #{ $id } foi estendida em { $minutes } minutos e agora termina às { $end_time }.
+vote-extend-invalid = Estenda uma votação em pelo menos 1 minuto.
+vote-ended-now = ⏹️ { $admin } encerrou a proposta #{ $id } antes do tempo.
+restore-error-no-snapshot = Não há nenhuma redefinição para restaurar neste servidor
+restore-error-unknown-snapshot = Não há nenhuma redefinição com esse nome. Veja `/vote history`
+restore-error-already-restored = Essa redefinição já foi restaurada
+restore-error-grace-expired = Essa redefinição não pode mais ser restaurada
+vote-status-ended = A votação terminou. O resultado será publicado em breve no canal da votação.
+vote-status-list = 🗳️ **Propostas abertas**
+vote-status-active =
+    🗳️ **Status da proposta #{ $id }**
+    { $action }
+    Iniciada por: { $initiator }
+    Término: { $end_time }
+    Votos: { $yes } SIM / { $no } NÃO (Total: { $total })
+    Porcentagem atual de SIM: { $percentage }%
+    Necessário: pelo menos { $min_votes } votos com maioria de { $majority }%
+    Status: { $outlook ->
+        [pass] Seria APROVADA com os votos atuais
+        [fail] Seria REJEITADA com os votos atuais
+       *[not-enough] Ainda não há votos suficientes
+    }
+vote-status-active-secret =
+    🗳️ **Status da proposta #{ $id }** (voto secreto)
+    { $action }
+    Iniciada por: { $initiator }
+    Término: { $end_time }
+    Votos registrados: { $ballots }
+    Necessário: pelo menos { $min_votes } votos com maioria de { $majority }%
+    A contagem fica oculta até o fim da votação.
+vote-config-invalid-majority = A porcentagem de maioria não pode ser maior que 100%.
+vote-config-too-large = O valor de { $field } não pode ser maior que { $max }.
+vote-config-invalid-grace = As horas de carência para restaurar não podem ser maiores que { $max }.
+vote-config-updated =
+    ✅ **Configurações de votação atualizadas** ({ $kind })
+    Espera entre votações: { $cooldown_hours } horas
+    Duração da votação: { $duration_minutes } minutos
+    Mínimo de votos: { $min_votes }
+    Porcentagem de maioria: { $majority }%
+    Prazo para restaurar uma redefinição: { $restore_grace_hours } horas
+    Votações canceladas iniciam a espera: { $cancel_cooldown ->
+        [yes] sim
+       *[no] não
+    }
+    Voto secreto: { $secret ->
+        [yes] sim
+       *[no] não
+    }
+vote-eligibility-updated =
+    ✅ **Regras de votação atualizadas** ({ $kind })
+    Idade mínima da conta: { $account_age_days } dias
+    Tempo mínimo no servidor: { $member_days } dias
+    Cargo necessário: { $role }
+    Saldo mínimo: { $min_balance }
+    Ponderação: { $weighting }
+vote-weighting = { $mode ->
+        [balance] pelo saldo
+        [quadratic] quadrática
+       *[one-person] um voto por pessoa
+    }
+
+## /propose
+
+proposal-kind = { $kind ->
+        [setting] configuração de votação
+        [grant] concessão
+        [giver-role] cargo de doador
+        [poll] enquete
+       *[reset] reinício
+    }
+vote-field = { $field ->
+        [cooldown-hours] horas de espera
+        [duration-minutes] minutos de duração
+        [min-votes] mínimo de votos
+       *[majority] porcentagem de maioria
+    }
+proposal-reset = Zerar todos os { $plural } deste servidor
+proposal-setting = Mudar { $field } das propostas de { $kind } para { $value }
+proposal-grant = Dar { $amount } para { $user }
+proposal-giver-role-add = Adicionar { $role } como cargo de doador
+proposal-giver-role-remove = Remover { $role } dos cargos de doador
+proposal-poll = Enquete: { $question }
+proposal-partial-reset = Redefinir os saldos deste servidor: { $mode }
+reset-mode-zero = zerar todos os { $plural }
+reset-mode-scale = manter { $percent }% de cada saldo
+reset-mode-cap = limitar os saldos a { $max }
+reset-mode-redistribute = dividir todos os { $plural } igualmente
+reset-mode-tax = mover { $percent }% de cada saldo para o tesouro
+reset-mode-invalid = Escalar e imposto precisam de um `amount` de 0 a 100, e o limite precisa de um `amount`.
+reset-mode-updated = ✅ As redefinições agora vão { $mode }.
+quorum-votes = pelo menos { $min_votes } votos
+quorum-active = { $percent }% dos membros ativos nos últimos { $days } dias
+vote-quorum-invalid = Um quórum de membros ativos precisa de `percent` de 1 a 100 e `days` de 1 a { $max_days }.
+vote-quorum-updated = ✅ As votações ({ $kind }) agora precisam de { $quorum }. Com { $active } membros ativos, são { $required } votos.
+vote-status-quorum = Quórum: { $percent }% de { $active } membros ativos nos últimos { $days } dias
+vote-reminder = ⏰ A proposta #{ $id } termina { $end_time }: { $action }. Vote com os botões da mensagem da votação ou com `/vote yes` e `/vote no`.
+vote-reminder-dm = ⏰ A proposta #{ $id } termina { $end_time } e você ainda não votou: { $action } { $link }
+vote-remind-on = 🔔 Você vai receber uma DM pouco antes das votações deste servidor terminarem, se ainda não tiver votado.
+vote-remind-off = 🔕 Você não vai mais receber lembretes de votação deste servidor.
+vote-reminder-updated = ✅ As votações ({ $kind }) vão enviar um lembrete { $minutes } minutos antes de terminar. Cargo mencionado: { $role }
+vote-reminder-off = ✅ As votações ({ $kind }) não vão enviar lembretes.
+proposal-grant-invalid = A quantia deve ser pelo menos 1.
+poll-question-invalid = Perguntas de enquete devem ter de 1 a { $max } caracteres.
+vote-message =
+    🗳️ **Proposta #{ $id }** de { $initiator }
+    { $action }
+    Requisitos: pelo menos { $min_votes } votos com maioria de { $majority }% para ser aprovada.
+vote-message-tally = Votos: { $yes } sim / { $no } não · Termina { $end_time }
+vote-message-secret-tally = 🔒 Voto secreto · Votos registrados: { $ballots } · Termina { $end_time }
+vote-message-hint = Vote com os botões abaixo, ou com `/vote yes` ou `/vote no` e `proposal: { $id }`.
+vote-message-weighting = Os votos são ponderados: { $weighting }.
+vote-weighted-tally = Ponderado ({ $weighting }): { $yes } sim / { $no } não
+vote-message-result-passed = ✅ **APROVADA** com { $yes } sim / { $no } não.
+vote-message-result-failed = ❌ **REJEITADA** com { $yes } sim / { $no } não.
+vote-message-result-poll = 🏁 **Encerrada** com { $yes } sim / { $no } não.
+vote-message-cancelled = 🚫 **Cancelada** por um administrador.
+button-yes = Sim
+button-no = Não
+proposal-reset-warning = ⚠️ Se a votação for aprovada, todos os { $plural } deste servidor serão zerados!
+proposal-summary = #{ $id }: { $action } (termina { $end_time }, { $yes } sim / { $no } não)
+proposal-summary-secret = #{ $id }: { $action } (termina { $end_time }, voto secreto, votos registrados: { $ballots })
+proposal-result-passed = 🗳️ A proposta #{ $id } terminou e foi APROVADA ({ $yes } sim / { $no } não): { $action }
+proposal-result-failed = 🗳️ A proposta #{ $id } terminou e foi REJEITADA ({ $yes } sim / { $no } não): { $action }. Não houve votos suficientes ou a maioria não foi atingida.
+proposal-result-poll = 🗳️ A enquete #{ $id } foi encerrada: { $question } ({ $yes } sim / { $no } não)
+
+## /config_admin role and /config_admin user
+
+role-added = { $role } foi adicionado como cargo de { $kind }.
+role-already-added = { $role } já é um cargo de { $kind }.
+role-removed = { $role } foi removido dos cargos de { $kind }.
+role-not-added = { $role } não é um cargo de { $kind }.
+role-list-title = Cargos de economia
+role-list-owner-note = O dono do servidor sempre pode dar { $plural }.
+user-access-allowed = { $user } agora pode dar { $plural }, independentemente dos cargos.
+user-access-denied = { $user } não pode mais dar { $plural }, independentemente dos cargos.
+user-access-cleared = A exceção de { $user } foi removida. Os cargos decidem se pode dar { $plural }.
+
+## /config show
+
+config-help = Use um dos subcomandos: show, roles, rewards. As configurações são alteradas com /config_admin
+config-title = Configuração do AndyCoin
+config-field-currency = Moeda
+config-field-language = Idioma
+config-field-roles = Cargos e usuários
+config-field-limits = Limites de emissão
+config-field-vote-settings = Configurações de votação
+config-field-vote-status = Status da votação
+config-roles =
+    Administradores de economia: { $admins }
+    Doadores: { $givers }
+    Usuários permitidos: { $allowed }
+    Usuários bloqueados: { $denied }
+config-limits-default = Padrão: máximo por doação { $max_per_give }, orçamento { $period } { $budget }
+config-limits-role = { $role }: máximo por doação { $max_per_give }, orçamento { $period } { $budget }
+config-vote-settings =
+    Espera: { $cooldown_hours } horas
+    Duração: { $duration_minutes } minutos
+    Mínimo de votos: { $min_votes }
+    Maioria: { $majority }%
+config-vote-cooldown = Em espera até { $cooldown_end }
+config-vote-idle = Nenhuma votação ativa
+config-currency =
+    { $name } / { $plural }
+    Símbolo: { $symbol }
+    Emoji: { $emoji }
+config-language-auto = O idioma do Discord de cada usuário, ou inglês
+
+## /config_admin export and /config_admin import
+
+config-exported = Aqui estão as configurações de AndyCoin deste servidor. Use `/config_admin import` para aplicá-las em outro servidor.
+import-too-large = Esse arquivo é grande demais. Arquivos de configuração têm no máximo { $max_kib } KiB.
+import-not-yaml = Esse arquivo não é um arquivo de configuração YAML.
+import-invalid = Não foi possível ler o arquivo de configuração: { $error }
+import-success = ✅ Configurações de AndyCoin importadas. Use `/config show` para revisá-las.
+import-missing-roles = ⚠️ { $count ->
+        [one] { $count } cargo do arquivo não existe neste servidor.
+       *[other] { $count } cargos do arquivo não existem neste servidor.
+    } Atualize-os com `/config_admin role`, `/config_admin budget` e `/config_admin reward`.
+
+## /config_admin currency, /config_admin budget and /config_admin language
+
+currency-invalid = Nomes, símbolos e emojis de moeda devem ter de 1 a { $max } caracteres.
+currency-mention = Nomes, símbolos e emojis de moeda não podem conter @, para nunca mencionar ninguém.
+currency-updated = ✅ Moeda atualizada. Os valores agora ficam assim: { $one } / { $many } ({ $short })
+budget-scope-role = cargo { $role }
+budget-scope-all = todos os doadores
+budget-updated = ✅ **Limites de emissão atualizados** ({ $scope })
+language-name = { $code ->
+        [de] alemão
+        [es-ES] espanhol
+        [pt-BR] português (Brasil)
+       *[other] inglês
+    }
+language-updated = ✅ As respostas agora são em { $language } para usuários cujo idioma do Discord não é suportado.
+language-cleared = ✅ As respostas agora são em inglês para usuários cujo idioma do Discord não é suportado.
+
+## /config_admin leaderboard
+
+leaderboard-schedule-set = ✅ O ranking será publicado em { $channel } conforme `{ $spec }` (UTC). Próxima publicação: { $next }
+leaderboard-schedule-show = O ranking é publicado em { $channel } conforme `{ $spec }` (UTC). Próxima publicação: { $next }
+leaderboard-schedule-off = ✅ As publicações agendadas do ranking estão desativadas.
+leaderboard-schedule-none = O ranking não é publicado em um agendamento. Defina um com uma expressão cron como `0 18 * * 5` (sextas às 18:00 UTC) ou `@weekly`.
+leaderboard-schedule-invalid = `{ $spec }` não é um agendamento válido. Use cinco campos, `minuto hora dia mês dia-da-semana`, como `0 18 * * 5`, ou um de @hourly, @daily, @weekly e @monthly.
+leaderboard-schedule-never = nunca
+
+## /config_admin season
+
+season-updated = ✅ **Temporadas atualizadas**
+season-show = A temporada { $number } segue `{ $spec }` (UTC) e termina { $end }. Ao terminar, os saldos voltam a 0.
+season-show-award = Os { $winners } primeiros recebem { $role }.
+season-show-channel = A classificação final é anunciada em { $channel }.
+season-off = ✅ As temporadas estão desativadas. As temporadas passadas continuam em `/seasons`.
+season-none = Não há temporadas. Comece-as com um agendamento como `0 0 1 * *` (dia 1 de cada mês) ou `@monthly`.
+season-needs-schedule = As temporadas precisam primeiro de um agendamento. Defina-o com a opção de agendamento, como `@monthly`.
+
+## /config_admin reward
+
+reward-added = ✅ Membros com pelo menos { $amount } agora recebem **{ $role }**.
+reward-sync-hint = Use `/config_admin reward sync` para dá-lo aos membros que já têm essa quantia.
+reward-removed = ✅ **{ $role }** não é mais um cargo de recompensa. Quem o tem continua com ele.
+reward-not-found = **{ $role }** não é um cargo de recompensa.
+reward-list-title = Cargos de recompensa
+reward-list-empty = Não há cargos de recompensa. Adicione um com `/config_admin reward add`.
+reward-list-entry = { $role } a partir de { $amount } · { $remove ->
+        [yes] removido abaixo disso
+       *[other] mantido abaixo disso
+    }
+reward-synced = ✅ { $members ->
+        [one] { $members } membro verificado
+       *[other] { $members } membros verificados
+    } e { $changes ->
+        [one] { $changes } mudança de cargo agendada
+       *[other] { $changes } mudanças de cargo agendadas
+    }. Elas são feitas aos poucos.
+
+## /flip
+
+flip-side = { $side ->
+        [heads] cara
+       *[tails] coroa
+    }
+flip-result = A moeda caiu em **{ $side }**!
+flip-invalid-guess = Palpite inválido! Use "cara" ou "coroa".
+flip-bet-server-only = O jogo de apostas só pode ser jogado em um servidor!
+flip-bet-insufficient = Você precisa de pelo menos { $amount } para jogar o jogo de apostas!
+flip-bet-won = A moeda caiu em **{ $side }**! Você acertou e ganhou { $amount }! Seu novo saldo é { $balance }.
+flip-bet-lost = A moeda caiu em **{ $side }**! Você errou e perdeu { $amount }. Seu novo saldo é { $balance }.
+flip-guess-correct = A moeda caiu em **{ $side }**! Você acertou!
+flip-guess-wrong = A moeda caiu em **{ $side }**! Você errou.
+
+## Slash command localizations
+
+cmd-give = dar
+    .description = Dar AndyCoins para um usuário (somente doadores)
+    .amount = quantidade
+    .amount-description = Quantidade de AndyCoins a dar
+    .user = usuario
+    .user-description = Usuário que vai receber os AndyCoins
+cmd-balance = saldo
+    .description = Ver seu saldo de AndyCoins ou o de outro usuário
+    .user = usuario
+    .user-description = Usuário cujo saldo ver (padrão: você)
+    .global = global
+    .global-description = Mostrar o saldo total em todos os servidores (padrão: só este servidor)
+cmd-leaderboard = ranking
+    .description = Mostrar o ranking de AndyCoins
+    .page = pagina
+    .page-description = Página a mostrar (padrão: 1)
+    .global = global
+    .global-description = Mostrar o ranking global de todos os servidores (padrão: só este servidor)
+    .period = periodo
+    .period-description = Classificar ganhos da última semana ou mês (padrão: tudo)
+    .board = quadro
+    .board-description = Classificar moedas ou membros por moedas doadas (padrão: moedas)
+cmd-rank = posicao
+    .description = Mostrar a sua posição ou a de outro usuário no ranking
+    .user = usuario
+    .user-description = Usuário a consultar (padrão: você)
+    .global = global
+    .global-description = Posição em todos os servidores (padrão: só este servidor)
+cmd-seasons = temporadas
+    .description = Ver a temporada atual e os campeões anteriores
+cmd-flip = cara_ou_coroa
+    .description = Jogar uma moeda
+    .guess = palpite
+    .guess-description = Cara ou coroa, se quiser adivinhar
+    .bet = apostar
+    .bet-description = Apostar uma moeda no seu palpite
+cmd-vote = votar
+    .description = Iniciar uma votação para zerar todos os AndyCoins do servidor ou votar
+cmd-vote-start = iniciar
+    .description = Iniciar uma votação para zerar todos os AndyCoins do servidor
+    .mode = modo
+    .mode-description = Como os saldos mudam se a votação for aprovada (padrão: o modo do servidor)
+    .amount = quantia
+    .amount-description = Porcentagem a manter ou taxar, ou o limite, para os modos que precisam
+cmd-vote-yes = sim
+    .description = Votar a favor de uma proposta aberta
+    .proposal = proposta
+    .proposal-description = Número da proposta em que você vota (padrão: a única aberta)
+cmd-vote-no = nao
+    .description = Votar contra uma proposta aberta
+    .proposal = proposta
+    .proposal-description = Número da proposta em que você vota (padrão: a única aberta)
+cmd-vote-status = status
+    .description = Ver o status da votação atual
+    .proposal = proposta
+    .proposal-description = Número da proposta a consultar (padrão: todas as abertas)
+cmd-vote-history = historico
+    .description = Ver as votações e redefinições anteriores
+    .kind = tipo
+    .kind-description = Mostrar só propostas deste tipo
+cmd-vote-stats = estatisticas
+    .description = Ver a taxa de aprovação, a participação e os votantes mais ativos
+cmd-vote-remind = lembrar
+    .description = Receber uma DM pouco antes das votações deste servidor terminarem
+    .enabled = ativado
+    .enabled-description = Se você quer lembretes das votações em que ainda não votou
+cmd-vote_admin = votacao_admin
+    .description = Gerenciar as votações
+cmd-vote_admin-config = configurar
+    .description = Configurar as votações
+    .cooldown_hours = horas_espera
+    .cooldown_hours-description = Horas de espera entre votações (padrão: 24)
+    .duration_minutes = minutos_duracao
+    .duration_minutes-description = Duração da votação em minutos (padrão: 30)
+    .min_votes = votos_minimos
+    .min_votes-description = Número mínimo de votos necessários (padrão: 10)
+    .majority_percentage = porcentagem_maioria
+    .majority_percentage-description = Porcentagem de votos SIM necessária para aprovar (padrão: 70)
+    .kind = tipo
+    .kind-description = Tipo de proposta a configurar (padrão: reinício)
+    .restore_grace_hours = horas_restauracao
+    .restore_grace_hours-description = Horas após uma redefinição em que os administradores podem restaurá-la (padrão: 24)
+    .cancel_cooldown = espera_ao_cancelar
+    .cancel_cooldown-description = Se votações canceladas iniciam a espera (padrão: não)
+    .secret = secreto
+    .secret-description = Se as votações são secretas e escondem a contagem parcial (padrão: não)
+cmd-vote_admin-eligibility = requisitos
+    .description = Configura quem pode votar e como os votos são ponderados
+    .kind = tipo
+    .kind-description = Tipo de proposta a configurar (padrão: redefinição)
+    .min_account_age_days = idade_conta_dias
+    .min_account_age_days-description = Idade mínima da conta do Discord em dias (padrão: 0)
+    .min_member_days = dias_no_servidor
+    .min_member_days-description = Dias mínimos no servidor (padrão: 0)
+    .required_role = cargo_necessario
+    .required_role-description = Cargo necessário para votar
+    .clear_required_role = remover_cargo
+    .clear_required_role-description = Remover o cargo necessário
+    .min_balance = saldo_minimo
+    .min_balance-description = Saldo mínimo para votar (padrão: 0)
+    .weighting = ponderacao
+    .weighting-description = Como os votos são ponderados (padrão: um voto por pessoa)
+cmd-vote_admin-reset_mode = modo_redefinicao
+    .description = Escolher como as redefinições mudam os saldos
+    .mode = modo
+    .mode-description = Como os saldos mudam quando uma redefinição é aprovada
+    .amount = quantia
+    .amount-description = Porcentagem a manter ou taxar, ou o limite, para os modos que precisam
+cmd-vote_admin-quorum = quorum
+    .description = Escolher quantos votos as votações precisam
+    .kind = tipo
+    .kind-description = Tipo de proposta a configurar (padrão: redefinição)
+    .mode = modo
+    .mode-description = Um número fixo de votos (min_votes) ou uma parte dos membros ativos
+    .percent = porcentagem
+    .percent-description = Porcentagem de membros ativos cujos votos são necessários
+    .days = dias
+    .days-description = Membros contam como ativos se foram vistos nesses dias
+cmd-vote_admin-reminder = lembrete
+    .description = Enviar lembretes pouco antes das votações terminarem
+    .kind = tipo
+    .kind-description = Tipo de proposta a configurar (padrão: redefinição)
+    .minutes = minutos
+    .minutes-description = Minutos antes do fim para enviar lembretes (0 desativa)
+    .role = cargo
+    .role-description = Cargo a mencionar no canal da votação
+    .clear_role = remover_cargo
+    .clear_role-description = Parar de mencionar um cargo
+cmd-vote_admin-restore = restaurar
+    .description = Devolver os saldos de uma redefinição recente
+    .snapshot = copia
+    .snapshot-description = Nome da cópia em /vote history (padrão: a mais recente)
+cmd-vote_admin-cancel = cancelar
+    .description = Cancelar uma proposta aberta sem aplicá-la
+    .proposal = proposta
+    .proposal-description = Número da proposta a cancelar (padrão: a única aberta)
+cmd-vote_admin-extend = estender
+    .description = Dar mais tempo a uma proposta aberta
+    .minutes = minutos
+    .minutes-description = Minutos a adicionar
+    .proposal = proposta
+    .proposal-description = Número da proposta a estender (padrão: a única aberta)
+cmd-vote_admin-end-now = encerrar-agora
+    .description = Encerrar agora uma proposta aberta e aplicar o resultado
+    .proposal = proposta
+    .proposal-description = Número da proposta a encerrar (padrão: a única aberta)
+cmd-propose = propor
+    .description = Propor uma mudança para o servidor votar
+cmd-propose-setting = configuracao
+    .description = Propor mudar uma configuração de votação
+    .kind = tipo
+    .kind-description = Tipo de proposta cuja configuração muda
+    .field = configuracao
+    .field-description = Configuração a mudar
+    .value = valor
+    .value-description = Novo valor
+cmd-propose-grant = conceder
+    .description = Propor dar AndyCoins a um usuário
+    .user = usuario
+    .user-description = Usuário que recebe os AndyCoins
+    .amount = quantia
+    .amount-description = Quantidade de AndyCoins a dar
+cmd-propose-giver_role = cargo_doador
+    .description = Propor adicionar ou remover um cargo de doador
+    .role = cargo
+    .role-description = Cargo a adicionar ou remover
+    .remove = remover
+    .remove-description = Remover o cargo em vez de adicioná-lo
+cmd-propose-poll = enquete
+    .description = Fazer ao servidor uma pergunta de sim ou não
+    .question = pergunta
+    .question-description = Pergunta a fazer
+cmd-config = configurar
+    .description = Ver a configuração do bot neste servidor
+cmd-config-show = mostrar
+    .description = Mostrar toda a configuração de AndyCoin deste servidor
+cmd-config-roles = cargos
+    .description = Listar os cargos de economia e os usuários permitidos e bloqueados
+cmd-config-rewards = recompensas
+    .description = Ver os cargos dados ao atingir um saldo
+cmd-config_admin = configurar_admin
+    .description = Configurar o bot neste servidor
+cmd-config_admin-role = cargo
+    .description = Gerenciar os cargos que podem dar AndyCoins
+cmd-config_admin-role-add = adicionar
+    .description = Adicionar um cargo de doador ou de administrador de economia
+    .role = cargo
+    .role-description = Cargo a adicionar
+    .kind = tipo
+    .kind-description = Tipo de cargo (padrão: doador)
+cmd-config_admin-role-remove = remover
+    .description = Remover um cargo de doador ou de administrador de economia
+    .role = cargo
+    .role-description = Cargo a remover
+    .kind = tipo
+    .kind-description = Tipo de cargo (padrão: doador)
+cmd-config_admin-user = usuario
+    .description = Permitir ou impedir que um usuário dê AndyCoins, independentemente dos cargos
+    .user = usuario
+    .user-description = Usuário a permitir ou bloquear
+    .access = acesso
+    .access-description = Permitir ou bloquear o usuário (deixe vazio para remover a exceção)
+cmd-config_admin-budget = orcamento
+    .description = Definir limites de emissão para todos os doadores ou para um cargo
+    .role = cargo
+    .role-description = Cargo ao qual aplicar os limites (padrão: todos os doadores)
+    .max_per_give = maximo_por_doacao
+    .max_per_give-description = Máximo de AndyCoins por doação (0 para sem limite)
+    .budget = orcamento
+    .budget-description = AndyCoins que cada doador pode emitir por período (0 para sem limite)
+    .period = periodo
+    .period-description = Período do orçamento, para todos os doadores (padrão: diário)
+    .clear = limpar
+    .clear-description = Remover os limites próprios do cargo para usar os padrões
+cmd-config_admin-currency = moeda
+    .description = Definir o nome, símbolo e emoji da moeda deste servidor
+    .name = nome
+    .name-description = Nome da moeda, por exemplo Peça de Ouro
+    .plural = plural
+    .plural-description = Nome no plural (padrão: o nome com um s)
+    .symbol = simbolo
+    .symbol-description = Símbolo curto para visualizações compactas, por exemplo po ("none" para remover)
+    .emoji = emoji
+    .emoji-description = Emoji mostrado com os valores ("none" para remover)
+    .reset = redefinir
+    .reset-description = Voltar para AndyCoins
+cmd-config_admin-language = idioma
+    .description = Definir o idioma para usuários cujo idioma do Discord não é suportado
+    .language = idioma
+    .language-description = Idioma a usar (deixe vazio para usar inglês)
+cmd-config_admin-leaderboard = ranking
+    .description = Publicar o ranking do servidor em um canal em um agendamento
+    .channel = canal
+    .channel-description = Canal onde publicar (padrão: este canal)
+    .schedule = agendamento
+    .schedule-description = Quando publicar, como expressão cron em UTC como "0 18 * * 5" ou @weekly
+    .off = desativar
+    .off-description = Parar de publicar o ranking
+cmd-config_admin-season = temporada
+    .description = Temporadas que zeram os saldos em um agendamento e dão um cargo aos primeiros
+    .schedule = agendamento
+    .schedule-description = Quando cada temporada termina, como expressão cron em UTC como "0 0 1 * *" ou @monthly
+    .role = cargo
+    .role-description = Cargo para os primeiros de cada temporada
+    .winners = vencedores
+    .winners-description = Quantos dos primeiros recebem o cargo (0 para nenhum cargo)
+    .channel = canal
+    .channel-description = Canal onde anunciar a classificação final
+    .off = desativar
+    .off-description = Parar as temporadas; as passadas são mantidas
+cmd-config_admin-reward = recompensa
+    .description = Gerenciar os cargos dados ao atingir um saldo
+cmd-config_admin-reward-add = adicionar
+    .description = Dar um cargo aos membros cujo saldo atinja um limite
+    .role = cargo
+    .role-description = Cargo a dar
+    .threshold = limite
+    .threshold-description = Saldo a partir do qual os membros recebem o cargo
+    .remove_below = remover_abaixo
+    .remove_below-description = Remover o cargo quando o saldo cair abaixo do limite (padrão: manter)
+cmd-config_admin-reward-remove = remover
+    .description = Parar de dar um cargo ao atingir um saldo
+    .role = cargo
+    .role-description = Cargo de recompensa que deixa de ser dado
+cmd-config_admin-reward-sync = sincronizar
+    .description = Dar e remover cargos de recompensa para que correspondam aos saldos atuais
+cmd-config_admin-export = exportar
+    .description = Exportar as configurações deste servidor como arquivo YAML
+cmd-config_admin-import = importar
+    .description = Importar configurações de um arquivo YAML criado com /config_admin export
+    .file = arquivo
+    .file-description = Arquivo de configuração do /config_admin export
+choice-giver = doador
+choice-economy-admin = administrador de economia
+choice-allow = permitir
+choice-deny = bloquear
+choice-daily = Diário
+choice-weekly = Semanal
+choice-reset = Reinício
+choice-vote-setting = Configuração de votação
+choice-grant = Concessão
+choice-giver-role = Cargo de doador
+choice-poll = Enquete
+choice-cooldown-hours = Horas de espera
+choice-duration-minutes = Minutos de duração
+choice-minimum-votes = Mínimo de votos
+choice-majority-percentage = Porcentagem de maioria
+choice-one-vote-per-person = Um voto por pessoa
+choice-balance-weighted = Ponderado pelo saldo
+choice-quadratic = Quadrática
+choice-reset-to-zero = Zerar
+choice-scale = Escalar
+choice-cap = Limite
+choice-redistribute = Redistribuir
+choice-tax = Imposto
+choice-fixed-number-of-votes = Número fixo de votos
+choice-percentage-of-active-members = Porcentagem de membros ativos
+choice-week = Semana
+choice-month = Mês
+choice-all-time = Tudo
+choice-coins = Moedas
+choice-top-givers = Maiores doadores
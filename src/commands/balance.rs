@@ -1,4 +1,8 @@
-use crate::{Context, Data, Error, logging};
+use crate::{
+    Context, Data, Error,
+    i18n::{self, tr},
+    logging,
+};
 use poise::serenity_prelude::{self as serenity, GuildId, User};
 
 // Core business logic for checking balance
@@ -37,16 +41,36 @@ pub async fn balance(
     // Call the testable business logic function
     let balance = get_balance(ctx.data(), target_user.id, guild_id, is_global);
 
-    let scope = if is_global || guild_id.is_none() {
-        "across all servers"
-    } else {
-        "in this server"
-    };
+    let locale = i18n::locale(ctx);
+    let is_server = !is_global && guild_id.is_some();
 
-    let response = if target_user.id == ctx.author().id {
-        format!("You have {balance} AndyCoins {scope}.")
+    // Global balances are shown in the default currency
+    let currency = if is_global {
+        ctx.data().get_currency(None)
     } else {
-        format!("{} has {balance} AndyCoins {scope}.", target_user.tag())
+        ctx.data().get_currency(guild_id)
+    };
+    let amount = currency.format(balance);
+    let name = ctx
+        .data()
+        .names
+        .display_name(guild_id.filter(|_| is_server), target_user);
+
+    let response = match (target_user.id == ctx.author().id, is_server) {
+        (true, true) => tr!(locale, "balance-self-server", amount = amount),
+        (true, false) => tr!(locale, "balance-self-global", amount = amount),
+        (false, true) => tr!(
+            locale,
+            "balance-other-server",
+            user = name.as_str(),
+            amount = amount
+        ),
+        (false, false) => tr!(
+            locale,
+            "balance-other-global",
+            user = name.as_str(),
+            amount = amount
+        ),
     };
 
     ctx.say(response).await?;
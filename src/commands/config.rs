@@ -1,69 +1,1112 @@
-use crate::{Context, Error, data::DataInner, logging};
+use crate::{
+    Context, Error,
+    cron::CronSpec,
+    data::{
+        BalanceReward, BudgetPeriod, CurrencyConfig, DEFAULT_SEASON_WINNERS, DataInner,
+        EconomyRole, GiverPolicy, GuildConfig, MintLimits, SeasonConfig, UserAccess,
+    },
+    i18n::{self, Language, tr},
+    logging,
+    permissions::{self, AccessLevel},
+    voting::ProposalKind,
+};
+
+use super::{require_guild, vote::proposal_summaries};
 use poise::serenity_prelude::{self as serenity, GuildId};
 
-/// Set the giver role for a server
-#[poise::command(slash_command, guild_only)]
-pub async fn role(
+/// Require the access level needed to manage economy roles of `kind`.
+///
+/// Economy admins can manage givers and the user lists; managing economy admins
+/// needs a server administrator.
+async fn require_manage(ctx: Context<'_>, kind: EconomyRole) -> Result<bool, Error> {
+    let level = match kind {
+        EconomyRole::Giver => AccessLevel::EconomyAdmin,
+        EconomyRole::Admin => AccessLevel::Administrator,
+    };
+    permissions::require(ctx, level).await
+}
+
+/// Manage the roles that can give AndyCoins
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("role_add", "role_remove"),
+    subcommand_required
+)]
+pub async fn role(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Add a giver or economy admin role
+#[poise::command(slash_command, guild_only, rename = "add")]
+pub async fn role_add(
     ctx: Context<'_>,
-    #[description = "Role that can give AndyCoins"] role: Option<serenity::Role>,
+    #[description = "Role to add"] role: serenity::Role,
+    #[description = "Kind of role (default: giver)"] kind: Option<EconomyRole>,
 ) -> Result<(), Error> {
-    let guild_id = if let Some(id) = ctx.guild_id() {
-        id
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let kind = kind.unwrap_or(EconomyRole::Giver);
+
+    if !require_manage(ctx, kind).await? {
+        return Ok(());
+    }
+
+    let locale = i18n::locale(ctx);
+    let response = if ctx.data().add_economy_role(guild_id, kind, role.id) {
+        // Save the updated data
+        ctx.data().save().await?;
+        tr!(
+            locale,
+            "role-added",
+            role = role.name.as_str(),
+            kind = kind.label(locale)
+        )
     } else {
-        ctx.say("This command can only be used in a server!")
-            .await?;
+        tr!(
+            locale,
+            "role-already-added",
+            role = role.name.as_str(),
+            kind = kind.label(locale)
+        )
+    };
+
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "role_add",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("role: {}, kind: {kind:?}", role.name),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Remove a giver or economy admin role
+#[poise::command(slash_command, guild_only, rename = "remove")]
+pub async fn role_remove(
+    ctx: Context<'_>,
+    #[description = "Role to remove"] role: serenity::Role,
+    #[description = "Kind of role (default: giver)"] kind: Option<EconomyRole>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
         return Ok(());
     };
+    let kind = kind.unwrap_or(EconomyRole::Giver);
 
-    // Check if the command user is the server owner
-    let is_owner = if let Some(guild) = ctx.guild() {
-        guild.owner_id == ctx.author().id
+    if !require_manage(ctx, kind).await? {
+        return Ok(());
+    }
+
+    let locale = i18n::locale(ctx);
+    let response = if ctx.data().remove_economy_role(guild_id, kind, role.id) {
+        // Save the updated data
+        ctx.data().save().await?;
+        tr!(
+            locale,
+            "role-removed",
+            role = role.name.as_str(),
+            kind = kind.label(locale)
+        )
     } else {
-        false
+        tr!(
+            locale,
+            "role-not-added",
+            role = role.name.as_str(),
+            kind = kind.label(locale)
+        )
     };
 
-    if !is_owner {
-        ctx.say("Only the server owner can set the giver role!")
-            .await?;
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "role_remove",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("role: {}, kind: {kind:?}", role.name),
+        true,
+    );
+
+    Ok(())
+}
+
+/// List the giver and economy admin roles and the user allowlist and denylist
+#[poise::command(slash_command, guild_only, rename = "roles")]
+pub async fn role_list(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let locale = i18n::locale(ctx);
+    let config = ctx.data().get_guild_config(guild_id);
+
+    let response = format!(
+        "**{}**\n{}\n{}",
+        tr!(locale, "role-list-title"),
+        roles_summary(locale, &config),
+        tr!(
+            locale,
+            "role-list-owner-note",
+            plural = config.currency.plural.as_str()
+        )
+    );
+
+    // Listing the roles shouldn't ping them
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "role_list",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        "",
+        true,
+    );
+
+    Ok(())
+}
+
+/// Manage the roles granted at balance thresholds
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("reward_add", "reward_remove", "reward_sync"),
+    subcommand_required
+)]
+pub async fn reward(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Grant a role to members whose balance reaches a threshold
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "add",
+    check = "permissions::economy_admin_check"
+)]
+pub async fn reward_add(
+    ctx: Context<'_>,
+    #[description = "Role to grant"] role: serenity::Role,
+    #[description = "Balance at which members get the role"]
+    #[min = 1]
+    threshold: u32,
+    #[description = "Remove the role when the balance drops below (default: keep it)"]
+    remove_below: Option<bool>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+    let remove_below = remove_below.unwrap_or(false);
+
+    ctx.data().set_balance_reward(
+        guild_id,
+        BalanceReward {
+            role_id: role.id.get(),
+            threshold,
+            remove_below,
+        },
+    );
+
+    // Save the updated data
+    ctx.data().save().await?;
+
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let response = format!(
+        "{}\n{}",
+        tr!(
+            locale,
+            "reward-added",
+            role = role.name.as_str(),
+            amount = currency.format(threshold)
+        ),
+        tr!(locale, "reward-sync-hint")
+    );
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "reward_add",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "role: {}, threshold: {threshold}, remove_below: {remove_below}",
+            role.name
+        ),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Stop granting a role at a balance threshold
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "remove",
+    check = "permissions::economy_admin_check"
+)]
+pub async fn reward_remove(
+    ctx: Context<'_>,
+    #[description = "Reward role to stop granting"] role: serenity::Role,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    let response = if ctx.data().remove_balance_reward(guild_id, role.id) {
+        // Save the updated data
+        ctx.data().save().await?;
+        tr!(locale, "reward-removed", role = role.name.as_str())
+    } else {
+        tr!(locale, "reward-not-found", role = role.name.as_str())
+    };
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "reward_remove",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("role: {}", role.name),
+        true,
+    );
+
+    Ok(())
+}
+
+/// List the roles granted at balance thresholds
+#[poise::command(slash_command, guild_only, rename = "rewards")]
+pub async fn reward_list(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    let rewards = ctx.data().get_balance_rewards(guild_id);
+    let response = if rewards.is_empty() {
+        tr!(locale, "reward-list-empty")
+    } else {
+        let currency = ctx.data().get_currency(Some(guild_id));
+        let mut lines = vec![format!("**{}**", tr!(locale, "reward-list-title"))];
+        lines.extend(rewards.iter().map(|reward| {
+            tr!(
+                locale,
+                "reward-list-entry",
+                role = format!("<@&{}>", reward.role_id),
+                amount = currency.format(reward.threshold),
+                remove = if reward.remove_below { "yes" } else { "no" }
+            )
+        }));
+        lines.join("\n")
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "reward_list",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        "",
+        true,
+    );
+
+    Ok(())
+}
+
+/// Grant and remove reward roles so they match everyone's current balance
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "sync",
+    check = "permissions::economy_admin_check"
+)]
+pub async fn reward_sync(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    if ctx.data().get_balance_rewards(guild_id).is_empty() {
+        ctx.say(tr!(locale, "reward-list-empty")).await?;
         return Ok(());
     }
+    // Members without a balance have never crossed a threshold, so only members
+    // with one are synced. Members who left the server just fail in the worker.
+    let ranked = ctx.data().get_ranked_users(Some(guild_id));
+    let members: Vec<_> = ctx
+        .data()
+        .get_ranking_page(Some(guild_id), 0, ranked)
+        .into_iter()
+        .map(|user| user.user_id)
+        .collect();
 
-    let response;
-    let role_name_for_log;
+    let changes = ctx.data().reward_sync_changes(guild_id, &members);
+    let count = changes.len();
+    ctx.data().queue_role_changes(changes);
+    ctx.say(tr!(
+        locale,
+        "reward-synced",
+        changes = count,
+        members = members.len()
+    ))
+    .await?;
 
-    if let Some(r) = role {
-        // Set the giver role
-        let role_name = r.name.clone();
-        role_name_for_log = role_name.clone();
-        ctx.data().set_giver_role(guild_id, Some(r.id));
-        response = format!(
-            "Set {role_name} as the giver role. Users with this role can now give AndyCoins."
-        );
+    // Log successful command execution
+    logging::log_command(
+        "reward_sync",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("members: {}, changes: {count}", members.len()),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Allow or deny a specific user giving AndyCoins, regardless of their roles
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn user(
+    ctx: Context<'_>,
+    #[description = "User to allow or deny"] user: serenity::User,
+    #[description = "Allow or deny the user (leave empty to clear)"] access: Option<UserAccess>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let currency = ctx.data().get_currency(Some(guild_id));
+
+    ctx.data().set_user_access(guild_id, user.id, access);
+
+    // Save the updated data
+    ctx.data().save().await?;
+
+    let locale = i18n::locale(ctx);
+    let id = match access {
+        Some(UserAccess::Allow) => "user-access-allowed",
+        Some(UserAccess::Deny) => "user-access-denied",
+        None => "user-access-cleared",
+    };
+    let response = tr!(
+        locale,
+        id,
+        user = ctx.data().names.display_name(Some(guild_id), &user),
+        plural = currency.plural.as_str()
+    );
+
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "user_access",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("user: {}, access: {access:?}", user.tag()),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Largest settings file accepted by `/config_admin import`
+const MAX_IMPORT_BYTES: u32 = 64 * 1024;
+
+/// Format a list of IDs as mentions, or "none"
+fn mention_list(locale: &str, ids: &[u64], prefix: &str) -> String {
+    if ids.is_empty() {
+        tr!(locale, "none")
+    } else {
+        ids.iter()
+            .map(|id| format!("<{prefix}{id}>"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Describe the economy roles and the user allowlist and denylist
+fn roles_summary(locale: &str, config: &GuildConfig) -> String {
+    tr!(
+        locale,
+        "config-roles",
+        admins = mention_list(locale, &config.admin_role_ids, "@&"),
+        givers = mention_list(locale, &config.giver_role_ids, "@&"),
+        allowed = mention_list(locale, &config.user_allowlist, "@"),
+        denied = mention_list(locale, &config.user_denylist, "@"),
+    )
+}
+
+/// Describe the default and per-role minting limits, one per line
+fn limits_summary(locale: &str, policy: &GiverPolicy) -> String {
+    let limit_str = |limit: Option<u32>| limit.map_or(tr!(locale, "no-limit"), |l| l.to_string());
+    let period = policy.budget_period.label(locale);
+
+    let mut lines = vec![tr!(
+        locale,
+        "config-limits-default",
+        max_per_give = limit_str(policy.default_limits.max_per_give),
+        budget = limit_str(policy.default_limits.budget),
+        period = period.as_str(),
+    )];
+    for rl in &policy.role_limits {
+        lines.push(tr!(
+            locale,
+            "config-limits-role",
+            role = format!("<@&{}>", rl.role_id),
+            max_per_give = limit_str(rl.limits.max_per_give),
+            budget = limit_str(rl.limits.budget),
+            period = period.as_str(),
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Show this server's full AndyCoin configuration
+#[poise::command(slash_command, guild_only)]
+pub async fn show(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let config = ctx.data().get_guild_config(guild_id);
+    let locale = i18n::locale(ctx);
+
+    let vote_config = &config.vote_config;
+    let vote_settings = tr!(
+        locale,
+        "config-vote-settings",
+        cooldown_hours = vote_config.cooldown_hours,
+        duration_minutes = vote_config.duration_minutes,
+        min_votes = vote_config.min_votes,
+        majority = vote_config.majority_percentage,
+    );
+
+    let open = config.open_proposals();
+    let vote_state = if !open.is_empty() {
+        let open: Vec<_> = open.into_iter().cloned().collect();
+        proposal_summaries(locale, &open, &config.currency)
+    } else {
+        match config.cooldown_end(ProposalKind::Reset, chrono::Utc::now()) {
+            Some(until) => tr!(
+                locale,
+                "config-vote-cooldown",
+                cooldown_end = until.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+            ),
+            None => tr!(locale, "config-vote-idle"),
+        }
+    };
+
+    let currency = &config.currency;
+    let currency_str = tr!(
+        locale,
+        "config-currency",
+        name = currency.name.as_str(),
+        plural = currency.plural.as_str(),
+        symbol = currency
+            .symbol
+            .clone()
+            .unwrap_or_else(|| tr!(locale, "none")),
+        emoji = currency
+            .emoji
+            .clone()
+            .unwrap_or_else(|| tr!(locale, "none")),
+    );
+
+    let language = config
+        .locale
+        .as_deref()
+        .and_then(i18n::supported_locale)
+        .map_or(tr!(locale, "config-language-auto"), |code| {
+            tr!(locale, "language-name", code = code)
+        });
+
+    let embed = serenity::CreateEmbed::new()
+        .title(tr!(locale, "config-title"))
+        .field(tr!(locale, "config-field-currency"), currency_str, false)
+        .field(tr!(locale, "config-field-language"), language, false)
+        .field(
+            tr!(locale, "config-field-roles"),
+            roles_summary(locale, &config),
+            false,
+        )
+        .field(
+            tr!(locale, "config-field-limits"),
+            limits_summary(locale, &config.giver_policy),
+            false,
+        )
+        .field(
+            tr!(locale, "config-field-vote-settings"),
+            vote_settings,
+            true,
+        )
+        .field(tr!(locale, "config-field-vote-status"), vote_state, true);
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "config_show",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        "",
+        true,
+    );
+
+    Ok(())
+}
+
+/// Export this server's settings as a YAML file
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn export(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+    let yaml = ctx.data().export_guild_settings(guild_id)?;
+
+    let attachment = serenity::CreateAttachment::bytes(
+        yaml.into_bytes(),
+        format!("andy_coin_settings_{guild_id}.yaml"),
+    );
+    ctx.send(
+        poise::CreateReply::default()
+            .content(tr!(locale, "config-exported"))
+            .attachment(attachment)
+            .ephemeral(true),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "config_export",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        "",
+        true,
+    );
+
+    Ok(())
+}
+
+/// Import server settings from a YAML file made with `/config_admin export`
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn import(
+    ctx: Context<'_>,
+    #[description = "Settings file from /config_admin export"] file: serenity::Attachment,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let args = format!("file: {}", file.filename);
+    let locale = i18n::locale(ctx);
+
+    if file.size > MAX_IMPORT_BYTES {
+        ctx.say(tr!(
+            locale,
+            "import-too-large",
+            max_kib = MAX_IMPORT_BYTES / 1024
+        ))
+        .await?;
+        return Ok(());
+    }
+
+    let bytes = file.download().await?;
+    let Ok(yaml) = String::from_utf8(bytes) else {
+        ctx.say(tr!(locale, "import-not-yaml")).await?;
+        return Ok(());
+    };
+
+    let config = match ctx.data().import_guild_settings(guild_id, &yaml) {
+        Ok(config) => config,
+        Err(e) => {
+            ctx.say(tr!(locale, "import-invalid", error = e.to_string()))
+                .await?;
+            logging::log_command(
+                "config_import",
+                Some(guild_id.get()),
+                ctx.author().id.get(),
+                &args,
+                false,
+            );
+            return Ok(());
+        }
+    };
+
+    // Save the updated data
+    ctx.data().save().await?;
+
+    // Roles from another server won't exist here
+    let missing_roles = ctx.guild().map_or(0, |guild| {
+        config
+            .giver_role_ids
+            .iter()
+            .chain(&config.admin_role_ids)
+            .chain(config.giver_policy.role_limits.iter().map(|rl| &rl.role_id))
+            .chain(config.balance_rewards.iter().map(|reward| &reward.role_id))
+            .filter(|id| !guild.roles.contains_key(&serenity::RoleId::new(**id)))
+            .count()
+    });
+
+    let mut response = tr!(locale, "import-success");
+    if missing_roles > 0 {
+        response.push('\n');
+        response.push_str(&tr!(locale, "import-missing-roles", count = missing_roles));
+    }
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "config_import",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &args,
+        true,
+    );
+
+    Ok(())
+}
+
+/// Longest currency name, symbol or emoji accepted
+const MAX_CURRENCY_TEXT: usize = 32;
+
+/// Set this server's currency name, symbol and emoji
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn currency(
+    ctx: Context<'_>,
+    #[description = "Currency name, e.g. Gold Piece"] name: Option<String>,
+    #[description = "Plural name (default: name with an s)"] plural: Option<String>,
+    #[description = "Short symbol for compact views, e.g. gp (\"none\" to clear)"] symbol: Option<
+        String,
+    >,
+    #[description = "Emoji shown with amounts (\"none\" to clear)"] emoji: Option<String>,
+    #[description = "Go back to AndyCoins"] reset: Option<bool>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    let texts = [&name, &plural, &symbol, &emoji];
+    if texts.iter().any(|t| {
+        t.as_ref()
+            .is_some_and(|t| t.trim().is_empty() || t.chars().count() > MAX_CURRENCY_TEXT)
+    }) {
+        ctx.say(tr!(locale, "currency-invalid", max = MAX_CURRENCY_TEXT))
+            .await?;
+        return Ok(());
+    }
+
+    let mut currency = if reset.unwrap_or(false) {
+        CurrencyConfig::default()
     } else {
-        // Clear the giver role
-        role_name_for_log = "None".to_string();
-        ctx.data().set_giver_role(guild_id, None);
-        response =
-            "Cleared the giver role. Only the server owner can give AndyCoins now.".to_string();
+        ctx.data().get_currency(Some(guild_id))
+    };
+
+    // "none" clears an optional field, a missing option keeps the current value
+    let apply = |current: Option<String>, new: Option<String>| match new {
+        Some(value) if value.trim().eq_ignore_ascii_case("none") => None,
+        Some(value) => Some(value.trim().to_string()),
+        None => current,
+    };
+
+    if let Some(name) = &name {
+        currency.name = name.trim().to_string();
+        currency.plural = format!("{}s", currency.name);
     }
+    if let Some(plural) = &plural {
+        currency.plural = plural.trim().to_string();
+    }
+    currency.symbol = apply(currency.symbol, symbol.clone());
+    currency.emoji = apply(currency.emoji, emoji.clone());
+
+    // The currency shows up in many replies, none of which should ping anyone
+    if currency.has_mentions() {
+        ctx.say(tr!(locale, "currency-mention")).await?;
+        return Ok(());
+    }
+
+    ctx.data().set_currency(guild_id, currency.clone());
 
     // Save the updated data
     ctx.data().save().await?;
 
+    ctx.say(tr!(
+        locale,
+        "currency-updated",
+        one = currency.format(1),
+        many = currency.format(100),
+        short = currency.format_short(100),
+    ))
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "currency",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "name: {name:?}, plural: {plural:?}, symbol: {symbol:?}, emoji: {emoji:?}, reset: {reset:?}"
+        ),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Set minting limits for givers, for everyone or for a specific role
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn budget(
+    ctx: Context<'_>,
+    #[description = "Role to set limits for (default: all givers)"] role: Option<serenity::Role>,
+    #[description = "Maximum AndyCoins per give (0 for no limit)"] max_per_give: Option<u32>,
+    #[description = "AndyCoins each giver can mint per period (0 for no limit)"] budget: Option<
+        u32,
+    >,
+    #[description = "Budget period, applies to all givers (default: daily)"] period: Option<
+        BudgetPeriod,
+    >,
+    #[description = "Remove the role's own limits so the defaults apply"] clear: Option<bool>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let mut policy = ctx.data().get_giver_policy(guild_id);
+
+    // 0 clears a limit, a missing option keeps the current value
+    let apply = |current: Option<u32>, new: Option<u32>| match new {
+        Some(0) => None,
+        Some(value) => Some(value),
+        None => current,
+    };
+
+    if let Some(p) = period {
+        policy.budget_period = p;
+    }
+
+    let scope = if let Some(r) = &role {
+        if clear.unwrap_or(false) {
+            policy.set_role_limits(r.id, None);
+        } else {
+            let current = policy
+                .role_limits
+                .iter()
+                .find(|rl| rl.role_id == r.id.get())
+                .map(|rl| rl.limits)
+                .unwrap_or(policy.default_limits);
+            policy.set_role_limits(
+                r.id,
+                Some(MintLimits {
+                    max_per_give: apply(current.max_per_give, max_per_give),
+                    budget: apply(current.budget, budget),
+                }),
+            );
+        }
+        tr!(locale, "budget-scope-role", role = r.name.as_str())
+    } else {
+        policy.default_limits = MintLimits {
+            max_per_give: apply(policy.default_limits.max_per_give, max_per_give),
+            budget: apply(policy.default_limits.budget, budget),
+        };
+        tr!(locale, "budget-scope-all")
+    };
+
+    ctx.data().set_giver_policy(guild_id, policy.clone());
+
+    // Save the updated data
+    ctx.data().save().await?;
+
+    let response = format!(
+        "{}\n{}",
+        tr!(locale, "budget-updated", scope = scope.as_str()),
+        limits_summary(locale, &policy)
+    );
+
+    // Per-role limits name their roles, which shouldn't be pinged
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "budget",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "role: {:?}, max_per_give: {max_per_give:?}, budget: {budget:?}, period: {period:?}, clear: {clear:?}",
+            role.as_ref().map(|r| &r.name)
+        ),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Set the language for responses when a user's own Discord language isn't supported
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn language(
+    ctx: Context<'_>,
+    #[description = "Language to use (leave empty to use English)"] language: Option<Language>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    ctx.data()
+        .set_locale(guild_id, language.map(|l| l.code().to_string()));
+
+    // Save the updated data
+    ctx.data().save().await?;
+
+    let locale = i18n::locale(ctx);
+    let response = match language {
+        Some(language) => tr!(
+            locale,
+            "language-updated",
+            language = tr!(locale, "language-name", code = language.code())
+        ),
+        None => tr!(locale, "language-cleared"),
+    };
     ctx.say(response).await?;
 
     // Log successful command execution
     logging::log_command(
-        "role",
+        "language",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("language: {language:?}"),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Post the server leaderboard to a channel on a schedule
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn leaderboard(
+    ctx: Context<'_>,
+    #[description = "Channel to post in (default: this channel)"] channel: Option<
+        serenity::GuildChannel,
+    >,
+    #[description = "When to post, as a cron spec in UTC like \"0 18 * * 5\" or @weekly"]
+    schedule: Option<String>,
+    #[description = "Stop posting the leaderboard"] off: Option<bool>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let current = ctx.data().get_guild_config(guild_id).leaderboard_schedule;
+
+    // Moving the posts to another channel keeps the current spec
+    let spec = schedule.or_else(|| {
+        channel
+            .as_ref()
+            .and(current.as_ref())
+            .map(|current| current.spec.clone())
+    });
+    let response = if off.unwrap_or(false) {
+        ctx.data()
+            .set_leaderboard_schedule(guild_id, None, chrono::Utc::now());
+        ctx.data().save().await?;
+        tr!(locale, "leaderboard-schedule-off")
+    } else if let Some(spec) = spec {
+        let channel_id = channel.as_ref().map_or(ctx.channel_id(), |c| c.id);
+        let now = chrono::Utc::now();
+        if ctx
+            .data()
+            .set_leaderboard_schedule(guild_id, Some((channel_id, spec.clone())), now)
+        {
+            ctx.data().save().await?;
+            tr!(
+                locale,
+                "leaderboard-schedule-set",
+                channel = format!("<#{channel_id}>"),
+                spec = spec.as_str(),
+                next = next_post(locale, &spec, now)
+            )
+        } else {
+            tr!(locale, "leaderboard-schedule-invalid", spec = spec.as_str())
+        }
+    } else if let Some(current) = current {
+        tr!(
+            locale,
+            "leaderboard-schedule-show",
+            channel = format!("<#{}>", current.channel_id),
+            spec = current.spec.as_str(),
+            next = next_post(locale, &current.spec, current.last_run)
+        )
+    } else {
+        tr!(locale, "leaderboard-schedule-none")
+    };
+    ctx.say(response).await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "leaderboard",
         Some(guild_id.get()),
         ctx.author().id.get(),
-        &format!("role: {role_name_for_log}"),
+        &format!(
+            "channel: {:?}, off: {off:?}",
+            channel.as_ref().map(|c| c.id.get())
+        ),
         true,
     );
 
     Ok(())
 }
 
+/// Run seasons that reset balances on a schedule and award the top finishers a role
+#[allow(clippy::too_many_arguments)]
+#[poise::command(slash_command, guild_only, check = "permissions::economy_admin_check")]
+pub async fn season(
+    ctx: Context<'_>,
+    #[description = "When each season ends, as a cron spec in UTC like \"0 0 1 * *\" or @monthly"]
+    schedule: Option<String>,
+    #[description = "Role awarded to each season's top finishers"] role: Option<serenity::Role>,
+    #[description = "How many top finishers get the role (0 for no role)"]
+    #[max = 10]
+    winners: Option<u32>,
+    #[description = "Channel to announce the final standings in"] channel: Option<
+        serenity::GuildChannel,
+    >,
+    #[description = "Stop running seasons; past seasons are kept"] off: Option<bool>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let current = ctx.data().get_season(guild_id);
+    let changed = schedule.is_some() || role.is_some() || winners.is_some() || channel.is_some();
+
+    let response = if off.unwrap_or(false) {
+        ctx.data().set_season(guild_id, None, chrono::Utc::now());
+        ctx.data().save().await?;
+        tr!(locale, "season-off")
+    } else if changed {
+        match schedule.or_else(|| current.as_ref().map(|season| season.spec.clone())) {
+            Some(spec) => {
+                let mut season = current.unwrap_or_default();
+                season.spec = spec.clone();
+                if let Some(role) = &role {
+                    season.award_role = Some(role.id.get());
+                    season.winners = winners.unwrap_or(DEFAULT_SEASON_WINNERS);
+                } else if let Some(winners) = winners {
+                    season.winners = winners;
+                }
+                if season.winners == 0 {
+                    season.award_role = None;
+                }
+                if let Some(channel) = &channel {
+                    season.channel_id = Some(channel.id.get());
+                }
+                if ctx
+                    .data()
+                    .set_season(guild_id, Some(season), chrono::Utc::now())
+                {
+                    ctx.data().save().await?;
+                    format!(
+                        "{}\n{}",
+                        tr!(locale, "season-updated"),
+                        season_summary(
+                            locale,
+                            &ctx.data().get_season(guild_id).unwrap_or_default()
+                        )
+                    )
+                } else {
+                    tr!(locale, "leaderboard-schedule-invalid", spec = spec.as_str())
+                }
+            }
+            None => tr!(locale, "season-needs-schedule"),
+        }
+    } else if let Some(current) = current {
+        season_summary(locale, &current)
+    } else {
+        tr!(locale, "season-none")
+    };
+    // The summary names the award role, which shouldn't be pinged
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "season",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "role: {:?}, winners: {winners:?}, channel: {:?}, off: {off:?}",
+            role.as_ref().map(|r| &r.name),
+            channel.as_ref().map(|c| c.id.get())
+        ),
+        true,
+    );
+
+    Ok(())
+}
+
+/// Describe the current season and its settings
+fn season_summary(locale: &str, season: &SeasonConfig) -> String {
+    let end = season.ends_at.map_or_else(
+        || tr!(locale, "leaderboard-schedule-never"),
+        |end| format!("<t:{}:F>", end.timestamp()),
+    );
+    let mut lines = vec![tr!(
+        locale,
+        "season-show",
+        number = season.number,
+        spec = season.spec.as_str(),
+        end = end
+    )];
+    if let Some(role_id) = season.award_role {
+        lines.push(tr!(
+            locale,
+            "season-show-award",
+            role = format!("<@&{role_id}>"),
+            winners = season.winners
+        ));
+    }
+    if let Some(channel_id) = season.channel_id {
+        lines.push(tr!(
+            locale,
+            "season-show-channel",
+            channel = format!("<#{channel_id}>")
+        ));
+    }
+    lines.join("\n")
+}
+
+/// When a leaderboard schedule posts next after `last_run`, for showing in Discord
+fn next_post(locale: &str, spec: &str, last_run: chrono::DateTime<chrono::Utc>) -> String {
+    CronSpec::parse(spec)
+        .and_then(|spec| spec.next_after(last_run))
+        .map_or_else(
+            || tr!(locale, "leaderboard-schedule-never"),
+            |next| format!("<t:{}:F>", next.timestamp()),
+        )
+}
+
 /// Flip a coin
 #[poise::command(slash_command, prefix_command)]
 pub async fn flip(
@@ -73,10 +1116,12 @@ pub async fn flip(
 ) -> Result<(), Error> {
     let result = DataInner::flip_coin();
     let result_str = if result { "heads" } else { "tails" };
+    let locale = i18n::locale(ctx);
+    let side = tr!(locale, "flip-side", side = result_str);
 
     // If no guess is provided, just show the result
     if guess.is_none() && bet.is_none() {
-        ctx.say(format!("The coin landed on **{result_str}**!"))
+        ctx.say(tr!(locale, "flip-result", side = side.as_str()))
             .await?;
 
         // Log simple flip
@@ -94,12 +1139,18 @@ pub async fn flip(
     // If a guess is provided, check if it's correct
     if let Some(guess_str) = guess {
         let guess_lower = guess_str.to_lowercase();
-        let is_heads = guess_lower == "heads" || guess_lower == "head" || guess_lower == "h";
-        let is_tails = guess_lower == "tails" || guess_lower == "tail" || guess_lower == "t";
+        // Accept the localized names for the sides too
+        let is_heads = guess_lower == "heads"
+            || guess_lower == "head"
+            || guess_lower == "h"
+            || guess_lower == tr!(locale, "flip-side", side = "heads").to_lowercase();
+        let is_tails = guess_lower == "tails"
+            || guess_lower == "tail"
+            || guess_lower == "t"
+            || guess_lower == tr!(locale, "flip-side", side = "tails").to_lowercase();
 
         if !is_heads && !is_tails {
-            ctx.say("Invalid guess! Please use 'heads' or 'tails'.")
-                .await?;
+            ctx.say(tr!(locale, "flip-invalid-guess")).await?;
             return Ok(());
         }
 
@@ -110,29 +1161,46 @@ pub async fn flip(
             let guild_id = if let Some(id) = ctx.guild_id() {
                 id
             } else {
-                ctx.say("The betting game can only be played in a server!")
-                    .await?;
+                ctx.say(tr!(locale, "flip-bet-server-only")).await?;
                 return Ok(());
             };
 
             let user_id = ctx.author().id;
             let current_balance = ctx.data().get_guild_balance(guild_id, user_id);
+            let currency = ctx.data().get_currency(Some(guild_id));
 
             if current_balance < 1 {
-                ctx.say("You need at least 1 AndyCoin to play the betting game!")
-                    .await?;
+                ctx.say(tr!(
+                    locale,
+                    "flip-bet-insufficient",
+                    amount = currency.format(1)
+                ))
+                .await?;
                 return Ok(());
             }
 
             if guess_result == result {
                 // Win: add a coin
                 let new_balance = ctx.data().add_coins(guild_id, user_id, 1);
-                ctx.say(format!("The coin landed on **{result_str}**! You guessed correctly and won 1 AndyCoin! Your new balance is {new_balance} AndyCoins.", 
-                               )).await?;
+                ctx.say(tr!(
+                    locale,
+                    "flip-bet-won",
+                    side = side.as_str(),
+                    amount = currency.format(1),
+                    balance = currency.format(new_balance)
+                ))
+                .await?;
             } else {
                 // Lose: remove a coin
                 let new_balance = ctx.data().remove_coins(guild_id, user_id, 1);
-                ctx.say(format!("The coin landed on **{result_str}**! You guessed wrong and lost 1 AndyCoin. Your new balance is {new_balance} AndyCoins.")).await?;
+                ctx.say(tr!(
+                    locale,
+                    "flip-bet-lost",
+                    side = side.as_str(),
+                    amount = currency.format(1),
+                    balance = currency.format(new_balance)
+                ))
+                .await?;
             }
 
             // Save the updated balances
@@ -159,15 +1227,11 @@ pub async fn flip(
         } else {
             // Regular guess without betting
             if guess_result == result {
-                ctx.say(format!(
-                    "The coin landed on **{result_str}**! You guessed correctly!",
-                ))
-                .await?;
+                ctx.say(tr!(locale, "flip-guess-correct", side = side.as_str()))
+                    .await?;
             } else {
-                ctx.say(format!(
-                    "The coin landed on **{result_str}**! You guessed wrong.",
-                ))
-                .await?;
+                ctx.say(tr!(locale, "flip-guess-wrong", side = side.as_str()))
+                    .await?;
             }
 
             // Log the guess result
@@ -196,10 +1260,14 @@ pub async fn flip(
     Ok(())
 }
 
-/// Command to configure the bot. Uses a subcommand structure via poise.
-#[poise::command(slash_command, subcommands("role"), owners_only)]
+/// Command to show the bot's configuration. Uses a subcommand structure via poise.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("show", "role_list", "reward_list")
+)]
 pub async fn config(ctx: Context<'_>) -> Result<(), Error> {
-    ctx.say("Use one of the subcommands: role").await?;
+    ctx.say(tr!(i18n::locale(ctx), "config-help")).await?;
 
     // Log command execution
     logging::log_command(
@@ -212,3 +1280,29 @@ pub async fn config(ctx: Context<'_>) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Configure the bot for this server
+// Hidden from members without Manage Server by default; server admins can
+// open it to economy admin roles in the integration settings. Each subcommand
+// still checks the access level it needs.
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "MANAGE_GUILD",
+    subcommands(
+        "role",
+        "user",
+        "budget",
+        "currency",
+        "language",
+        "leaderboard",
+        "season",
+        "reward",
+        "export",
+        "import"
+    ),
+    subcommand_required
+)]
+pub async fn config_admin(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
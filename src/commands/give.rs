@@ -1,27 +1,47 @@
-use crate::{Context, Data, Error, logging};
+use super::require_guild;
+use crate::{
+    Context, Data, Error,
+    data::MintError,
+    i18n::{self, tr},
+    logging, permissions,
+};
 use poise::serenity_prelude::{self as serenity, UserId};
 
+/// Result of a successful give
+#[derive(Debug, PartialEq, Eq)]
+pub struct GiveReceipt {
+    pub new_balance: u32,
+    /// Giver's remaining minting budget, `None` if unlimited
+    pub remaining_budget: Option<u32>,
+}
+
 /// Core business logic for giving coins
+///
+/// Gives made by a user are checked against the guild's giver policy using the
+/// giver's roles; system gives (no initiator) are not limited.
 pub fn give_coins(
     data: &Data,
     guild_id: serenity::GuildId,
     user_id: serenity::UserId,
     amount: u32,
     initiator_id: Option<serenity::UserId>,
-) -> u32 {
-    // Get the previous balance
-    let previous_balance = data.get_guild_balance(guild_id, user_id);
+    initiator_roles: &[serenity::RoleId],
+) -> Result<GiveReceipt, MintError> {
+    // Enforce the giver policy before minting anything
+    let remaining_budget = match initiator_id {
+        Some(giver_id) => data.reserve_mint(
+            guild_id,
+            giver_id,
+            initiator_roles,
+            amount,
+            chrono::Utc::now(),
+        )?,
+        None => None,
+    };
 
     // Add coins
-    let guild_map = data
-        .guild_balances
-        .entry(guild_id)
-        .or_insert_with(dashmap::DashMap::new);
-
-    let new_balance = *guild_map
-        .entry(user_id)
-        .and_modify(|bal| *bal += amount)
-        .or_insert(amount);
+    let (previous_balance, new_balance) =
+        data.modify_balance(guild_id, user_id, |balance| balance.saturating_add(amount));
 
     // Log the balance change
     logging::log_balance_change(
@@ -32,11 +52,21 @@ pub fn give_coins(
         "give_command",
         initiator_id.map(UserId::get),
     );
+    data.record_balance_change(
+        guild_id,
+        user_id,
+        previous_balance,
+        new_balance,
+        initiator_id,
+    );
 
-    new_balance
+    Ok(GiveReceipt {
+        new_balance,
+        remaining_budget,
+    })
 }
 
-/// Give AndyCoins to a user (server owner only)
+/// Give AndyCoins to a user (givers only)
 #[poise::command(slash_command, prefix_command, guild_only)]
 pub async fn give(
     ctx: Context<'_>,
@@ -45,11 +75,8 @@ pub async fn give(
 ) -> Result<(), Error> {
     // Log command execution
     let args = format!("amount: {amount}, user: {}", user.tag());
-    let guild_id = if let Some(id) = ctx.guild_id() {
-        id
-    } else {
-        ctx.say("This command can only be used in a server!")
-            .await?;
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
         return Ok(());
     };
 
@@ -57,26 +84,66 @@ pub async fn give(
     let member = if let Some(member) = ctx.author_member().await {
         member
     } else {
-        ctx.say("Failed to get your member information.").await?;
+        ctx.say(tr!(locale, "error-member-info")).await?;
         return Ok(());
     };
 
+    let currency = ctx.data().get_currency(Some(guild_id));
+
     // Check if the user has permission to give coins
-    if !ctx.data().has_giver_role(guild_id, &member) {
-        ctx.say("You don't have permission to give AndyCoins! Only the server owner or users with the giver role can do this.").await?;
+    if !permissions::author_can_give(ctx).await {
+        ctx.say(tr!(
+            locale,
+            "give-no-permission",
+            currency = currency.plural.as_str()
+        ))
+        .await?;
         return Ok(());
     }
 
     // Call the testable business logic function
-    let new_balance = give_coins(ctx.data(), guild_id, user.id, amount, Some(ctx.author().id));
+    let receipt = match give_coins(
+        ctx.data(),
+        guild_id,
+        user.id,
+        amount,
+        Some(ctx.author().id),
+        &member.roles,
+    ) {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            ctx.say(e.describe(locale, &currency)).await?;
+            logging::log_command(
+                "give",
+                Some(guild_id.get()),
+                ctx.author().id.get(),
+                &args,
+                false,
+            );
+            return Ok(());
+        }
+    };
 
     // Save the updated balances
     ctx.data().save().await?;
 
-    let response = format!(
-        "Gave {amount} AndyCoins to {}. Their new balance in this server is {new_balance} AndyCoins.",
-        user.tag(),
+    let mut response = tr!(
+        locale,
+        "give-success",
+        amount = currency.format(amount),
+        user = ctx.data().names.display_name(Some(guild_id), &user),
+        balance = currency.format(receipt.new_balance),
     );
+    if let Some(remaining) = receipt.remaining_budget {
+        let period = ctx.data().get_giver_policy(guild_id).budget_period;
+        response.push('\n');
+        response.push_str(&tr!(
+            locale,
+            "give-remaining-budget",
+            remaining = currency.format(remaining),
+            period = period.label(locale)
+        ));
+    }
     ctx.say(response).await?;
 
     // Log successful command execution
@@ -94,6 +161,7 @@ pub async fn give(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::{GiverPolicy, MintLimits};
 
     // Helper function to create a test user ID
     fn test_user_id(id: u64) -> serenity::UserId {
@@ -113,11 +181,58 @@ mod tests {
         let initiator_id = test_user_id(456);
 
         // Test giving coins
-        let new_balance = give_coins(&data, guild_id, user_id, 50, Some(initiator_id));
-        assert_eq!(new_balance, 50);
+        let receipt = give_coins(&data, guild_id, user_id, 50, Some(initiator_id), &[]).unwrap();
+        assert_eq!(receipt.new_balance, 50);
+        assert_eq!(receipt.remaining_budget, None);
 
         // Test giving more coins
-        let new_balance = give_coins(&data, guild_id, user_id, 25, Some(initiator_id));
-        assert_eq!(new_balance, 75);
+        let receipt = give_coins(&data, guild_id, user_id, 25, Some(initiator_id), &[]).unwrap();
+        assert_eq!(receipt.new_balance, 75);
+    }
+
+    #[test]
+    fn test_give_coins_enforces_policy() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let user_id = test_user_id(123);
+        let initiator_id = test_user_id(456);
+
+        data.set_giver_policy(
+            guild_id,
+            GiverPolicy {
+                default_limits: MintLimits {
+                    max_per_give: Some(20),
+                    budget: Some(30),
+                },
+                ..Default::default()
+            },
+        );
+
+        // Over the per-give maximum
+        let result = give_coins(&data, guild_id, user_id, 25, Some(initiator_id), &[]);
+        assert_eq!(result, Err(MintError::ExceedsMaxPerGive { max: 20 }));
+        assert_eq!(data.get_guild_balance(guild_id, user_id), 0);
+
+        // Within limits, budget is reported
+        let receipt = give_coins(&data, guild_id, user_id, 20, Some(initiator_id), &[]).unwrap();
+        assert_eq!(
+            receipt,
+            GiveReceipt {
+                new_balance: 20,
+                remaining_budget: Some(10),
+            }
+        );
+
+        // Budget exhausted, balance untouched
+        let result = give_coins(&data, guild_id, user_id, 15, Some(initiator_id), &[]);
+        assert!(matches!(
+            result,
+            Err(MintError::BudgetExhausted { remaining: 10, .. })
+        ));
+        assert_eq!(data.get_guild_balance(guild_id, user_id), 20);
+
+        // System gives are not limited
+        let receipt = give_coins(&data, guild_id, user_id, 100, None, &[]).unwrap();
+        assert_eq!(receipt.new_balance, 120);
     }
 }
@@ -1,63 +1,567 @@
-use crate::{Context, Data, Error, logging};
+use crate::{
+    Context, Data, Error,
+    data::{BalanceChanges, CurrencyConfig, MAX_CHANGE_DAYS, Standing},
+    i18n::{self, tr},
+    logging,
+};
 use poise::serenity_prelude::{self as serenity, GuildId};
+use std::{cmp::Reverse, collections::BTreeMap};
 
-// Core business logic for getting leaderboard
-pub fn get_leaderboard(
-    data: &Data,
+/// Users shown per leaderboard page
+const PAGE_SIZE: usize = 10;
+
+const CUSTOM_ID_PREFIX: &str = "leaderboard:";
+
+/// Board a leaderboard ranks users on: the server's, or the global one (`None`)
+/// when asked for or outside a server
+pub fn ranking_board(
     guild_id: Option<serenity::GuildId>,
     is_global: bool,
-    limit: usize,
-) -> (Vec<(serenity::UserId, u32)>, &'static str) {
-    if is_global || guild_id.is_none() {
-        (data.get_global_top_users(limit), "Global")
+) -> (Option<serenity::GuildId>, &'static str) {
+    match guild_id {
+        Some(guild_id) if !is_global => (Some(guild_id), "Server"),
+        _ => (None, "Global"),
+    }
+}
+
+/// Time window a leaderboard covers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum LeaderboardPeriod {
+    Week,
+    Month,
+    #[default]
+    #[name = "All time"]
+    All,
+}
+
+impl LeaderboardPeriod {
+    fn code(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::All => "all",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Self> {
+        [Self::Week, Self::Month, Self::All]
+            .into_iter()
+            .find(|period| period.code() == code)
+    }
+
+    /// First day of the window ending `today`, or `None` for all time
+    pub fn since(self, today: chrono::NaiveDate) -> Option<chrono::NaiveDate> {
+        let days = match self {
+            Self::Week => 7,
+            Self::Month => MAX_CHANGE_DAYS,
+            Self::All => return None,
+        };
+        Some(today - chrono::Duration::days(i64::from(days) - 1))
+    }
+}
+
+/// What a leaderboard ranks users by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum LeaderboardKind {
+    /// Balances for all time, otherwise net gains in the period
+    #[default]
+    Coins,
+    /// Coins given to other members
+    #[name = "Top givers"]
+    Givers,
+}
+
+impl LeaderboardKind {
+    fn code(self) -> &'static str {
+        match self {
+            Self::Coins => "coins",
+            Self::Givers => "givers",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Self> {
+        [Self::Coins, Self::Givers]
+            .into_iter()
+            .find(|kind| kind.code() == code)
+    }
+}
+
+/// Which leaderboard a message shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeaderboardView {
+    pub kind: LeaderboardKind,
+    pub period: LeaderboardPeriod,
+}
+
+impl LeaderboardView {
+    /// Whether this is the plain leaderboard of current balances
+    fn is_balances(self) -> bool {
+        self == Self::default()
+    }
+}
+
+/// A user's place on a leaderboard of balance changes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChangeRank {
+    pub user_id: serenity::UserId,
+    pub rank: usize,
+    pub changes: BalanceChanges,
+}
+
+/// Rank users by their balance changes in a period, densely like balances.
+/// Coins rank by net gain and givers by coins given; users without any are left out.
+pub fn rank_changes(
+    data: &Data,
+    board: Option<GuildId>,
+    view: LeaderboardView,
+    today: chrono::NaiveDate,
+) -> Vec<ChangeRank> {
+    let score = |changes: &BalanceChanges| match view.kind {
+        LeaderboardKind::Coins => changes.net(),
+        LeaderboardKind::Givers => i64::try_from(changes.given).unwrap_or(i64::MAX),
+    };
+    let mut users: Vec<_> = data
+        .get_balance_changes(board, view.period.since(today))
+        .into_iter()
+        .filter(|(_, changes)| match view.kind {
+            LeaderboardKind::Coins => changes.earned != 0 || changes.received > 0,
+            LeaderboardKind::Givers => changes.given > 0,
+        })
+        .collect();
+    users.sort_by_key(|(user_id, changes)| (Reverse(score(changes)), *user_id));
+
+    let mut rank = 0;
+    let mut previous = None;
+    users
+        .into_iter()
+        .map(|(user_id, changes)| {
+            if previous != Some(score(&changes)) {
+                rank += 1;
+                previous = Some(score(&changes));
+            }
+            ChangeRank {
+                user_id,
+                rank,
+                changes,
+            }
+        })
+        .collect()
+}
+
+/// Format a gain or loss with its sign
+fn format_signed(currency: &CurrencyConfig, amount: i64) -> String {
+    let sign = if amount < 0 { "-" } else { "+" };
+    let amount = u32::try_from(amount.unsigned_abs()).unwrap_or(u32::MAX);
+    format!("{sign}{}", currency.format_short(amount))
+}
+
+/// Number of pages a ranking fills, at least one
+fn page_count(users: usize) -> usize {
+    users.div_ceil(PAGE_SIZE).max(1)
+}
+
+/// Custom ID of a button that shows `page` of a leaderboard, highlighting `viewer`
+fn custom_id(
+    is_global: bool,
+    view: LeaderboardView,
+    page: usize,
+    viewer: serenity::UserId,
+) -> String {
+    let scope = if is_global { "global" } else { "server" };
+    let (kind, period) = (view.kind.code(), view.period.code());
+    format!("{CUSTOM_ID_PREFIX}{scope}:{page}:{viewer}:{kind}-{period}")
+}
+
+/// Scope, view, page and viewer of a button, if it is a leaderboard button
+fn parse_custom_id(custom_id: &str) -> Option<(bool, LeaderboardView, usize, serenity::UserId)> {
+    let mut parts = custom_id.strip_prefix(CUSTOM_ID_PREFIX)?.split(':');
+    let is_global = match parts.next()? {
+        "global" => true,
+        "server" => false,
+        _ => return None,
+    };
+    let page = parts.next()?.parse().ok()?;
+    let viewer = parts.next()?.parse().ok()?;
+    // Buttons posted before periods existed show balances
+    let view = match parts.next() {
+        Some(view) => {
+            let (kind, period) = view.split_once('-')?;
+            LeaderboardView {
+                kind: LeaderboardKind::from_code(kind)?,
+                period: LeaderboardPeriod::from_code(period)?,
+            }
+        }
+        None => LeaderboardView::default(),
+    };
+    Some((is_global, view, page, serenity::UserId::new(viewer)))
+}
+
+/// One page of the leaderboard as an embed, with Prev and Next buttons
+#[allow(clippy::too_many_arguments)]
+async fn render_page(
+    ctx: &serenity::Context,
+    data: &Data,
+    locale: &str,
+    guild_id: Option<GuildId>,
+    is_global: bool,
+    view: LeaderboardView,
+    page: usize,
+    viewer: serenity::UserId,
+) -> Option<(serenity::CreateEmbed, Vec<serenity::CreateActionRow>)> {
+    let (board, scope) = ranking_board(guild_id, is_global);
+    // Global leaderboards use the default currency
+    let currency = data.get_currency(board);
+
+    // Rows of (user, rank, value), the number of ranked users and the viewer's rank
+    let (rows, ranked_users, viewer_rank, page) = if view.is_balances() {
+        let ranked_users = data.get_ranked_users(board);
+        let page = page.min(page_count(ranked_users) - 1);
+        let rows: Vec<_> = data
+            .get_ranking_page(board, page * PAGE_SIZE, PAGE_SIZE)
+            .into_iter()
+            .map(|user| (user.user_id, user.rank, currency.format_short(user.balance)))
+            .collect();
+        let viewer_rank = data
+            .get_rank_info(board, viewer)
+            .map_or(0, |info| info.rank);
+        (rows, ranked_users, viewer_rank, page)
+    } else {
+        let ranking = rank_changes(data, board, view, chrono::Utc::now().date_naive());
+        let page = page.min(page_count(ranking.len()) - 1);
+        let rows: Vec<_> = ranking
+            .iter()
+            .skip(page * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .map(|user| {
+                let value = match view.kind {
+                    LeaderboardKind::Coins => {
+                        let received = u32::try_from(user.changes.received).unwrap_or(u32::MAX);
+                        tr!(
+                            locale,
+                            "leaderboard-gain",
+                            net = format_signed(&currency, user.changes.net()),
+                            earned = format_signed(&currency, user.changes.earned),
+                            received = currency.format_short(received),
+                        )
+                    }
+                    LeaderboardKind::Givers => {
+                        let given = u32::try_from(user.changes.given).unwrap_or(u32::MAX);
+                        currency.format_short(given)
+                    }
+                };
+                (user.user_id, user.rank, value)
+            })
+            .collect();
+        let viewer_rank = ranking
+            .iter()
+            .find(|user| user.user_id == viewer)
+            .map_or(0, |user| user.rank);
+        (rows, ranking.len(), viewer_rank, page)
+    };
+    if ranked_users == 0 {
+        return None;
+    }
+    let pages = page_count(ranked_users);
+
+    // Server leaderboards show nicknames
+    let user_ids: Vec<_> = rows.iter().map(|(user_id, _, _)| *user_id).collect();
+    let names = data.names.display_names(ctx, board, &user_ids).await;
+
+    let mut lines = Vec::new();
+    if !view.is_balances() {
+        let heading = match view.kind {
+            LeaderboardKind::Coins => "leaderboard-heading-gains",
+            LeaderboardKind::Givers => "leaderboard-heading-givers",
+        };
+        lines.push(format!(
+            "*{}*",
+            tr!(locale, heading, period = view.period.code())
+        ));
+    }
+    for (user_id, rank, value) in &rows {
+        let username = names
+            .get(user_id)
+            .cloned()
+            .unwrap_or_else(|| tr!(locale, "unknown-user", id = user_id.to_string()));
+        lines.push(if *user_id == viewer {
+            format!("▶ **{rank}. {username}: {value}**")
+        } else {
+            format!("{rank}. **{username}**: {value}")
+        });
+    }
+
+    let title_id = if scope == "Global" {
+        "leaderboard-title-global"
     } else {
-        #[allow(clippy::unnecessary_unwrap)]
-        (data.get_guild_top_users(guild_id.unwrap(), limit), "Server")
+        "leaderboard-title-server"
+    };
+    let embed = serenity::CreateEmbed::new()
+        .title(tr!(locale, title_id, currency = currency.name.as_str()))
+        .description(lines.join("\n"))
+        .footer(serenity::CreateEmbedFooter::new(tr!(
+            locale,
+            "leaderboard-footer",
+            page = page + 1,
+            pages = pages,
+            rank = viewer_rank,
+        )));
+
+    let prev =
+        serenity::CreateButton::new(custom_id(is_global, view, page.saturating_sub(1), viewer))
+            .label(tr!(locale, "button-prev"))
+            .style(serenity::ButtonStyle::Secondary)
+            .disabled(page == 0);
+    let next = serenity::CreateButton::new(custom_id(is_global, view, page + 1, viewer))
+        .label(tr!(locale, "button-next"))
+        .style(serenity::ButtonStyle::Secondary)
+        .disabled(page + 1 >= pages);
+    let components = vec![serenity::CreateActionRow::Buttons(vec![prev, next])];
+
+    Some((embed, components))
+}
+
+/// How a user's standing moved since the previous scheduled post
+fn standing_change(
+    currency: &CurrencyConfig,
+    previous: Option<&Standing>,
+    rank: usize,
+    balance: u32,
+) -> String {
+    let Some(previous) = previous else {
+        return "🆕".to_string();
+    };
+    let mut change = match rank.cmp(&previous.rank) {
+        std::cmp::Ordering::Less => format!("▲{}", previous.rank - rank),
+        std::cmp::Ordering::Greater => format!("▼{}", rank - previous.rank),
+        std::cmp::Ordering::Equal => String::new(),
+    };
+    let gain = i64::from(balance) - i64::from(previous.balance);
+    if gain != 0 {
+        if !change.is_empty() {
+            change.push(' ');
+        }
+        change.push_str(&format!("({})", format_signed(currency, gain)));
+    }
+    change
+}
+
+/// The top of a server's leaderboard for a scheduled post, with each user's change
+/// since the `previous` post. Returns the embed and the standings it shows.
+pub async fn render_standings(
+    ctx: &serenity::Context,
+    data: &Data,
+    locale: &str,
+    guild_id: GuildId,
+    previous: &BTreeMap<u64, Standing>,
+    previous_run: chrono::DateTime<chrono::Utc>,
+) -> Option<(serenity::CreateEmbed, BTreeMap<u64, Standing>)> {
+    let rows = data.get_ranking_page(Some(guild_id), 0, PAGE_SIZE);
+    if rows.is_empty() {
+        return None;
+    }
+    let currency = data.get_currency(Some(guild_id));
+    let user_ids: Vec<_> = rows.iter().map(|user| user.user_id).collect();
+    let names = data
+        .names
+        .display_names(ctx, Some(guild_id), &user_ids)
+        .await;
+
+    let mut lines = Vec::new();
+    if !previous.is_empty() {
+        let since = format!("<t:{}:R>", previous_run.timestamp());
+        lines.push(format!(
+            "*{}*",
+            tr!(locale, "leaderboard-heading-since", since = since)
+        ));
+    }
+    let mut standings = BTreeMap::new();
+    for user in &rows {
+        let username = names
+            .get(&user.user_id)
+            .cloned()
+            .unwrap_or_else(|| tr!(locale, "unknown-user", id = user.user_id.to_string()));
+        let balance = currency.format_short(user.balance);
+        let mut line = format!("{}. **{username}**: {balance}", user.rank);
+        if !previous.is_empty() {
+            let change = standing_change(
+                &currency,
+                previous.get(&user.user_id.get()),
+                user.rank,
+                user.balance,
+            );
+            if !change.is_empty() {
+                line.push(' ');
+                line.push_str(&change);
+            }
+        }
+        lines.push(line);
+        standings.insert(
+            user.user_id.get(),
+            Standing {
+                rank: user.rank,
+                balance: user.balance,
+            },
+        );
     }
+
+    let embed = serenity::CreateEmbed::new()
+        .title(tr!(
+            locale,
+            "leaderboard-title-server",
+            currency = currency.name.as_str()
+        ))
+        .description(lines.join("\n"))
+        .timestamp(serenity::Timestamp::now());
+    Some((embed, standings))
 }
 
 /// Display the AndyCoin leaderboard
 #[poise::command(slash_command, prefix_command)]
 pub async fn leaderboard(
     ctx: Context<'_>,
-    #[description = "Number of users to show (default: 10)"] limit: Option<usize>,
+    #[description = "Page to show (default: 1)"] page: Option<usize>,
     #[description = "Show global leaderboard across all servers (default: current server only)"]
     global: Option<bool>,
+    #[description = "Rank gains over the last week or month (default: all time)"] period: Option<
+        LeaderboardPeriod,
+    >,
+    #[description = "Rank coins, or members by coins given (default: coins)"] board: Option<
+        LeaderboardKind,
+    >,
 ) -> Result<(), Error> {
+    let view = LeaderboardView {
+        kind: board.unwrap_or_default(),
+        period: period.unwrap_or_default(),
+    };
     // Format arguments for logging
-    let limit_arg = limit.unwrap_or(10).to_string();
+    let page_arg = page.unwrap_or(1).to_string();
     let global_arg = global.unwrap_or(false).to_string();
-    let args = format!("limit: {limit_arg}, global: {global_arg}");
-    let limit = limit.unwrap_or(10).min(25); // Cap at 25 to avoid too long messages
-    let is_global = global.unwrap_or(false);
+    let args = format!(
+        "page: {page_arg}, global: {global_arg}, period: {}, board: {}",
+        view.period.code(),
+        view.kind.code()
+    );
+    let page = page.unwrap_or(1).saturating_sub(1);
+    let is_global = global.unwrap_or(false) || ctx.guild_id().is_none();
     let guild_id = ctx.guild_id();
+    let locale = i18n::locale(ctx);
 
-    // Call the testable business logic function
-    let (top_users, scope) = get_leaderboard(ctx.data(), guild_id, is_global, limit);
-
-    if top_users.is_empty() {
-        ctx.say("No one has any AndyCoins yet!").await?;
+    let rendered = render_page(
+        ctx.serenity_context(),
+        ctx.data(),
+        locale,
+        guild_id,
+        is_global,
+        view,
+        page,
+        ctx.author().id,
+    )
+    .await;
+    let Some((embed, components)) = rendered else {
+        let currency = ctx
+            .data()
+            .get_currency(ranking_board(guild_id, is_global).0);
+        let id = if view.is_balances() {
+            "leaderboard-empty"
+        } else {
+            "leaderboard-empty-period"
+        };
+        ctx.say(tr!(locale, id, currency = currency.plural.as_str()))
+            .await?;
         return Ok(());
-    }
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .embed(embed)
+            .components(components),
+    )
+    .await?;
+
+    // Log successful command execution
+    logging::log_command(
+        "leaderboard",
+        ctx.guild_id().map(GuildId::get),
+        ctx.author().id.get(),
+        &args,
+        true,
+    );
 
-    let mut response = format!("# {scope} AndyCoin Leaderboard\n");
+    Ok(())
+}
 
-    for (idx, (user_id, balance)) in top_users.iter().enumerate() {
-        let rank = idx + 1;
-        // Try to fetch the user info
-        let username = match ctx.http().get_user(*user_id).await {
-            Ok(user) => user.tag(),
-            Err(_) => format!("User {user_id}"),
+/// Handle a press of a leaderboard page button
+pub async fn handle_component(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    data: &Data,
+) -> Result<(), Error> {
+    let Some((is_global, view, page, viewer)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+    let guild_id = interaction.guild_id;
+    let locale = i18n::interaction_locale(data, &interaction.locale, guild_id);
+
+    let response =
+        match render_page(ctx, data, locale, guild_id, is_global, view, page, viewer).await {
+            Some((embed, components)) => serenity::CreateInteractionResponse::UpdateMessage(
+                serenity::CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(components),
+            ),
+            // Everyone's balance was cleared since the leaderboard was posted
+            None => serenity::CreateInteractionResponse::Acknowledge,
         };
+    interaction.create_response(ctx, response).await?;
+    Ok(())
+}
 
-        response.push_str(&format!("{rank}. **{username}**: {balance} AndyCoins\n"));
-    }
+/// Show your or another user's rank on the leaderboard
+#[poise::command(slash_command, prefix_command)]
+pub async fn rank(
+    ctx: Context<'_>,
+    #[description = "User to look up (defaults to yourself)"] user: Option<serenity::User>,
+    #[description = "Rank across all servers (default: current server only)"] global: Option<bool>,
+) -> Result<(), Error> {
+    let target_user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let is_global = global.unwrap_or(false) || ctx.guild_id().is_none();
+    let guild_id = ctx.guild_id();
+    let locale = i18n::locale(ctx);
+    let args = format!("user: {}, global: {is_global}", target_user.tag());
+
+    let (board, _) = ranking_board(guild_id, is_global);
+    let name = ctx.data().names.display_name(board, target_user);
+    let currency = ctx.data().get_currency(board);
 
+    let response = match ctx.data().get_rank_info(board, target_user.id) {
+        Some(info) => {
+            let mut response = tr!(
+                locale,
+                "rank-position",
+                user = name.as_str(),
+                rank = info.rank,
+                users = info.ranked_users,
+                amount = currency.format(info.balance),
+                percentile = format!("{:.0}", info.percentile),
+            );
+            response.push('\n');
+            response.push_str(&match info.to_next_rank {
+                Some(gap) => tr!(
+                    locale,
+                    "rank-next",
+                    amount = currency.format(gap),
+                    rank = info.rank - 1,
+                ),
+                None => tr!(locale, "rank-top"),
+            });
+            response
+        }
+        None => tr!(locale, "rank-none", user = name),
+    };
     ctx.say(response).await?;
 
-    // Log successful command execution
     logging::log_command(
-        "leaderboard",
+        "rank",
         ctx.guild_id().map(GuildId::get),
         ctx.author().id.get(),
         &args,
@@ -82,7 +586,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_leaderboard() {
+    fn test_get_ranking() {
         let data = Data::new();
         let guild1 = test_guild_id(1);
         let guild2 = test_guild_id(2);
@@ -95,47 +599,173 @@ mod tests {
         data.add_coins(guild2, test_user_id(3), 70); // Same user in different guild
 
         // Test guild-specific leaderboard
-        let (top_users, scope) = get_leaderboard(&data, Some(guild1), false, 3);
+        let (board, scope) = ranking_board(Some(guild1), false);
         assert_eq!(scope, "Server");
-        assert_eq!(top_users.len(), 3);
-
-        // The order might vary depending on how the DashMap iterates, so we'll just check that
-        // the expected users and balances are present
-        let has_user1 = top_users
-            .iter()
-            .any(|(id, bal)| *id == test_user_id(1) && *bal == 100);
-        let has_user2 = top_users
-            .iter()
-            .any(|(id, bal)| *id == test_user_id(2) && *bal == 50);
-        let has_user3 = top_users
+        let users: Vec<_> = data
+            .get_ranking_page(board, 0, PAGE_SIZE)
             .iter()
-            .any(|(id, bal)| *id == test_user_id(3) && *bal == 75);
-
-        assert!(
-            has_user1,
-            "User 1 with balance 100 should be in the top users"
-        );
-        assert!(
-            has_user2,
-            "User 2 with balance 50 should be in the top users"
-        );
-        assert!(
-            has_user3,
-            "User 3 with balance 75 should be in the top users"
+            .map(|user| (user.user_id, user.balance))
+            .collect();
+        assert_eq!(
+            users,
+            vec![
+                (test_user_id(1), 100),
+                (test_user_id(3), 75),
+                (test_user_id(2), 50)
+            ]
         );
 
         // Test global leaderboard
-        let (top_users, scope) = get_leaderboard(&data, Some(guild1), true, 3);
+        let (board, scope) = ranking_board(Some(guild1), true);
         assert_eq!(scope, "Global");
-        assert_eq!(top_users.len(), 3);
-        assert_eq!(top_users[0].0, test_user_id(1));
-        assert_eq!(top_users[0].1, 150); // 100 + 50
-        assert_eq!(top_users[1].0, test_user_id(3));
-        assert_eq!(top_users[1].1, 145); // 75 + 75
+        let ranking = data.get_ranking_page(board, 0, PAGE_SIZE);
+        assert_eq!(ranking.len(), 3);
+        assert_eq!(ranking[0].user_id, test_user_id(1));
+        assert_eq!(ranking[0].balance, 150); // 100 + 50
+        assert_eq!(ranking[1].user_id, test_user_id(3));
+        assert_eq!(ranking[1].balance, 145); // 75 + 70
 
         // Test leaderboard in DM (should be global)
-        let (top_users, scope) = get_leaderboard(&data, None, false, 3);
+        let (board, scope) = ranking_board(None, false);
         assert_eq!(scope, "Global");
-        assert_eq!(top_users.len(), 3);
+        assert_eq!(data.get_ranked_users(board), 3);
+    }
+
+    #[test]
+    fn test_dense_rank_and_rank_info() {
+        let data = Data::new();
+        let guild = Some(test_guild_id(1));
+        for (user, balance) in [(1, 100), (2, 80), (3, 80), (4, 50)] {
+            data.add_coins(test_guild_id(1), test_user_id(user), balance);
+        }
+        let ranking = data.get_ranking_page(guild, 0, PAGE_SIZE);
+        let ranks: Vec<_> = ranking.iter().map(|user| user.rank).collect();
+        assert_eq!(ranks, vec![1, 2, 2, 3]);
+
+        // Tied users need the same amount to move up
+        let info = data.get_rank_info(guild, test_user_id(3)).unwrap();
+        assert_eq!(info.rank, 2);
+        assert_eq!(info.ranked_users, 4);
+        assert_eq!(info.to_next_rank, Some(20));
+        assert!((info.percentile - 25.0).abs() < 1e-9);
+
+        let last = data.get_rank_info(guild, test_user_id(4)).unwrap();
+        assert_eq!(last.to_next_rank, Some(30));
+        assert_eq!(last.percentile, 0.0);
+
+        let first = data.get_rank_info(guild, test_user_id(1)).unwrap();
+        assert_eq!(first.to_next_rank, None);
+        assert!((first.percentile - 75.0).abs() < 1e-9);
+
+        assert_eq!(data.get_rank_info(guild, test_user_id(5)), None);
+
+        // Pages past the first keep the ranks of the full ranking
+        let second: Vec<_> = data
+            .get_ranking_page(guild, 2, 2)
+            .iter()
+            .map(|user| (user.user_id, user.rank))
+            .collect();
+        assert_eq!(second, vec![(test_user_id(3), 2), (test_user_id(4), 3)]);
+    }
+
+    #[test]
+    fn test_pages_and_custom_ids() {
+        assert_eq!(page_count(0), 1);
+        assert_eq!(page_count(PAGE_SIZE), 1);
+        assert_eq!(page_count(PAGE_SIZE + 1), 2);
+
+        let balances = LeaderboardView::default();
+        let id = custom_id(false, balances, 3, test_user_id(42));
+        assert_eq!(
+            parse_custom_id(&id),
+            Some((false, balances, 3, test_user_id(42)))
+        );
+        let givers = LeaderboardView {
+            kind: LeaderboardKind::Givers,
+            period: LeaderboardPeriod::Week,
+        };
+        let id = custom_id(true, givers, 0, test_user_id(7));
+        assert_eq!(
+            parse_custom_id(&id),
+            Some((true, givers, 0, test_user_id(7)))
+        );
+
+        // Buttons from before periods existed show balances
+        assert_eq!(
+            parse_custom_id("leaderboard:server:1:42"),
+            Some((false, balances, 1, test_user_id(42)))
+        );
+
+        // Other buttons aren't leaderboard buttons
+        assert_eq!(parse_custom_id("vote:1:yes"), None);
+        assert_eq!(parse_custom_id("leaderboard:weekly:1:2"), None);
+        assert_eq!(parse_custom_id("leaderboard:server:1:2:coins-year"), None);
+    }
+
+    #[test]
+    fn test_standing_change() {
+        let currency = CurrencyConfig::default();
+        let previous = Standing {
+            rank: 3,
+            balance: 100,
+        };
+        assert_eq!(standing_change(&currency, None, 1, 100), "🆕");
+        assert_eq!(standing_change(&currency, Some(&previous), 3, 100), "");
+        assert_eq!(
+            standing_change(&currency, Some(&previous), 1, 150),
+            format!("▲2 ({})", format_signed(&currency, 50))
+        );
+        assert_eq!(
+            standing_change(&currency, Some(&previous), 4, 90),
+            format!("▼1 ({})", format_signed(&currency, -10))
+        );
+    }
+
+    #[test]
+    fn test_rank_changes() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let today = chrono::Utc::now().date_naive();
+
+        // User 1 is given 100 by user 2 and 30 by user 3, and loses 20 on their own
+        data.record_balance_change(guild_id, test_user_id(1), 0, 100, Some(test_user_id(2)));
+        data.record_balance_change(guild_id, test_user_id(1), 100, 130, Some(test_user_id(3)));
+        data.record_balance_change(guild_id, test_user_id(1), 130, 110, None);
+        // User 4 earns 110 from the bot
+        data.record_balance_change(guild_id, test_user_id(4), 0, 110, None);
+
+        let week = LeaderboardView {
+            kind: LeaderboardKind::Coins,
+            period: LeaderboardPeriod::Week,
+        };
+        let gains = rank_changes(&data, Some(guild_id), week, today);
+        let ranks: Vec<_> = gains.iter().map(|user| (user.user_id, user.rank)).collect();
+        // Tied on net gains, and givers without gains aren't ranked
+        assert_eq!(ranks, vec![(test_user_id(1), 1), (test_user_id(4), 1)]);
+        assert_eq!(gains[0].changes.earned, -20);
+        assert_eq!(gains[0].changes.received, 130);
+        assert_eq!(gains[0].changes.net(), 110);
+
+        let givers = LeaderboardView {
+            kind: LeaderboardKind::Givers,
+            period: LeaderboardPeriod::Month,
+        };
+        let ranks: Vec<_> = rank_changes(&data, Some(guild_id), givers, today)
+            .iter()
+            .map(|user| (user.user_id, user.rank, user.changes.given))
+            .collect();
+        assert_eq!(
+            ranks,
+            vec![(test_user_id(2), 1, 100), (test_user_id(3), 2, 30)]
+        );
+
+        // Changes from before the window don't count, but stay in the all time boards
+        let next_month = today + chrono::Duration::days(i64::from(MAX_CHANGE_DAYS));
+        assert!(rank_changes(&data, Some(guild_id), week, next_month).is_empty());
+        let all_givers = LeaderboardView {
+            kind: LeaderboardKind::Givers,
+            period: LeaderboardPeriod::All,
+        };
+        assert_eq!(rank_changes(&data, None, all_givers, next_month).len(), 2);
     }
 }
@@ -2,27 +2,50 @@ pub mod balance;
 pub mod config;
 pub mod give;
 pub mod leaderboard;
+pub mod seasons;
 pub mod vote;
 
 pub use balance::balance;
 pub use config::config;
+pub use config::config_admin;
 pub use config::flip;
 pub use give::give;
 pub use leaderboard::leaderboard;
+pub use leaderboard::rank;
+pub use seasons::seasons;
+pub use vote::propose;
 pub use vote::vote;
 pub use vote::vote_admin;
 
-use crate::{Data, Error};
+use crate::{
+    Context, Data, Error,
+    i18n::{self, tr},
+};
+use poise::serenity_prelude as serenity;
+
+/// The server a command runs in. Outside a server, tells the user the command
+/// only works in one and returns `None`.
+pub async fn require_guild(ctx: Context<'_>) -> Result<Option<serenity::GuildId>, Error> {
+    let guild_id = ctx.guild_id();
+    if guild_id.is_none() {
+        ctx.say(tr!(i18n::locale(ctx), "error-server-only")).await?;
+    }
+    Ok(guild_id)
+}
 
 // Helper function to get all commands
 pub fn _all_commands() -> Vec<poise::Command<Data, Error>> {
     vec![
         config(),
+        config_admin(),
         give(),
         balance(),
         leaderboard(),
+        rank(),
+        seasons(),
         flip(),
         vote(),
+        propose(),
         vote_admin(),
     ]
 }
@@ -34,6 +57,6 @@ mod tests {
     #[test]
     fn test_all_commands() {
         let commands = _all_commands();
-        assert_eq!(commands.len(), 7); // Updated to include vote and vote_admin
+        assert_eq!(commands.len(), 11); // Updated to include vote, propose, vote_admin, rank, seasons and config_admin
     }
 }
@@ -0,0 +1,255 @@
+use super::require_guild;
+use crate::{
+    Context, Data, Error,
+    data::{SeasonEnd, SeasonRecord},
+    i18n::{self, tr},
+    logging,
+};
+use poise::serenity_prelude::{self as serenity, GuildId};
+use std::collections::HashMap;
+
+/// Past seasons shown by /seasons
+const SEASONS_SHOWN: usize = 10;
+
+/// Finishers shown per past season
+const CHAMPIONS_SHOWN: usize = 3;
+
+/// Medal for a final rank, or the rank itself past the podium
+fn place(rank: usize) -> String {
+    match rank {
+        1 => "🥇".to_string(),
+        2 => "🥈".to_string(),
+        3 => "🥉".to_string(),
+        _ => format!("{rank}."),
+    }
+}
+
+/// A Discord date for a season's start or end
+fn date(time: chrono::DateTime<chrono::Utc>) -> String {
+    format!("<t:{}:d>", time.timestamp())
+}
+
+/// Name of a user, falling back to their ID when it couldn't be looked up
+fn name(
+    locale: &str,
+    names: &HashMap<serenity::UserId, String>,
+    user_id: serenity::UserId,
+) -> String {
+    names
+        .get(&user_id)
+        .cloned()
+        .unwrap_or_else(|| tr!(locale, "unknown-user", id = user_id.to_string()))
+}
+
+/// One line per past season with its top finishers, newest first
+fn champion_lines(
+    data: &Data,
+    locale: &str,
+    guild_id: GuildId,
+    history: &[SeasonRecord],
+    names: &HashMap<serenity::UserId, String>,
+) -> Vec<String> {
+    let currency = data.get_currency(Some(guild_id));
+    history
+        .iter()
+        .rev()
+        .take(SEASONS_SHOWN)
+        .map(|record| {
+            let champions: Vec<_> = record
+                .ranked()
+                .into_iter()
+                .take(CHAMPIONS_SHOWN)
+                .map(|(user_id, standing)| {
+                    format!(
+                        "{} {} ({})",
+                        place(standing.rank),
+                        name(locale, names, user_id),
+                        currency.format_short(standing.balance)
+                    )
+                })
+                .collect();
+            let champions = if champions.is_empty() {
+                tr!(locale, "seasons-no-champions")
+            } else {
+                champions.join(" · ")
+            };
+            format!(
+                "**{}** ({} – {}): {champions}",
+                tr!(locale, "seasons-name", number = record.number),
+                date(record.started),
+                date(record.ended)
+            )
+        })
+        .collect()
+}
+
+/// The announcement of a season's final standings, the winners' role and the next
+/// season
+pub async fn render_season_end(
+    ctx: &serenity::Context,
+    data: &Data,
+    locale: &str,
+    end: &SeasonEnd,
+) -> serenity::CreateEmbed {
+    let record = &end.record;
+    let currency = data.get_currency(Some(end.guild_id));
+    let ranked = record.ranked();
+    let user_ids: Vec<_> = ranked.iter().map(|(user_id, _)| *user_id).collect();
+    let names = data
+        .names
+        .display_names(ctx, Some(end.guild_id), &user_ids)
+        .await;
+
+    let mut lines: Vec<_> = ranked
+        .iter()
+        .map(|(user_id, standing)| {
+            format!(
+                "{} **{}**: {}",
+                place(standing.rank),
+                name(locale, &names, *user_id),
+                currency.format_short(standing.balance)
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(tr!(locale, "seasons-no-champions"));
+    }
+    if let (Some(role_id), false) = (record.award_role, record.awarded.is_empty()) {
+        let winners: Vec<_> = record.awarded.iter().map(|id| format!("<@{id}>")).collect();
+        lines.push(String::new());
+        lines.push(tr!(
+            locale,
+            "seasons-awarded",
+            role = format!("<@&{role_id}>"),
+            users = winners.join(", ")
+        ));
+    }
+    if let Some(season) = data.get_season(end.guild_id) {
+        lines.push(String::new());
+        lines.push(match season.ends_at {
+            Some(ends_at) => tr!(
+                locale,
+                "seasons-next",
+                number = season.number,
+                end = format!("<t:{}:R>", ends_at.timestamp())
+            ),
+            None => tr!(locale, "seasons-next-open", number = season.number),
+        });
+    }
+
+    serenity::CreateEmbed::new()
+        .title(tr!(locale, "seasons-ended-title", number = record.number))
+        .description(lines.join("\n"))
+        .timestamp(serenity::Timestamp::now())
+}
+
+/// Show the current season and past champions
+#[poise::command(slash_command, guild_only)]
+pub async fn seasons(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+    let data = ctx.data();
+
+    let history = data.get_season_history(guild_id);
+    let user_ids: Vec<_> = history
+        .iter()
+        .rev()
+        .take(SEASONS_SHOWN)
+        .flat_map(|record| record.ranked().into_iter().take(CHAMPIONS_SHOWN))
+        .map(|(user_id, _)| user_id)
+        .collect();
+    let names = data
+        .names
+        .display_names(ctx.serenity_context(), Some(guild_id), &user_ids)
+        .await;
+
+    let mut lines = vec![match data.get_season(guild_id) {
+        Some(season) => match season.ends_at {
+            Some(ends_at) => tr!(
+                locale,
+                "seasons-current",
+                number = season.number,
+                end = format!("<t:{}:R>", ends_at.timestamp())
+            ),
+            None => tr!(locale, "seasons-current-open", number = season.number),
+        },
+        None => tr!(locale, "seasons-off"),
+    }];
+    lines.push(String::new());
+    if history.is_empty() {
+        lines.push(tr!(locale, "seasons-none"));
+    } else {
+        lines.extend(champion_lines(data, locale, guild_id, &history, &names));
+    }
+
+    let embed = serenity::CreateEmbed::new()
+        .title(tr!(locale, "seasons-title"))
+        .description(lines.join("\n"));
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+
+    logging::log_command(
+        "seasons",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        "",
+        true,
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Standing;
+    use std::collections::BTreeMap;
+
+    // Helper function to create a test guild ID
+    fn test_guild_id(id: u64) -> serenity::GuildId {
+        serenity::GuildId::new(id)
+    }
+
+    // Helper function to build a finished season with the given final balances
+    fn test_season(number: u32, standings: &[(u64, usize, u32)]) -> SeasonRecord {
+        let started: chrono::DateTime<chrono::Utc> = "2025-01-01T00:00:00Z".parse().unwrap();
+        SeasonRecord {
+            number,
+            started,
+            ended: started + chrono::Duration::days(7),
+            standings: standings
+                .iter()
+                .map(|&(user_id, rank, balance)| (user_id, Standing { rank, balance }))
+                .collect::<BTreeMap<_, _>>(),
+            award_role: None,
+            awarded: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_champion_lines() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let currency = data.get_currency(Some(guild_id));
+        let history = vec![
+            test_season(1, &[(1, 1, 100)]),
+            test_season(2, &[(4, 3, 10), (2, 1, 50), (3, 1, 50), (1, 2, 20)]),
+            test_season(3, &[]),
+        ];
+        let names = HashMap::from([(serenity::UserId::new(2), "Bea".to_string())]);
+
+        let lines = champion_lines(&data, "en-US", guild_id, &history, &names);
+        assert_eq!(lines.len(), 3);
+        // Newest first, and only the podium
+        assert!(lines[0].contains(&tr!("en-US", "seasons-no-champions")));
+        let expected = format!(
+            "🥇 Bea ({}) · 🥇 {} ({}) · 🥈",
+            currency.format_short(50),
+            tr!("en-US", "unknown-user", id = "3"),
+            currency.format_short(50)
+        );
+        assert!(lines[1].contains(&expected), "{}", lines[1]);
+        assert!(!lines[1].contains("🥉"));
+    }
+}
@@ -1,263 +1,798 @@
-use crate::{Context, Error, data::VoteConfig, logging};
-use std::fmt::Write;
-
-/// Vote decision options
-#[derive(Debug, poise::ChoiceParameter)]
-pub enum VoteDecision {
-    #[name = "Start a new vote"]
-    Start,
-    #[name = "Vote yes"]
-    Yes,
-    #[name = "Vote no"]
-    No,
+use super::require_guild;
+use crate::{
+    Context, Error,
+    data::{CurrencyConfig, MAX_RESET_GRACE_HOURS},
+    i18n::{self, tr},
+    logging, permissions, scheduler, vote_message,
+    voting::{
+        MAX_ACTIVITY_DAYS, ProposalAction, ProposalKind, Quorum, QuorumKind, ResetMode,
+        ResetModeKind, VoteConfig, VoteError, VoteField, VotePhase, VoteResult, VoteStats,
+        VoteStatus, VoteWeighting, Voter,
+    },
+};
+use poise::serenity_prelude as serenity;
+
+/// Longest poll question, in characters
+const MAX_POLL_QUESTION: usize = 200;
+/// Archived votes listed by `/vote history`
+const HISTORY_LIMIT: usize = 10;
+/// Voters listed by `/vote stats`
+const STATS_TOP_VOTERS: usize = 5;
+
+/// Reply for a setting above its [`VoteField::max`]
+fn too_large(locale: &str, field: VoteField) -> String {
+    match field {
+        VoteField::MajorityPercentage => tr!(locale, "vote-config-invalid-majority"),
+        _ => tr!(
+            locale,
+            "vote-config-too-large",
+            field = field.label(locale),
+            max = field.max()
+        ),
+    }
 }
 
 /// Start a vote to reset all AndyCoins in the server or cast your vote
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("start", "yes", "no", "status", "history", "stats", "remind"),
+    subcommand_required
+)]
+pub async fn vote(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Start a vote to reset all AndyCoins in the server
 #[poise::command(slash_command, guild_only)]
-pub async fn vote(
+pub async fn start(
     ctx: Context<'_>,
-    #[description = "Your vote decision"] decision: VoteDecision,
+    #[description = "How balances change if the vote passes (default: the server's reset mode)"]
+    mode: Option<ResetModeKind>,
+    #[description = "Percentage to keep or tax, or the cap, for modes that need one"]
+    amount: Option<u32>,
 ) -> Result<(), Error> {
-    let guild_id = ctx.guild_id().unwrap();
-    let user_id = ctx.author().id;
-
-    match decision {
-        VoteDecision::Yes => {
-            match ctx.data().cast_vote(guild_id, user_id, true) {
-                Ok(()) => {
-                    ctx.say("You have voted YES on the current reset proposal.")
-                        .await?;
-
-                    // Log successful vote
-                    logging::log_command(
-                        "vote_cast",
-                        Some(guild_id.get()),
-                        ctx.author().id.get(),
-                        "vote: YES",
-                        true,
-                    );
-                }
-                Err(e) => {
-                    ctx.say(format!("Error: {e}")).await?;
-                }
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let mode = match mode {
+        Some(kind) => match ResetMode::from_choice(kind, amount) {
+            Some(mode) => mode,
+            None => {
+                let locale = i18n::locale(ctx);
+                ctx.say(tr!(locale, "reset-mode-invalid")).await?;
+                return Ok(());
             }
-        }
-        VoteDecision::No => {
-            match ctx.data().cast_vote(guild_id, user_id, false) {
-                Ok(()) => {
-                    ctx.say("You have voted NO on the current reset proposal.")
-                        .await?;
-
-                    // Log successful vote
-                    logging::log_command(
-                        "vote_cast",
-                        Some(guild_id.get()),
-                        ctx.author().id.get(),
-                        "vote: NO",
-                        true,
-                    );
-                }
-                Err(e) => {
-                    ctx.say(format!("Error: {e}")).await?;
-                }
+        },
+        None => ctx.data().get_reset_mode(guild_id),
+    };
+    open_proposal(ctx, ProposalAction::reset(mode)).await
+}
+
+/// Vote yes on an open proposal
+#[poise::command(slash_command, guild_only)]
+pub async fn yes(
+    ctx: Context<'_>,
+    #[description = "Number of the proposal to vote on (default: the only open one)"]
+    proposal: Option<u32>,
+) -> Result<(), Error> {
+    cast(ctx, proposal, true).await
+}
+
+/// Vote no on an open proposal
+#[poise::command(slash_command, guild_only)]
+pub async fn no(
+    ctx: Context<'_>,
+    #[description = "Number of the proposal to vote on (default: the only open one)"]
+    proposal: Option<u32>,
+) -> Result<(), Error> {
+    cast(ctx, proposal, false).await
+}
+
+/// Cast the author's ballot
+async fn cast(ctx: Context<'_>, proposal: Option<u32>, vote_yes: bool) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    let voter = author_voter(ctx).await;
+    match ctx.data().cast_vote(guild_id, proposal, &voter, vote_yes) {
+        Ok(status) => {
+            let proposal_id = status.proposal_id;
+            let id = if vote_yes {
+                "vote-cast-yes"
+            } else {
+                "vote-cast-no"
+            };
+            // Only the voter sees how they voted on a secret ballot
+            let secret = status.is_secret();
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(tr!(locale, id, id = proposal_id))
+                    .ephemeral(secret),
+            )
+            .await?;
+
+            // Refresh the tallies in the public message, unless the vote has ended
+            // since and the message already shows its result
+            if ctx.data().get_proposal(guild_id, proposal_id).is_some() {
+                let guild_locale = i18n::guild_locale(ctx.data(), guild_id);
+                let config = ctx
+                    .data()
+                    .get_effective_vote_config(guild_id, status.action.kind());
+                let currency = ctx.data().get_currency(Some(guild_id));
+                let content = vote_message::render(guild_locale, &status, &config, &currency);
+                vote_message::edit(ctx.http(), guild_locale, &status, content, false).await;
             }
+
+            // Log successful vote
+            logging::log_command(
+                "vote_cast",
+                Some(guild_id.get()),
+                ctx.author().id.get(),
+                &format!(
+                    "proposal: {proposal_id}, vote: {}",
+                    vote_message::vote_label(vote_yes, secret)
+                ),
+                true,
+            );
         }
-        VoteDecision::Start => {
-            // Start a new vote
-            match ctx.data().start_vote(guild_id, user_id) {
-                Ok(end_time) => {
-                    let vote_config = ctx.data().get_vote_config(guild_id);
-                    let end_time_str = end_time.format("%H:%M:%S UTC");
-
-                    let mut response = String::new();
-                    writeln!(&mut response, "🗳️ **AndyCoin Reset Vote Started**")?;
-                    writeln!(
-                        &mut response,
-                        "A vote to reset all AndyCoins in this server has been started by {}.",
-                        ctx.author().name
-                    )?;
-                    writeln!(&mut response, "The vote will end at {end_time_str}.")?;
-                    writeln!(
-                        &mut response,
-                        "Requirements: At least {} votes with {}% majority to pass.",
-                        vote_config.min_votes, vote_config.majority_percentage
-                    )?;
-                    writeln!(
-                        &mut response,
-                        "Use `/vote yes` to vote in favor or `/vote no` to vote against."
-                    )?;
-                    writeln!(
-                        &mut response,
-                        "⚠️ If the vote passes, all AndyCoins in this server will be reset to 0!"
-                    )?;
-
-                    ctx.say(response).await?;
-
-                    // Log successful vote start
-                    logging::log_command(
-                        "vote_start",
-                        Some(guild_id.get()),
-                        ctx.author().id.get(),
-                        &format!("end_time: {end_time_str}"),
-                        true,
-                    );
-                }
-                Err(e) => {
-                    ctx.say(format!("Error: {e}")).await?;
-                }
-            }
+        Err(e) => {
+            // Eligibility errors can name the required role, which mustn't be pinged
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(tr!(locale, "error-prefix", error = e.describe(locale)))
+                    .allowed_mentions(serenity::CreateAllowedMentions::new()),
+            )
+            .await?;
         }
     }
 
     Ok(())
 }
 
-/// Configure vote settings
+/// List past votes, and past resets with whether they can still be restored
+#[poise::command(slash_command, guild_only)]
+pub async fn history(
+    ctx: Context<'_>,
+    #[description = "Only list proposals of this type"] kind: Option<ProposalKind>,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let grace_hours = ctx.data().get_reset_grace_hours(guild_id);
+    let now = chrono::Utc::now();
+
+    let records: Vec<_> = ctx
+        .data()
+        .get_vote_history(guild_id)
+        .into_iter()
+        .filter(|record| kind.is_none_or(|kind| record.action.kind() == kind))
+        .take(HISTORY_LIMIT)
+        .collect();
+    let snapshots = if kind.is_none_or(|kind| kind == ProposalKind::Reset) {
+        ctx.data().get_reset_snapshots(guild_id)
+    } else {
+        Vec::new()
+    };
+    let treasury = ctx.data().get_treasury(guild_id);
+    if records.is_empty() && snapshots.is_empty() {
+        ctx.say(tr!(locale, "vote-history-none")).await?;
+        return Ok(());
+    }
+
+    let mut lines = Vec::new();
+    if !records.is_empty() {
+        lines.push(tr!(locale, "vote-history"));
+        lines.extend(records.iter().map(|record| {
+            let result = match record.result {
+                VoteResult::Passed => "passed",
+                VoteResult::Failed => "failed",
+                VoteResult::Cancelled => "cancelled",
+            };
+            tr!(
+                locale,
+                "vote-history-vote",
+                id = record.proposal_id,
+                action = record.action.describe(locale, &currency),
+                date = record.closed_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                result = result,
+                yes = record.tally.yes_votes,
+                no = record.tally.no_votes,
+            )
+        }));
+    }
+
+    if !snapshots.is_empty() {
+        lines.push(tr!(locale, "vote-history-resets"));
+        lines.extend(snapshots.iter().map(|snapshot| {
+            let until = snapshot.restorable_until(grace_hours);
+            let state = if snapshot.restored_at.is_some() {
+                "restored"
+            } else if now < until {
+                "restorable"
+            } else {
+                "final"
+            };
+            tr!(
+                locale,
+                "vote-history-entry",
+                name = snapshot.name.as_str(),
+                id = snapshot.proposal_id,
+                date = snapshot.taken_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                mode = snapshot.mode.describe(locale, &currency),
+                total = currency.format(u32::try_from(snapshot.total()).unwrap_or(u32::MAX)),
+                users = snapshot.balances.len(),
+                state = state,
+                until = until.format("%Y-%m-%d %H:%M UTC").to_string(),
+            )
+        }));
+        if treasury > 0 {
+            lines.push(tr!(
+                locale,
+                "vote-history-treasury",
+                amount = currency.format(treasury)
+            ));
+        }
+    }
+    ctx.send(
+        poise::CreateReply::default()
+            .content(lines.join("\n"))
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    logging::log_command(
+        "vote_history",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("votes: {}, snapshots: {}", records.len(), snapshots.len()),
+        true,
+    );
+    Ok(())
+}
+
+/// Show the pass rate, average turnout and most active voters
+#[poise::command(slash_command, guild_only)]
+pub async fn stats(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    let records = ctx.data().get_vote_history(guild_id);
+    if records.is_empty() {
+        ctx.say(tr!(locale, "vote-history-none")).await?;
+        return Ok(());
+    }
+
+    let stats = VoteStats::from_records(&records, STATS_TOP_VOTERS);
+    let mut lines = vec![tr!(
+        locale,
+        "vote-stats",
+        votes = records.len(),
+        passed = stats.passed,
+        failed = stats.failed,
+        cancelled = stats.cancelled,
+        pass_rate = format!("{:.0}", stats.pass_rate),
+        turnout = format!("{:.1}", stats.average_turnout),
+    )];
+    if !stats.top_voters.is_empty() {
+        lines.push(tr!(locale, "vote-stats-voters"));
+        lines.extend(
+            stats
+                .top_voters
+                .iter()
+                .enumerate()
+                .map(|(rank, (user_id, ballots))| {
+                    tr!(
+                        locale,
+                        "vote-stats-voter",
+                        rank = rank + 1,
+                        user = format!("<@{user_id}>"),
+                        ballots = *ballots,
+                    )
+                }),
+        );
+    }
+    ctx.send(
+        poise::CreateReply::default()
+            .content(lines.join("\n"))
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    logging::log_command(
+        "vote_stats",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("votes: {}", records.len()),
+        true,
+    );
+    Ok(())
+}
+
+/// Get a DM shortly before votes in this server close
+#[poise::command(slash_command, guild_only)]
+pub async fn remind(
+    ctx: Context<'_>,
+    #[description = "Whether to get reminder DMs for votes you haven't voted on"] enabled: bool,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+
+    ctx.data()
+        .set_reminder_subscription(guild_id, ctx.author().id, enabled);
+    let id = if enabled {
+        "vote-remind-on"
+    } else {
+        "vote-remind-off"
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(tr!(locale, id))
+            .ephemeral(true),
+    )
+    .await?;
+
+    logging::log_command(
+        "vote_remind",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("enabled: {enabled}"),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// The invoking member, for eligibility checks
+async fn author_voter(ctx: Context<'_>) -> Voter {
+    match ctx.author_member().await {
+        Some(member) => Voter::from_member(&member),
+        None => Voter::from_user(ctx.author()),
+    }
+}
+
+/// Open a proposal and announce it in the channel
+async fn open_proposal(ctx: Context<'_>, action: ProposalAction) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let locale = i18n::locale(ctx);
+    let kind = action.kind();
+
+    let initiator = author_voter(ctx).await;
+    match ctx
+        .data()
+        .start_proposal(guild_id, action, &initiator, ctx.channel_id())
+    {
+        Ok((proposal_id, end_time)) => {
+            let end_time_str = end_time.format("%H:%M:%S UTC");
+
+            // The public message is edited later, so it uses the server's language
+            let guild_locale = i18n::guild_locale(ctx.data(), guild_id);
+            let status = ctx
+                .data()
+                .get_proposal(guild_id, proposal_id)
+                .unwrap_or_default();
+            let vote_config = ctx.data().get_effective_vote_config(guild_id, kind);
+            let currency = ctx.data().get_currency(Some(guild_id));
+            let reply = poise::CreateReply::default()
+                .content(vote_message::render(
+                    guild_locale,
+                    &status,
+                    &vote_config,
+                    &currency,
+                ))
+                .components(vote_message::components(guild_locale, proposal_id, false))
+                // Poll questions are written by members, so they mustn't ping anyone
+                .allowed_mentions(serenity::CreateAllowedMentions::new());
+
+            let handle = ctx.send(reply).await?;
+            let message_id = handle.message().await?.id;
+            ctx.data()
+                .set_vote_message(guild_id, proposal_id, message_id);
+
+            // Log successful vote start
+            logging::log_command(
+                "vote_start",
+                Some(guild_id.get()),
+                ctx.author().id.get(),
+                &format!("proposal: {proposal_id}, kind: {kind:?}, end_time: {end_time_str}"),
+                true,
+            );
+        }
+        Err(e) => {
+            // The initiator may lack the required role, named without a ping
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(tr!(locale, "error-prefix", error = e.describe(locale)))
+                    .allowed_mentions(serenity::CreateAllowedMentions::new()),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Propose a change for the server to vote on
 #[poise::command(
     slash_command,
     guild_only,
-    subcommands("status", "config"),
+    subcommands("setting", "grant", "giver_role", "poll"),
     subcommand_required
 )]
-pub async fn vote_admin(_: Context<'_>) -> Result<(), Error> {
+pub async fn propose(_: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
 
-/// Check the status of the current vote
+/// Propose changing a vote setting
 #[poise::command(slash_command, guild_only)]
-pub async fn status(ctx: Context<'_>) -> Result<(), Error> {
-    let guild_id = ctx.guild_id().unwrap();
-    let vote_config = ctx.data().get_vote_config(guild_id);
-
-    // Check if the vote has expired
-    if let Some(vote_passed) = ctx.data().check_vote_expiry(guild_id) {
-        let result_str = if vote_passed {
-            "The vote has ended and PASSED. All AndyCoins have been reset to 0."
-        } else {
-            "The vote has ended and FAILED. Not enough votes or majority not reached."
-        };
-        ctx.say(result_str).await?;
+pub async fn setting(
+    ctx: Context<'_>,
+    #[description = "Type of proposal whose setting changes"] kind: ProposalKind,
+    #[description = "Setting to change"] field: VoteField,
+    #[description = "New value"] value: u32,
+) -> Result<(), Error> {
+    if value > field.max() {
+        let locale = i18n::locale(ctx);
+        ctx.say(too_large(locale, field)).await?;
         return Ok(());
     }
 
-    let vote_status = ctx.data().get_vote_status(guild_id);
+    open_proposal(ctx, ProposalAction::Setting { kind, field, value }).await
+}
 
-    // Check if a vote is active
-    if !vote_status.active {
-        // Check if there's a cooldown
-        if let Some(last_vote_time) = vote_status.last_vote_time {
-            let cooldown_duration = chrono::Duration::hours(i64::from(vote_config.cooldown_hours));
-            let now = chrono::Utc::now();
+/// Propose giving AndyCoins to a user
+#[poise::command(slash_command, guild_only)]
+pub async fn grant(
+    ctx: Context<'_>,
+    #[description = "User to give the AndyCoins to"] user: serenity::User,
+    #[description = "Amount of AndyCoins to give"] amount: u32,
+) -> Result<(), Error> {
+    if amount == 0 {
+        let locale = i18n::locale(ctx);
+        ctx.say(tr!(locale, "proposal-grant-invalid")).await?;
+        return Ok(());
+    }
+
+    let user_id = user.id.get();
+    open_proposal(ctx, ProposalAction::Grant { user_id, amount }).await
+}
 
-            if now < last_vote_time + cooldown_duration {
-                let cooldown_end = last_vote_time + cooldown_duration;
-                let cooldown_end_str = cooldown_end.format("%H:%M:%S UTC on %Y-%m-%d");
+/// Propose adding or removing a giver role
+#[poise::command(slash_command, guild_only)]
+pub async fn giver_role(
+    ctx: Context<'_>,
+    #[description = "Role to add or remove"] role: serenity::Role,
+    #[description = "Remove the role instead of adding it"] remove: Option<bool>,
+) -> Result<(), Error> {
+    let role_id = role.id.get();
+    let add = !remove.unwrap_or(false);
+    open_proposal(ctx, ProposalAction::GiverRole { role_id, add }).await
+}
+
+/// Ask the server a yes or no question
+#[poise::command(slash_command, guild_only)]
+pub async fn poll(
+    ctx: Context<'_>,
+    #[description = "Question to ask"] question: String,
+) -> Result<(), Error> {
+    let question = question.trim().to_string();
+    if question.is_empty() || question.chars().count() > MAX_POLL_QUESTION {
+        let locale = i18n::locale(ctx);
+        ctx.say(tr!(
+            locale,
+            "poll-question-invalid",
+            max = MAX_POLL_QUESTION
+        ))
+        .await?;
+        return Ok(());
+    }
+
+    open_proposal(ctx, ProposalAction::Poll { question }).await
+}
+
+/// Configure vote settings
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR",
+    subcommands(
+        "config",
+        "eligibility",
+        "reset_mode",
+        "quorum",
+        "reminder",
+        "restore",
+        "cancel",
+        "extend",
+        "end_now"
+    ),
+    subcommand_required
+)]
+pub async fn vote_admin(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// One line per open proposal, for status overviews
+pub fn proposal_summaries(
+    locale: &str,
+    proposals: &[VoteStatus],
+    currency: &CurrencyConfig,
+) -> String {
+    proposals
+        .iter()
+        .map(|status| {
+            let end_time_str = status.end_time.map_or(tr!(locale, "unknown"), |end| {
+                end.format("%H:%M:%S UTC").to_string()
+            });
+            if status.is_secret() {
+                return tr!(
+                    locale,
+                    "proposal-summary-secret",
+                    id = status.proposal_id,
+                    action = status.action.describe(locale, currency),
+                    end_time = end_time_str,
+                    ballots = status.ballots(),
+                );
+            }
+            tr!(
+                locale,
+                "proposal-summary",
+                id = status.proposal_id,
+                action = status.action.describe(locale, currency),
+                end_time = end_time_str,
+                yes = status.yes_votes.len(),
+                no = status.no_votes.len(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check the status of the current vote
+#[poise::command(slash_command, guild_only)]
+pub async fn status(
+    ctx: Context<'_>,
+    #[description = "Number of the proposal to check (default: all open ones)"] proposal: Option<
+        u32,
+    >,
+) -> Result<(), Error> {
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let locale = i18n::locale(ctx);
 
-                ctx.say(format!("No active vote. A vote was recently completed. The next vote can be started at {cooldown_end_str}.")).await?;
+    let open = ctx.data().get_open_proposals(guild_id);
+    let vote_status = match (proposal, open.as_slice()) {
+        (Some(id), _) => match open.iter().find(|s| s.proposal_id == id) {
+            Some(status) => status.clone(),
+            None => {
+                let error = VoteError::UnknownProposal;
+                ctx.say(tr!(locale, "error-prefix", error = error.describe(locale)))
+                    .await?;
                 return Ok(());
             }
+        },
+        (None, [only]) => only.clone(),
+        (None, []) => {
+            // No open proposals, so report on reset votes
+            let cooldown_end =
+                ctx.data()
+                    .get_cooldown_end(guild_id, ProposalKind::Reset, chrono::Utc::now());
+            let response = match cooldown_end {
+                Some(until) => tr!(
+                    locale,
+                    "vote-status-cooldown",
+                    cooldown_end = until.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+                ),
+                None => tr!(locale, "vote-status-none"),
+            };
+            ctx.say(response).await?;
+            return Ok(());
         }
-
-        ctx.say("No active vote. Use `/vote` to start a new vote.")
+        (None, _) => {
+            let response = format!(
+                "{}\n{}",
+                tr!(locale, "vote-status-list"),
+                proposal_summaries(locale, &open, &currency)
+            );
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(response)
+                    .allowed_mentions(serenity::CreateAllowedMentions::new()),
+            )
             .await?;
-        return Ok(());
-    }
+            return Ok(());
+        }
+    };
 
-    // Get vote information
-    let yes_votes = vote_status.yes_votes.len();
-    let no_votes = vote_status.no_votes.len();
-    let total_votes = yes_votes + no_votes;
-    let yes_percentage = if total_votes > 0 {
-        (yes_votes as f64 / total_votes as f64) * 100.0
-    } else {
-        0.0
+    let vote_config = ctx
+        .data()
+        .get_effective_vote_config(guild_id, vote_status.action.kind());
+    let end_time = match vote_status.phase(&vote_config, chrono::Utc::now()) {
+        VotePhase::Active { end_time } => end_time,
+        _ => {
+            ctx.say(tr!(locale, "vote-status-ended")).await?;
+            return Ok(());
+        }
     };
 
     // Format end time
-    let end_time_str = if let Some(end_time) = vote_status.end_time {
-        end_time.format("%H:%M:%S UTC").to_string()
-    } else {
-        "Unknown".to_string()
-    };
+    let end_time_str = end_time.format("%H:%M:%S UTC").to_string();
 
     // Format initiator
     let initiator_str = if let Some(initiator_id) = vote_status.initiator_id {
         format!("<@{initiator_id}>")
     } else {
-        "Unknown".to_string()
+        tr!(locale, "unknown")
     };
 
-    // Build response
-    let mut response = String::new();
-    writeln!(&mut response, "🗳️ **AndyCoin Reset Vote Status**")?;
-    writeln!(&mut response, "Initiator: {initiator_str}")?;
-    writeln!(&mut response, "End Time: {end_time_str}")?;
-    writeln!(
-        &mut response,
-        "Votes: {yes_votes} YES / {no_votes} NO (Total: {total_votes})",
-    )?;
-    writeln!(
-        &mut response,
-        "Current YES Percentage: {yes_percentage:.1}%",
-    )?;
-    writeln!(
-        &mut response,
-        "Required: At least {} votes with {}% majority",
-        vote_config.min_votes, vote_config.majority_percentage
-    )?;
+    // A percentage quorum explains where the minimum comes from
+    let quorum_note = match vote_config.quorum {
+        Quorum::Votes => String::new(),
+        Quorum::ActiveMembers { percent, days } => {
+            let note = tr!(
+                locale,
+                "vote-status-quorum",
+                percent = percent,
+                days = days,
+                active = ctx.data().get_active_members(guild_id, days),
+            );
+            format!("\n{note}")
+        }
+    };
+
+    // Secret ballots keep their tally hidden until the vote ends
+    if vote_status.is_secret() {
+        let ballots = vote_status.ballots();
+        let response = tr!(
+            locale,
+            "vote-status-active-secret",
+            id = vote_status.proposal_id,
+            action = vote_status.action.describe(locale, &currency),
+            initiator = initiator_str,
+            end_time = end_time_str,
+            ballots = ballots,
+            min_votes = vote_config.min_votes,
+            majority = vote_config.majority_percentage,
+        );
+        ctx.send(
+            poise::CreateReply::default()
+                .content(response + &quorum_note)
+                .allowed_mentions(serenity::CreateAllowedMentions::new()),
+        )
+        .await?;
+
+        logging::log_command(
+            "vote_status",
+            Some(guild_id.get()),
+            ctx.author().id.get(),
+            &format!(
+                "proposal: {}, secret, total: {ballots}",
+                vote_status.proposal_id
+            ),
+            true,
+        );
+        return Ok(());
+    }
+
+    // Get vote information, weighted by current balances
+    let tally = ctx.data().tally(guild_id, &vote_status);
+    let yes_votes = tally.yes_votes;
+    let no_votes = tally.no_votes;
+    let total_votes = yes_votes + no_votes;
+    let yes_percentage = tally.yes_percentage();
 
     // Check if the vote would pass with current numbers
-    if total_votes >= vote_config.min_votes as usize {
-        if yes_percentage >= f64::from(vote_config.majority_percentage) {
-            writeln!(&mut response, "Status: Would PASS with current votes")?;
-        } else {
-            writeln!(&mut response, "Status: Would FAIL with current votes")?;
-        }
+    let outlook = if total_votes < vote_config.min_votes as usize {
+        "not-enough"
+    } else if vote_config.passes(&tally) {
+        "pass"
     } else {
-        writeln!(&mut response, "Status: Not enough votes yet")?;
-    }
+        "fail"
+    };
 
-    ctx.say(response).await?;
+    // Build response
+    let response = tr!(
+        locale,
+        "vote-status-active",
+        id = vote_status.proposal_id,
+        action = vote_status.action.describe(locale, &currency),
+        initiator = initiator_str,
+        end_time = end_time_str,
+        yes = yes_votes,
+        no = no_votes,
+        total = total_votes,
+        percentage = format!("{yes_percentage:.1}"),
+        min_votes = vote_config.min_votes,
+        majority = vote_config.majority_percentage,
+        outlook = outlook,
+    );
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response + &quorum_note)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
 
     // Log status check
     logging::log_command(
         "vote_status",
         Some(guild_id.get()),
         ctx.author().id.get(),
-        &format!("yes: {yes_votes}, no: {no_votes}, total: {total_votes}"),
+        &format!(
+            "proposal: {}, yes: {yes_votes}, no: {no_votes}, total: {total_votes}",
+            vote_status.proposal_id
+        ),
         true,
     );
     Ok(())
 }
 
 /// Configure vote settings
-#[poise::command(slash_command, guild_only)]
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+#[allow(clippy::too_many_arguments)]
 pub async fn config(
     ctx: Context<'_>,
+    #[description = "Type of proposal to configure (default: reset)"] kind: Option<ProposalKind>,
     #[description = "Cooldown hours between votes (default: 24)"] cooldown_hours: Option<u32>,
     #[description = "Duration of voting in minutes (default: 30)"] duration_minutes: Option<u32>,
     #[description = "Minimum number of votes required (default: 10)"] min_votes: Option<u32>,
     #[description = "Percentage of YES votes required to pass (default: 70)"]
     majority_percentage: Option<u32>,
+    #[description = "Hours after a reset during which admins can restore it (default: 24)"]
+    restore_grace_hours: Option<u32>,
+    #[description = "Whether cancelled votes start the cooldown (default: false)"]
+    cancel_cooldown: Option<bool>,
+    #[description = "Whether votes are secret ballots with hidden running tallies (default: false)"]
+    secret: Option<bool>,
 ) -> Result<(), Error> {
-    let guild_id = ctx.guild_id().expect("Guild ID not found");
-
-    // Check if user has permission (server owner or admin)
-    #[allow(deprecated)]
-    let permissions = ctx
-        .author_member()
-        .await
-        .unwrap()
-        .permissions(ctx.cache())
-        .unwrap();
-    if !permissions.administrator() && ctx.author().id != ctx.guild().unwrap().owner_id {
-        ctx.say("You need to be a server administrator to configure vote settings.")
-            .await?;
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
         return Ok(());
-    }
+    };
+    let kind = kind.unwrap_or(ProposalKind::Reset);
 
     // Get current config
-    let mut vote_config = ctx.data().get_vote_config(guild_id);
+    let mut vote_config = ctx.data().get_vote_config(guild_id, kind);
+
+    // Reject values too large to schedule
+    let too_big = [
+        (VoteField::CooldownHours, cooldown_hours),
+        (VoteField::DurationMinutes, duration_minutes),
+        (VoteField::MajorityPercentage, majority_percentage),
+    ]
+    .into_iter()
+    .find(|(field, value)| value.is_some_and(|value| value > field.max()));
+    if let Some((field, _)) = too_big {
+        ctx.say(too_large(locale, field)).await?;
+        return Ok(());
+    }
+    if restore_grace_hours.is_some_and(|hours| hours > MAX_RESET_GRACE_HOURS) {
+        ctx.say(tr!(
+            locale,
+            "vote-config-invalid-grace",
+            max = MAX_RESET_GRACE_HOURS
+        ))
+        .await?;
+        return Ok(());
+    }
 
     // Update config with provided values
     if let Some(hours) = cooldown_hours {
@@ -272,35 +807,49 @@ pub async fn config(
         vote_config.min_votes = votes;
     }
 
+    if let Some(cooldown) = cancel_cooldown {
+        vote_config.cancel_cooldown = cooldown;
+    }
+
+    if let Some(secret) = secret {
+        vote_config.secret = secret;
+    }
+
     if let Some(percentage) = majority_percentage {
-        if percentage > 100 {
-            ctx.say("Majority percentage cannot be greater than 100%.")
-                .await?;
-            return Ok(());
-        }
         vote_config.majority_percentage = percentage;
     }
 
     // Save the updated config
-    ctx.data().set_vote_config(guild_id, &vote_config);
+    ctx.data().set_vote_config(guild_id, kind, &vote_config);
+    if let Some(hours) = restore_grace_hours {
+        ctx.data().set_reset_grace_hours(guild_id, hours);
+    }
 
     let VoteConfig {
         cooldown_hours,
         duration_minutes,
         min_votes,
         majority_percentage: majority,
+        ..
     } = vote_config.clone();
 
     // Build response
-    let mut response = String::new();
-    writeln!(&mut response, "✅ **Vote Settings Updated**")?;
-    writeln!(
-        &mut response,
-        "Cooldown between votes: {cooldown_hours} hours",
-    )?;
-    writeln!(&mut response, "Vote duration: {duration_minutes} minutes")?;
-    writeln!(&mut response, "Minimum votes required: {min_votes}")?;
-    writeln!(&mut response, "Majority percentage required: {majority}%")?;
+    let response = tr!(
+        locale,
+        "vote-config-updated",
+        kind = kind.label(locale),
+        cooldown_hours = cooldown_hours,
+        duration_minutes = duration_minutes,
+        min_votes = min_votes,
+        majority = majority,
+        restore_grace_hours = ctx.data().get_reset_grace_hours(guild_id),
+        cancel_cooldown = if vote_config.cancel_cooldown {
+            "yes"
+        } else {
+            "no"
+        },
+        secret = if vote_config.secret { "yes" } else { "no" },
+    );
 
     ctx.say(response).await?;
 
@@ -310,17 +859,506 @@ pub async fn config(
         Some(guild_id.get()),
         ctx.author().id.get(),
         &format!(
-            "cooldown: {cooldown_hours}, duration: {duration_minutes}, min_votes: {min_votes}, majority: {majority}"
+            "kind: {kind:?}, cooldown: {cooldown_hours}, duration: {duration_minutes}, min_votes: {min_votes}, majority: {majority}, secret: {}",
+            vote_config.secret
         ),
         true,
     );
 
     // Save data to file
     if let Err(e) = ctx.data().save().await {
-        ctx.say(format!("Warning: Failed to save settings: {e}"))
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Configure who may vote and how ballots are weighted
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+#[allow(clippy::too_many_arguments)]
+pub async fn eligibility(
+    ctx: Context<'_>,
+    #[description = "Type of proposal to configure (default: reset)"] kind: Option<ProposalKind>,
+    #[description = "Minimum age of the voter's Discord account in days (default: 0)"]
+    min_account_age_days: Option<u32>,
+    #[description = "Minimum days the voter has been in the server (default: 0)"]
+    min_member_days: Option<u32>,
+    #[description = "Role required to vote"] required_role: Option<serenity::Role>,
+    #[description = "Remove the required role"] clear_required_role: Option<bool>,
+    #[description = "Minimum balance required to vote (default: 0)"] min_balance: Option<u32>,
+    #[description = "How ballots are weighted (default: one vote per person)"] weighting: Option<
+        VoteWeighting,
+    >,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let kind = kind.unwrap_or(ProposalKind::Reset);
+
+    let mut vote_config = ctx.data().get_vote_config(guild_id, kind);
+    if let Some(days) = min_account_age_days {
+        vote_config.min_account_age_days = days;
+    }
+    if let Some(days) = min_member_days {
+        vote_config.min_member_days = days;
+    }
+    if clear_required_role.unwrap_or(false) {
+        vote_config.required_role = None;
+    }
+    if let Some(role) = required_role {
+        vote_config.required_role = Some(role.id.get());
+    }
+    if let Some(balance) = min_balance {
+        vote_config.min_balance = balance;
+    }
+    if let Some(weighting) = weighting {
+        vote_config.weighting = weighting;
+    }
+    ctx.data().set_vote_config(guild_id, kind, &vote_config);
+
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let role = vote_config
+        .required_role
+        .map_or(tr!(locale, "none"), |role_id| format!("<@&{role_id}>"));
+    let response = tr!(
+        locale,
+        "vote-eligibility-updated",
+        kind = kind.label(locale),
+        account_age_days = vote_config.min_account_age_days,
+        member_days = vote_config.min_member_days,
+        role = role,
+        min_balance = currency.format(vote_config.min_balance),
+        weighting = vote_config.weighting.label(locale),
+    );
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    logging::log_command(
+        "vote_eligibility",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "kind: {kind:?}, account_age_days: {}, member_days: {}, role: {:?}, min_balance: {}, weighting: {:?}",
+            vote_config.min_account_age_days,
+            vote_config.min_member_days,
+            vote_config.required_role,
+            vote_config.min_balance,
+            vote_config.weighting
+        ),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Choose how reset votes change balances
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn reset_mode(
+    ctx: Context<'_>,
+    #[description = "How balances change when a reset vote passes"] mode: ResetModeKind,
+    #[description = "Percentage to keep or tax, or the cap, for modes that need one"]
+    amount: Option<u32>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let Some(mode) = ResetMode::from_choice(mode, amount) else {
+        ctx.say(tr!(locale, "reset-mode-invalid")).await?;
+        return Ok(());
+    };
+    ctx.data().set_reset_mode(guild_id, mode);
+
+    let currency = ctx.data().get_currency(Some(guild_id));
+    ctx.say(tr!(
+        locale,
+        "reset-mode-updated",
+        mode = mode.describe(locale, &currency)
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_reset_mode",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("mode: {mode:?}"),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Choose how many ballots votes need
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn quorum(
+    ctx: Context<'_>,
+    #[description = "Type of proposal to configure (default: reset)"] kind: Option<ProposalKind>,
+    #[description = "A fixed number of votes (min_votes), or a share of active members"]
+    mode: QuorumKind,
+    #[description = "Percentage of active members whose votes are needed"] percent: Option<u32>,
+    #[description = "Members count as active if seen in this many days"] days: Option<u32>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let kind = kind.unwrap_or(ProposalKind::Reset);
+
+    let Some(quorum) = Quorum::from_choice(mode, percent, days) else {
+        ctx.say(tr!(
+            locale,
+            "vote-quorum-invalid",
+            max_days = MAX_ACTIVITY_DAYS
+        ))
+        .await?;
+        return Ok(());
+    };
+    let mut vote_config = ctx.data().get_vote_config(guild_id, kind);
+    vote_config.quorum = quorum;
+    ctx.data().set_vote_config(guild_id, kind, &vote_config);
+
+    let active = match quorum {
+        Quorum::Votes => 0,
+        Quorum::ActiveMembers { days, .. } => ctx.data().get_active_members(guild_id, days),
+    };
+    let required = ctx
+        .data()
+        .get_effective_vote_config(guild_id, kind)
+        .min_votes;
+    ctx.say(tr!(
+        locale,
+        "vote-quorum-updated",
+        kind = kind.label(locale),
+        quorum = quorum.describe(locale, vote_config.min_votes),
+        active = active,
+        required = required,
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_quorum",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("kind: {kind:?}, quorum: {quorum:?}"),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Send reminders shortly before votes close
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn reminder(
+    ctx: Context<'_>,
+    #[description = "Type of proposal to configure (default: reset)"] kind: Option<ProposalKind>,
+    #[description = "Minutes before the end to send reminders (0 turns them off)"] minutes: u32,
+    #[description = "Role to ping in the vote's channel"] role: Option<serenity::Role>,
+    #[description = "Stop pinging a role"] clear_role: Option<bool>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+    let kind = kind.unwrap_or(ProposalKind::Reset);
+
+    let mut vote_config = ctx.data().get_vote_config(guild_id, kind);
+    vote_config.reminder_minutes = (minutes > 0).then_some(minutes);
+    if clear_role.unwrap_or(false) {
+        vote_config.reminder_role = None;
+    }
+    if let Some(role) = role {
+        vote_config.reminder_role = Some(role.id.get());
+    }
+    ctx.data().set_vote_config(guild_id, kind, &vote_config);
+
+    let response = match vote_config.reminder_minutes {
+        Some(minutes) => tr!(
+            locale,
+            "vote-reminder-updated",
+            kind = kind.label(locale),
+            minutes = minutes,
+            role = vote_config
+                .reminder_role
+                .map_or(tr!(locale, "none"), |role_id| format!("<@&{role_id}>")),
+        ),
+        None => tr!(locale, "vote-reminder-off", kind = kind.label(locale)),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(serenity::CreateAllowedMentions::new()),
+    )
+    .await?;
+
+    logging::log_command(
+        "vote_reminder",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "kind: {kind:?}, minutes: {minutes}, role: {:?}",
+            vote_config.reminder_role
+        ),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Give back the balances taken by a recent reset
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn restore(
+    ctx: Context<'_>,
+    #[description = "Name of the snapshot to restore, from /vote history (default: the latest)"]
+    snapshot: Option<String>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let restored = ctx.data().restore_reset(
+        guild_id,
+        snapshot.as_deref().map(str::trim),
+        ctx.author().id,
+        chrono::Utc::now(),
+    );
+    let snapshot = match restored {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            ctx.say(tr!(locale, "error-prefix", error = e.describe(locale)))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let users = snapshot.changes().count();
+    ctx.say(tr!(
+        locale,
+        "vote-restore-done",
+        name = snapshot.name.as_str(),
+        users = users,
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_restore",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("snapshot: {}, users: {users}", snapshot.name),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Cancel an open proposal without applying it
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn cancel(
+    ctx: Context<'_>,
+    #[description = "Number of the proposal to cancel (default: the only open one)"]
+    proposal: Option<u32>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let status = match ctx
+        .data()
+        .cancel_vote(guild_id, proposal, chrono::Utc::now())
+    {
+        Ok(status) => status,
+        Err(e) => {
+            ctx.say(tr!(locale, "error-prefix", error = e.describe(locale)))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let guild_locale = i18n::guild_locale(ctx.data(), guild_id);
+    let config = ctx
+        .data()
+        .get_effective_vote_config(guild_id, status.action.kind());
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let content = vote_message::render_cancelled(guild_locale, &status, &config, &currency);
+    vote_message::edit(ctx.http(), guild_locale, &status, content, true).await;
+
+    ctx.say(tr!(
+        locale,
+        "vote-cancelled",
+        id = status.proposal_id,
+        admin = format!("<@{}>", ctx.author().id)
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_cancel",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!("proposal: {}", status.proposal_id),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Give an open proposal more time
+#[poise::command(slash_command, guild_only, check = "permissions::administrator_check")]
+pub async fn extend(
+    ctx: Context<'_>,
+    #[description = "Minutes to add"] minutes: u32,
+    #[description = "Number of the proposal to extend (default: the only open one)"]
+    proposal: Option<u32>,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    if minutes == 0 {
+        ctx.say(tr!(locale, "vote-extend-invalid")).await?;
+        return Ok(());
+    }
+
+    let status = match ctx
+        .data()
+        .extend_vote(guild_id, proposal, minutes, chrono::Utc::now())
+    {
+        Ok(status) => status,
+        Err(e) => {
+            ctx.say(tr!(locale, "error-prefix", error = e.describe(locale)))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Show the new end time in the vote message
+    let guild_locale = i18n::guild_locale(ctx.data(), guild_id);
+    let config = ctx
+        .data()
+        .get_effective_vote_config(guild_id, status.action.kind());
+    let currency = ctx.data().get_currency(Some(guild_id));
+    let content = vote_message::render(guild_locale, &status, &config, &currency);
+    vote_message::edit(ctx.http(), guild_locale, &status, content, false).await;
+
+    let end_time = status.end_time.unwrap_or_default();
+    ctx.say(tr!(
+        locale,
+        "vote-extended",
+        id = status.proposal_id,
+        minutes = minutes,
+        end_time = end_time.format("%H:%M:%S UTC").to_string()
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_extend",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "proposal: {}, minutes: {minutes}, end_time: {end_time}",
+            status.proposal_id
+        ),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// End an open proposal now and apply the result
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "end-now",
+    check = "permissions::administrator_check"
+)]
+pub async fn end_now(
+    ctx: Context<'_>,
+    #[description = "Number of the proposal to end (default: the only open one)"] proposal: Option<
+        u32,
+    >,
+) -> Result<(), Error> {
+    let locale = i18n::locale(ctx);
+    let Some(guild_id) = require_guild(ctx).await? else {
+        return Ok(());
+    };
+
+    let vote = match ctx.data().end_vote(guild_id, proposal, chrono::Utc::now()) {
+        Ok(vote) => vote,
+        Err(e) => {
+            ctx.say(tr!(locale, "error-prefix", error = e.describe(locale)))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    ctx.say(tr!(
+        locale,
+        "vote-ended-now",
+        id = vote.status.proposal_id,
+        admin = format!("<@{}>", ctx.author().id)
+    ))
+    .await?;
+
+    logging::log_command(
+        "vote_end_now",
+        Some(guild_id.get()),
+        ctx.author().id.get(),
+        &format!(
+            "proposal: {}, yes: {}, no: {}, passed: {}",
+            vote.status.proposal_id, vote.tally.yes_votes, vote.tally.no_votes, vote.passed
+        ),
+        true,
+    );
+
+    if let Err(e) = ctx.data().save().await {
+        ctx.say(tr!(locale, "save-failed", error = e.to_string()))
             .await?;
     }
 
+    scheduler::publish_result(ctx.http(), ctx.data(), &vote).await;
     Ok(())
 }
 
@@ -0,0 +1,196 @@
+//! Cron-like schedules for recurring posts.
+//!
+//! A spec has the five usual fields, `minute hour day-of-month month day-of-week`,
+//! each a `*`, a number, a range `a-b`, a step `*/n` or `a-b/n`, or a comma list of
+//! those. Sunday is 0 or 7. The shortcuts `@hourly`, `@daily`, `@weekly` (Sunday
+//! midnight) and `@monthly` are accepted too. Times are in UTC. As in cron, when both
+//! day fields are restricted a day matching either one counts.
+
+use chrono::{Datelike, Timelike};
+
+/// How far ahead to look for the next run before giving up (e.g. on "0 0 31 2 *")
+const MAX_SEARCH_DAYS: i64 = 366 * 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CronSpec {
+    // Bit n is set when value n matches
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    // Whether the day fields were `*`, for cron's either-day rule
+    any_day: bool,
+    any_weekday: bool,
+}
+
+/// Parse one field into a bitmask of the values in `min..=max`
+fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                // A single value with a step runs to the end, like "5/15"
+                None if part.contains('/') => (range.parse().ok()?, max),
+                None => {
+                    let value = range.parse().ok()?;
+                    (value, value)
+                }
+            },
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Some(mask)
+}
+
+impl CronSpec {
+    /// Parse a spec, returning `None` if it isn't valid
+    pub fn parse(spec: &str) -> Option<Self> {
+        let expanded = match spec.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minutes, hours, days, months, weekdays] = fields[..] else {
+            return None;
+        };
+        // Sunday may be written as 7
+        let weekday_mask = parse_field(weekdays, 0, 7)?;
+        let weekday_mask = (weekday_mask | (weekday_mask >> 7)) & 0x7F;
+        Some(Self {
+            minutes: parse_field(minutes, 0, 59)?,
+            hours: u32::try_from(parse_field(hours, 0, 23)?).ok()?,
+            days: u32::try_from(parse_field(days, 1, 31)?).ok()?,
+            months: u16::try_from(parse_field(months, 1, 12)?).ok()?,
+            weekdays: u8::try_from(weekday_mask).ok()?,
+            any_day: days == "*",
+            any_weekday: weekdays == "*",
+        })
+    }
+
+    fn matches_day(&self, date: chrono::NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day, self.any_weekday) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+
+    /// First time after `after` that the spec matches, to the minute
+    pub fn next_after(
+        &self,
+        after: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + chrono::Duration::minutes(1);
+        let mut date = start.date_naive();
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_day(date) {
+                let first_minute = if date == start.date_naive() {
+                    start.hour() * 60 + start.minute()
+                } else {
+                    0
+                };
+                let found = (first_minute..24 * 60).find(|minute| {
+                    self.hours & (1 << (minute / 60)) != 0
+                        && self.minutes & (1 << (minute % 60)) != 0
+                });
+                if let Some(minute) = found {
+                    let time = chrono::NaiveTime::from_hms_opt(minute / 60, minute % 60, 0)?;
+                    return Some(date.and_time(time).and_utc());
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to parse a UTC time
+    fn utc(time: &str) -> chrono::DateTime<chrono::Utc> {
+        time.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(CronSpec::parse("0 18 * * 5").is_some());
+        assert!(CronSpec::parse("*/15 9-17 1,15 * 1-5").is_some());
+        assert_eq!(CronSpec::parse("@weekly"), CronSpec::parse("0 0 * * 7"));
+        assert_eq!(CronSpec::parse("@daily"), CronSpec::parse("0 0 * * *"));
+
+        // Wrong field counts, out of range values and bad steps
+        assert_eq!(CronSpec::parse("0 18 * *"), None);
+        assert_eq!(CronSpec::parse("0 18 * * 5 2025"), None);
+        assert_eq!(CronSpec::parse("60 * * * *"), None);
+        assert_eq!(CronSpec::parse("0 0 0 * *"), None);
+        assert_eq!(CronSpec::parse("*/0 * * * *"), None);
+        assert_eq!(CronSpec::parse("5-1 * * * *"), None);
+        assert_eq!(CronSpec::parse("every friday"), None);
+    }
+
+    #[test]
+    fn test_next_after() {
+        // Fridays at 18:00; 2025-01-01 was a Wednesday
+        let friday = CronSpec::parse("0 18 * * 5").unwrap();
+        assert_eq!(
+            friday.next_after(utc("2025-01-01T12:00:00Z")),
+            Some(utc("2025-01-03T18:00:00Z"))
+        );
+        // Strictly after, even when exactly on a run
+        assert_eq!(
+            friday.next_after(utc("2025-01-03T18:00:00Z")),
+            Some(utc("2025-01-10T18:00:00Z"))
+        );
+
+        let steps = CronSpec::parse("*/20 9 * * *").unwrap();
+        assert_eq!(
+            steps.next_after(utc("2025-01-01T09:20:30Z")),
+            Some(utc("2025-01-01T09:40:00Z"))
+        );
+        assert_eq!(
+            steps.next_after(utc("2025-01-01T09:59:00Z")),
+            Some(utc("2025-01-02T09:00:00Z"))
+        );
+
+        // Restricting both day fields matches either
+        let either = CronSpec::parse("0 0 15 * 1").unwrap();
+        assert_eq!(
+            either.next_after(utc("2025-01-01T00:00:00Z")),
+            Some(utc("2025-01-06T00:00:00Z"))
+        );
+        assert_eq!(
+            either.next_after(utc("2025-01-13T00:00:00Z")),
+            Some(utc("2025-01-15T00:00:00Z"))
+        );
+
+        // Leap days and dates that never come
+        let leap = CronSpec::parse("0 0 29 2 *").unwrap();
+        assert_eq!(
+            leap.next_after(utc("2025-01-01T00:00:00Z")),
+            Some(utc("2028-02-29T00:00:00Z"))
+        );
+        let never = CronSpec::parse("0 0 31 2 *").unwrap();
+        assert_eq!(never.next_after(utc("2025-01-01T00:00:00Z")), None);
+    }
+}
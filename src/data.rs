@@ -2,11 +2,23 @@ use poise::serenity_prelude::{self as serenity, RoleId};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::{
-    ops::{Deref, DerefMut},
+    collections::{BTreeMap, HashMap},
+    ops::Deref,
     path::Path,
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
-use crate::DATA_FILE;
+use crate::{
+    DATA_FILE,
+    cron::CronSpec,
+    i18n::{DEFAULT_LOCALE, tr},
+    names::NameCache,
+    voting::{
+        MAX_ACTIVITY_DAYS, ProposalAction, ProposalKind, Quorum, ResetMode, Tally, VoteConfig,
+        VoteError, VoteOutcome, VotePhase, VoteRecord, VoteResult, VoteStatus, Voter,
+    },
+};
+use andy_coin::ranking::{RankInfo, RankedUser, RankingIndex};
 
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct UserBalance {
@@ -15,48 +27,881 @@ pub struct UserBalance {
     pub balance: u32,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
-pub struct VoteConfig {
-    pub cooldown_hours: u32,
-    pub duration_minutes: u32,
-    pub min_votes: u32,
-    pub majority_percentage: u32,
+/// A finished vote and where to announce its result
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExpiredVote {
+    pub guild_id: serenity::GuildId,
+    /// The proposal as it closed, no longer among the open ones
+    pub status: VoteStatus,
+    pub tally: Tally,
+    pub passed: bool,
+}
+
+/// A reminder that an open vote is about to close
+#[derive(Debug, PartialEq, Eq)]
+pub struct VoteReminder {
+    pub guild_id: serenity::GuildId,
+    pub channel_id: Option<serenity::ChannelId>,
+    pub proposal_id: u32,
+    pub action: ProposalAction,
+    pub message_id: Option<serenity::MessageId>,
+    pub end_time: chrono::DateTime<chrono::Utc>,
+    /// Role to ping in the vote's channel
+    pub role_id: Option<RoleId>,
+    /// Opted-in members who haven't voted yet, to remind by DM
+    pub user_ids: Vec<serenity::UserId>,
+}
+
+/// Window over which a giver's minting budget is counted
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, poise::ChoiceParameter,
+)]
+pub enum BudgetPeriod {
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl BudgetPeriod {
+    /// Start of the budget window containing `now` (midnight UTC, Monday for weekly)
+    pub fn window_start(self, now: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+        use chrono::Datelike;
+
+        let days_back = match self {
+            BudgetPeriod::Daily => 0,
+            BudgetPeriod::Weekly => i64::from(now.weekday().num_days_from_monday()),
+        };
+        let day = now.date_naive() - chrono::Duration::days(days_back);
+        day.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+    }
+
+    /// Lowercase name used in responses
+    pub fn label(self, locale: &str) -> String {
+        match self {
+            BudgetPeriod::Daily => tr!(locale, "budget-period-daily"),
+            BudgetPeriod::Weekly => tr!(locale, "budget-period-weekly"),
+        }
+    }
+}
+
+/// Minting limits for a giver. `None` means unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintLimits {
+    pub max_per_give: Option<u32>,
+    pub budget: Option<u32>,
+}
+
+impl MintLimits {
+    /// Combine two sets of limits, keeping the more permissive value of each
+    fn most_permissive(self, other: MintLimits) -> MintLimits {
+        fn wider(a: Option<u32>, b: Option<u32>) -> Option<u32> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                _ => None,
+            }
+        }
+
+        MintLimits {
+            max_per_give: wider(self.max_per_give, other.max_per_give),
+            budget: wider(self.budget, other.budget),
+        }
+    }
+}
+
+/// Minting limits that apply to holders of a specific role
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoleMintLimits {
+    pub role_id: u64,
+    #[serde(flatten)]
+    pub limits: MintLimits,
+}
+
+/// Per-guild policy for how many coins givers may mint
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GiverPolicy {
+    #[serde(default)]
+    pub default_limits: MintLimits,
+    #[serde(default)]
+    pub budget_period: BudgetPeriod,
+    #[serde(default)]
+    pub role_limits: Vec<RoleMintLimits>,
+}
+
+impl GiverPolicy {
+    /// Resolve the limits for a giver holding `roles`.
+    ///
+    /// If any of the roles has its own limits, the most permissive of those applies;
+    /// otherwise the guild default does.
+    pub fn limits_for(&self, roles: &[serenity::RoleId]) -> MintLimits {
+        self.role_limits
+            .iter()
+            .filter(|rl| roles.iter().any(|r| r.get() == rl.role_id))
+            .map(|rl| rl.limits)
+            .reduce(MintLimits::most_permissive)
+            .unwrap_or(self.default_limits)
+    }
+
+    /// Set or clear the limits for a role
+    pub fn set_role_limits(&mut self, role_id: serenity::RoleId, limits: Option<MintLimits>) {
+        self.role_limits.retain(|rl| rl.role_id != role_id.get());
+        if let Some(limits) = limits {
+            self.role_limits.push(RoleMintLimits {
+                role_id: role_id.get(),
+                limits,
+            });
+        }
+    }
+}
+
+/// How much a giver has minted in the current budget window
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GiverUsage {
+    pub window_start: chrono::DateTime<chrono::Utc>,
+    pub minted: u32,
+}
+
+/// Hours after a reset during which admins can restore it, by default
+pub const DEFAULT_RESET_GRACE_HOURS: u32 = 24;
+/// Longest a reset can stay restorable, in hours (a year)
+pub const MAX_RESET_GRACE_HOURS: u32 = 8760;
+/// Number of reset snapshots kept per guild
+const MAX_RESET_SNAPSHOTS: usize = 10;
+/// Number of finished votes archived per guild
+const MAX_VOTE_HISTORY: usize = 500;
+/// Minutes between updates of a member's last activity
+const ACTIVITY_RESOLUTION_MINUTES: i64 = 60;
+/// Days of balance changes kept for windowed leaderboards
+pub const MAX_CHANGE_DAYS: u32 = 30;
+/// Finished seasons kept per guild
+const MAX_SEASON_HISTORY: usize = 100;
+/// Final standings archived per season
+pub const SEASON_STANDINGS: usize = 10;
+/// Top finishers awarded the season role unless configured otherwise
+pub const DEFAULT_SEASON_WINNERS: u32 = 3;
+
+/// A user's place on a posted leaderboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    pub rank: usize,
+    pub balance: u32,
+}
+
+/// Automatic leaderboard posts in a channel
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardSchedule {
+    pub channel_id: u64,
+    /// Cron-like spec of when to post, see [`CronSpec`]
+    pub spec: String,
+    /// When the leaderboard was last posted, or the schedule set up. The next post
+    /// is the first run of the spec after this.
+    pub last_run: chrono::DateTime<chrono::Utc>,
+    /// Map of user_id -> standing in the last post, for showing changes since
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub standings: BTreeMap<u64, Standing>,
+}
+
+impl LeaderboardSchedule {
+    /// When the leaderboard is posted next, if the spec ever runs again
+    pub fn next_run(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        CronSpec::parse(&self.spec)?.next_after(self.last_run)
+    }
+}
+
+/// A scheduled leaderboard post that is due
+#[derive(Debug, PartialEq, Eq)]
+pub struct LeaderboardPost {
+    pub guild_id: serenity::GuildId,
+    pub channel_id: serenity::ChannelId,
+    /// Standings in the previous post, and when it was posted
+    pub previous: BTreeMap<u64, Standing>,
+    pub previous_run: chrono::DateTime<chrono::Utc>,
+}
+
+/// Automatic balance resets at the end of each season
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonConfig {
+    /// Cron-like spec of when seasons end, see [`CronSpec`]
+    pub spec: String,
+    /// Number of the current season, from 1
+    pub number: u32,
+    pub started: chrono::DateTime<chrono::Utc>,
+    /// When the current season ends, if the spec ever runs again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Role awarded to the top finishers of each season
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub award_role: Option<u64>,
+    /// How many top finishers are awarded the role; ties share a place
+    #[serde(default = "default_season_winners")]
+    pub winners: u32,
+    /// Channel the final standings are announced in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<u64>,
+}
+
+fn default_season_winners() -> u32 {
+    DEFAULT_SEASON_WINNERS
+}
+
+/// Final standings of a finished season
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonRecord {
+    pub number: u32,
+    pub started: chrono::DateTime<chrono::Utc>,
+    pub ended: chrono::DateTime<chrono::Utc>,
+    /// Map of user_id -> final standing, for the top SEASON_STANDINGS
+    pub standings: BTreeMap<u64, Standing>,
+    /// Role awarded to the season's winners, and who was given it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub award_role: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub awarded: Vec<u64>,
+}
+
+impl SeasonRecord {
+    /// Final standings, best first
+    pub fn ranked(&self) -> Vec<(serenity::UserId, Standing)> {
+        let mut ranked: Vec<_> = self
+            .standings
+            .iter()
+            .map(|(&user_id, &standing)| (serenity::UserId::new(user_id), standing))
+            .collect();
+        ranked.sort_by_key(|(user_id, standing)| (standing.rank, *user_id));
+        ranked
+    }
+}
+
+/// A season that just ended, with the role changes still to make in Discord
+#[derive(Debug, PartialEq, Eq)]
+pub struct SeasonEnd {
+    pub guild_id: serenity::GuildId,
+    pub channel_id: Option<serenity::ChannelId>,
+    pub record: SeasonRecord,
+    /// The previous season's award role and winners, who lose it
+    pub previous_award: Option<(serenity::RoleId, Vec<serenity::UserId>)>,
+}
+
+/// The top `count` balances with their ranks. Equal balances share a rank and the
+/// next lower balance gets the next one, as on the leaderboard.
+fn top_standings(balances: &BTreeMap<u64, u32>, count: usize) -> BTreeMap<u64, Standing> {
+    let mut sorted: Vec<_> = balances
+        .iter()
+        .filter(|&(_, &balance)| balance > 0)
+        .map(|(&user_id, &balance)| (user_id, balance))
+        .collect();
+    sorted.sort_by_key(|&(user_id, balance)| (std::cmp::Reverse(balance), user_id));
+    let mut standings = BTreeMap::new();
+    let mut rank = 0;
+    let mut previous = None;
+    for (user_id, balance) in sorted.into_iter().take(count) {
+        if previous != Some(balance) {
+            rank += 1;
+            previous = Some(balance);
+        }
+        standings.insert(user_id, Standing { rank, balance });
+    }
+    standings
+}
+
+/// A role members hold while their balance is at least a threshold
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceReward {
+    pub role_id: u64,
+    pub threshold: u32,
+    /// Take the role away again when the balance drops below the threshold
+    #[serde(default)]
+    pub remove_below: bool,
+}
+
+impl BalanceReward {
+    /// Whether a balance calls for adding the role (`Some(true)`) or removing it
+    /// (`Some(false)`), or leaves it as it is
+    pub fn target(&self, balance: u32) -> Option<bool> {
+        if balance >= self.threshold {
+            Some(true)
+        } else if self.remove_below {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// The change a balance going from `previous` to `balance` calls for, if it
+    /// crosses the threshold
+    fn crossed(&self, previous: u32, balance: u32) -> Option<bool> {
+        let target = self.target(balance)?;
+        (self.target(previous) != Some(target)).then_some(target)
+    }
+}
+
+/// A reward role to add to or remove from a member in Discord
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoleChange {
+    pub guild_id: serenity::GuildId,
+    pub user_id: serenity::UserId,
+    pub role_id: RoleId,
+    pub add: bool,
+}
+
+/// A user's balance changes over some period
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChanges {
+    /// Coins from the bot itself, such as flips, grants and resets. Can be negative.
+    #[serde(default)]
+    pub earned: i64,
+    /// Coins given to the user by other members
+    #[serde(default)]
+    pub received: u64,
+    /// Coins the user gave to other members
+    #[serde(default)]
+    pub given: u64,
+}
+
+impl BalanceChanges {
+    /// Change of the user's balance
+    pub fn net(&self) -> i64 {
+        self.earned
+            .saturating_add(i64::try_from(self.received).unwrap_or(i64::MAX))
+    }
+
+    pub fn add(&mut self, other: &BalanceChanges) {
+        self.earned = self.earned.saturating_add(other.earned);
+        self.received = self.received.saturating_add(other.received);
+        self.given = self.given.saturating_add(other.given);
+    }
+}
+
+/// Balances of a guild taken just before a reset vote cleared them
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResetSnapshot {
+    /// Name admins use to pick the snapshot, e.g. "reset-3"
+    pub name: String,
+    pub proposal_id: u32,
+    pub taken_at: chrono::DateTime<chrono::Utc>,
+    /// Map of user_id -> balance before the reset
+    pub balances: BTreeMap<u64, u32>,
+    #[serde(default)]
+    pub mode: ResetMode,
+    /// Map of user_id -> balance right after the reset, where not 0
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub after: BTreeMap<u64, u32>,
+    /// Coins the reset moved into the treasury
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub to_treasury: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_by: Option<u64>,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+impl ResetSnapshot {
+    /// Total balance before the reset
+    pub fn total(&self) -> u64 {
+        self.balances
+            .values()
+            .map(|balance| u64::from(*balance))
+            .sum()
+    }
+
+    /// Users whose balance the reset changed, with their balance before and after
+    pub fn changes(&self) -> impl Iterator<Item = (u64, u32, u32)> + '_ {
+        self.balances.iter().filter_map(|(&user_id, &before)| {
+            let after = self.after.get(&user_id).copied().unwrap_or(0);
+            (before != after).then_some((user_id, before, after))
+        })
+    }
+
+    /// Until when admins can restore the snapshot
+    pub fn restorable_until(&self, grace_hours: u32) -> chrono::DateTime<chrono::Utc> {
+        crate::voting::saturating_add(
+            self.taken_at,
+            chrono::Duration::hours(i64::from(grace_hours)),
+        )
+    }
+}
+
+/// Reasons a reset can't be restored
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestoreError {
+    NoSnapshot,
+    UnknownSnapshot,
+    AlreadyRestored,
+    GraceExpired,
+}
+
+impl RestoreError {
+    /// Describe the error in a locale
+    pub fn describe(&self, locale: &str) -> String {
+        match self {
+            RestoreError::NoSnapshot => tr!(locale, "restore-error-no-snapshot"),
+            RestoreError::UnknownSnapshot => tr!(locale, "restore-error-unknown-snapshot"),
+            RestoreError::AlreadyRestored => tr!(locale, "restore-error-already-restored"),
+            RestoreError::GraceExpired => tr!(locale, "restore-error-grace-expired"),
+        }
+    }
+}
+
+impl std::fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.describe(DEFAULT_LOCALE))
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+/// Reasons a give can be rejected by the guild's giver policy
+#[derive(Debug, PartialEq, Eq)]
+pub enum MintError {
+    ExceedsMaxPerGive {
+        max: u32,
+    },
+    BudgetExhausted {
+        remaining: u32,
+        period: BudgetPeriod,
+    },
 }
 
-impl Default for VoteConfig {
+impl MintError {
+    /// Describe the error in a locale using a guild's currency
+    pub fn describe(&self, locale: &str, currency: &CurrencyConfig) -> String {
+        match self {
+            MintError::ExceedsMaxPerGive { max } => {
+                tr!(locale, "mint-exceeds-max", max = currency.format(*max))
+            }
+            MintError::BudgetExhausted { remaining, period } => tr!(
+                locale,
+                "mint-budget-exhausted",
+                period = period.label(locale),
+                remaining = currency.format(*remaining)
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for MintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.describe(DEFAULT_LOCALE, &CurrencyConfig::default()))
+    }
+}
+
+impl std::error::Error for MintError {}
+
+/// How a guild's currency is named and displayed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrencyConfig {
+    pub name: String,
+    pub plural: String,
+    pub symbol: Option<String>,
+    pub emoji: Option<String>,
+}
+
+impl Default for CurrencyConfig {
     fn default() -> Self {
         Self {
-            cooldown_hours: 24,      // Once per day
-            duration_minutes: 30,    // Half hour voting time
-            min_votes: 10,           // At least 10 votes
-            majority_percentage: 70, // 7/10 majority (70%)
+            name: "AndyCoin".to_string(),
+            plural: "AndyCoins".to_string(),
+            symbol: None,
+            emoji: None,
         }
     }
 }
 
-#[derive(Clone, Default, Serialize, Deserialize)]
-pub struct VoteStatus {
-    pub active: bool,
-    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
-    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
-    pub initiator_id: Option<u64>,
-    pub yes_votes: Vec<u64>,
-    pub no_votes: Vec<u64>,
-    pub last_vote_time: Option<chrono::DateTime<chrono::Utc>>,
+impl CurrencyConfig {
+    /// Name of the currency for `amount` coins, singular or plural
+    pub fn unit(&self, amount: u32) -> &str {
+        if amount == 1 {
+            &self.name
+        } else {
+            &self.plural
+        }
+    }
+
+    /// Format an amount for a sentence, e.g. "5 AndyCoins" or "🪙 5 Gold"
+    pub fn format(&self, amount: u32) -> String {
+        match &self.emoji {
+            Some(emoji) => format!("{emoji} {amount} {}", self.unit(amount)),
+            None => format!("{amount} {}", self.unit(amount)),
+        }
+    }
+
+    /// Format an amount compactly, using the symbol if there is one, e.g. "5 AC"
+    pub fn format_short(&self, amount: u32) -> String {
+        match (&self.emoji, &self.symbol) {
+            (Some(emoji), Some(symbol)) => format!("{emoji} {amount} {symbol}"),
+            (None, Some(symbol)) => format!("{amount} {symbol}"),
+            _ => self.format(amount),
+        }
+    }
+
+    /// Whether any of the texts could ping someone when shown in a message, like
+    /// `@everyone` or `<@&role>`. Custom emoji have no `@`, so they're fine.
+    pub fn has_mentions(&self) -> bool {
+        [Some(&self.name), Some(&self.plural)]
+            .into_iter()
+            .chain([self.symbol.as_ref(), self.emoji.as_ref()])
+            .flatten()
+            .any(|text| text.contains('@'))
+    }
+}
+
+/// Roles that grant economy permissions in a guild
+#[derive(Clone, Copy, Debug, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum EconomyRole {
+    /// Can give AndyCoins
+    #[name = "giver"]
+    Giver,
+    /// Can give AndyCoins and manage givers
+    #[name = "economy admin"]
+    Admin,
+}
+
+impl EconomyRole {
+    /// Name used in responses
+    pub fn label(self, locale: &str) -> String {
+        match self {
+            EconomyRole::Giver => tr!(locale, "economy-role-giver"),
+            EconomyRole::Admin => tr!(locale, "economy-role-admin"),
+        }
+    }
+}
+
+/// Per-user override of the role-based giver check
+#[derive(Clone, Copy, Debug, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum UserAccess {
+    /// Can give regardless of roles
+    #[name = "allow"]
+    Allow,
+    /// Can never give, regardless of roles
+    #[name = "deny"]
+    Deny,
 }
 
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct GuildConfig {
     pub guild_id: u64,
+    /// Legacy single giver role, migrated into `giver_role_ids` on load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub giver_role_id: Option<u64>,
     #[serde(default)]
-    pub vote_config: VoteConfig,
+    pub giver_role_ids: Vec<u64>,
     #[serde(default)]
+    pub admin_role_ids: Vec<u64>,
+    #[serde(default)]
+    pub user_allowlist: Vec<u64>,
+    #[serde(default)]
+    pub user_denylist: Vec<u64>,
+    /// Thresholds for reset proposals
+    #[serde(default)]
+    pub vote_config: VoteConfig,
+    /// Legacy single reset vote, migrated into `active_proposals` on load
+    #[serde(default, skip_serializing_if = "VoteStatus::is_unused")]
     pub vote_status: VoteStatus,
+    /// Thresholds for the other proposal types, where changed from their defaults
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub proposal_configs: BTreeMap<ProposalKind, VoteConfig>,
+    /// Open proposals by number, until they're finished or cancelled
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub active_proposals: BTreeMap<u32, VoteStatus>,
+    /// When the last vote of each type closed, for its cooldown
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub last_votes: BTreeMap<ProposalKind, chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub next_proposal_id: u32,
+    /// Hours after a reset during which admins can restore it, if not the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_grace_hours: Option<u32>,
+    /// Balances before recent resets, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reset_snapshots: Vec<ResetSnapshot>,
+    /// How reset votes opened without a mode change balances
+    #[serde(default)]
+    pub reset_mode: ResetMode,
+    /// Coins collected by taxing and redistributing resets
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub treasury: u32,
+    /// Finished and cancelled votes, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vote_history: Vec<VoteRecord>,
+    // Map of user_id -> when the member was last seen active, for quorums
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub activity: HashMap<u64, chrono::DateTime<chrono::Utc>>,
+    /// Members who asked to be reminded of votes by DM
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reminder_subscribers: Vec<u64>,
+    // Map of day -> user_id -> balance changes that day, for the last MAX_CHANGE_DAYS
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub daily_changes: BTreeMap<chrono::NaiveDate, HashMap<u64, BalanceChanges>>,
+    // Map of user_id -> balance changes since they were first tracked
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub lifetime_changes: HashMap<u64, BalanceChanges>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaderboard_schedule: Option<LeaderboardSchedule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<SeasonConfig>,
+    /// Finished seasons, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub season_history: Vec<SeasonRecord>,
+    /// Roles granted at balance thresholds, lowest threshold first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub balance_rewards: Vec<BalanceReward>,
+    #[serde(default)]
+    pub giver_policy: GiverPolicy,
+    // Map of giver user_id -> minting usage in the current window
+    #[serde(default)]
+    pub giver_usage: HashMap<u64, GiverUsage>,
+    #[serde(default)]
+    pub currency: CurrencyConfig,
+    /// Locale for responses when the user's own locale isn't supported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+impl GuildConfig {
+    /// Move the legacy single giver role into the giver role list, and the legacy
+    /// reset vote into the open proposals and cooldowns
+    pub fn migrate_legacy(&mut self) {
+        if let Some(role_id) = self.giver_role_id.take() {
+            if !self.giver_role_ids.contains(&role_id) {
+                self.giver_role_ids.push(role_id);
+            }
+        }
+
+        if !self.vote_status.is_unused() {
+            let mut status = std::mem::take(&mut self.vote_status);
+            if let Some(last) = status.last_vote_time {
+                self.last_votes.insert(ProposalKind::Reset, last);
+            }
+            if status.active {
+                self.next_proposal_id += 1;
+                status.proposal_id = self.next_proposal_id;
+                self.active_proposals.insert(status.proposal_id, status);
+            }
+        }
+    }
+
+    /// Hours after a reset during which admins can restore it
+    pub fn reset_grace_hours(&self) -> u32 {
+        self.reset_grace_hours.unwrap_or(DEFAULT_RESET_GRACE_HOURS)
+    }
+
+    /// Thresholds for a proposal type
+    pub fn vote_config(&self, kind: ProposalKind) -> VoteConfig {
+        match kind {
+            ProposalKind::Reset => self.vote_config.clone(),
+            _ => self
+                .proposal_configs
+                .get(&kind)
+                .cloned()
+                .unwrap_or_else(|| kind.default_config()),
+        }
+    }
+
+    /// Thresholds for a proposal type, with the quorum resolved against the
+    /// members active at `now`
+    pub fn effective_vote_config(
+        &self,
+        kind: ProposalKind,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> VoteConfig {
+        let vote_config = self.vote_config(kind);
+        match vote_config.quorum {
+            Quorum::Votes => vote_config,
+            Quorum::ActiveMembers { days, .. } => {
+                vote_config.with_quorum(self.active_members(days, now))
+            }
+        }
+    }
+
+    /// Number of members active in the last `days` days
+    pub fn active_members(&self, days: u32, now: chrono::DateTime<chrono::Utc>) -> usize {
+        let since = now - chrono::Duration::days(i64::from(days));
+        self.activity
+            .values()
+            .filter(|seen| **seen >= since)
+            .count()
+    }
+
+    fn vote_config_mut(&mut self, kind: ProposalKind) -> &mut VoteConfig {
+        match kind {
+            ProposalKind::Reset => &mut self.vote_config,
+            _ => self
+                .proposal_configs
+                .entry(kind)
+                .or_insert_with(|| kind.default_config()),
+        }
+    }
+
+    /// Count a balance change towards the windowed leaderboards. Changes from a
+    /// `giver` count as received by the user and given by the giver.
+    fn record_change(
+        &mut self,
+        user_id: u64,
+        change: i64,
+        giver: Option<u64>,
+        today: chrono::NaiveDate,
+    ) {
+        let received = match giver {
+            Some(_) => u64::try_from(change).ok(),
+            None => None,
+        };
+        let recipient = BalanceChanges {
+            earned: if received.is_some() { 0 } else { change },
+            received: received.unwrap_or(0),
+            given: 0,
+        };
+        let day = self.daily_changes.entry(today).or_default();
+        day.entry(user_id).or_default().add(&recipient);
+        self.lifetime_changes
+            .entry(user_id)
+            .or_default()
+            .add(&recipient);
+        if let (Some(giver), Some(given)) = (giver, received) {
+            let giver_changes = BalanceChanges {
+                given,
+                ..Default::default()
+            };
+            day.entry(giver).or_default().add(&giver_changes);
+            self.lifetime_changes
+                .entry(giver)
+                .or_default()
+                .add(&giver_changes);
+        }
+
+        let first_day = today - chrono::Duration::days(i64::from(MAX_CHANGE_DAYS) - 1);
+        self.daily_changes.retain(|day, _| *day >= first_day);
+    }
+
+    /// Balance changes per user since `since`, or since tracking began for `None`
+    pub fn changes_since(&self, since: Option<chrono::NaiveDate>) -> HashMap<u64, BalanceChanges> {
+        let Some(since) = since else {
+            return self.lifetime_changes.clone();
+        };
+        let mut totals: HashMap<u64, BalanceChanges> = HashMap::new();
+        for day in self.daily_changes.range(since..).map(|(_, day)| day) {
+            for (user_id, changes) in day {
+                totals.entry(*user_id).or_default().add(changes);
+            }
+        }
+        totals
+    }
+
+    /// Archive a vote that just closed, dropping the oldest records past the limit
+    fn archive_vote(&mut self, record: VoteRecord) {
+        self.vote_history.push(record);
+        let excess = self.vote_history.len().saturating_sub(MAX_VOTE_HISTORY);
+        self.vote_history.drain(..excess);
+    }
+
+    /// Open proposals, oldest first
+    pub fn open_proposals(&self) -> Vec<&VoteStatus> {
+        self.active_proposals.values().collect()
+    }
+
+    /// Find an open proposal by number, or the only open one when none is given.
+    /// Returns its number and type.
+    pub fn find_proposal(
+        &self,
+        proposal_id: Option<u32>,
+    ) -> Result<(u32, ProposalKind), VoteError> {
+        let status = match proposal_id {
+            Some(id) => self
+                .active_proposals
+                .get(&id)
+                .ok_or(VoteError::UnknownProposal)?,
+            None => match self.open_proposals().as_slice() {
+                [] => return Err(VoteError::NotActive),
+                [only] => *only,
+                _ => return Err(VoteError::ChooseProposal),
+            },
+        };
+        Ok((status.proposal_id, status.action.kind()))
+    }
+
+    /// A closed vote of a type, carrying the cooldown new proposals of the type
+    /// have to wait out
+    fn closed_vote(&self, kind: ProposalKind) -> VoteStatus {
+        VoteStatus {
+            last_vote_time: self.last_votes.get(&kind).copied(),
+            ..Default::default()
+        }
+    }
+
+    /// When the cooldown after the last vote of a type ends, if it's still running
+    pub fn cooldown_end(
+        &self,
+        kind: ProposalKind,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        match self.closed_vote(kind).phase(&self.vote_config(kind), now) {
+            VotePhase::Cooldown { until } => Some(until),
+            _ => None,
+        }
+    }
+
+    /// Take a finished or cancelled proposal out of the open ones, starting its
+    /// type's cooldown if it has one
+    fn close_proposal(&mut self, proposal_id: u32) -> Option<VoteStatus> {
+        let status = self.active_proposals.remove(&proposal_id)?;
+        if let Some(last) = status.last_vote_time {
+            self.last_votes.insert(status.action.kind(), last);
+        }
+        Some(status)
+    }
+
+    fn role_list(&self, kind: EconomyRole) -> &Vec<u64> {
+        match kind {
+            EconomyRole::Giver => &self.giver_role_ids,
+            EconomyRole::Admin => &self.admin_role_ids,
+        }
+    }
+
+    fn role_list_mut(&mut self, kind: EconomyRole) -> &mut Vec<u64> {
+        match kind {
+            EconomyRole::Giver => &mut self.giver_role_ids,
+            EconomyRole::Admin => &mut self.admin_role_ids,
+        }
+    }
+
+    /// Get a user's allowlist or denylist entry
+    pub fn user_access(&self, user_id: serenity::UserId) -> Option<UserAccess> {
+        if self.user_denylist.contains(&user_id.get()) {
+            Some(UserAccess::Deny)
+        } else if self.user_allowlist.contains(&user_id.get()) {
+            Some(UserAccess::Allow)
+        } else {
+            None
+        }
+    }
+
+    /// Check if any of `roles` is an economy admin role
+    pub fn is_economy_admin(&self, roles: &[serenity::RoleId]) -> bool {
+        roles.iter().any(|r| self.admin_role_ids.contains(&r.get()))
+    }
+
+    /// Check if a (non-owner) user may give coins.
+    ///
+    /// The denylist wins over everything, then the allowlist, then economy admin
+    /// and giver roles.
+    pub fn can_give(&self, user_id: serenity::UserId, roles: &[serenity::RoleId]) -> bool {
+        match self.user_access(user_id) {
+            Some(UserAccess::Deny) => false,
+            Some(UserAccess::Allow) => true,
+            None => {
+                self.is_economy_admin(roles)
+                    || roles.iter().any(|r| self.giver_role_ids.contains(&r.get()))
+            }
+        }
+    }
 }
 
-#[derive(Default)]
-pub struct Data(pub DataInner);
+/// Shared handle to the bot's data, cheap to clone into background tasks
+#[derive(Clone, Default)]
+pub struct Data(pub Arc<DataInner>);
 
 impl Deref for Data {
     type Target = DataInner;
@@ -66,16 +911,10 @@ impl Deref for Data {
     }
 }
 
-impl DerefMut for Data {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 impl Data {
     #[must_use]
     pub fn new() -> Self {
-        Self(DataInner::new())
+        Self(Arc::new(DataInner::new()))
     }
 
     /// Parse YAML string into user balances and guild configs
@@ -92,7 +931,7 @@ impl Data {
     }
 
     pub async fn load() -> Self {
-        Data(DataInner::load().await)
+        Data(Arc::new(DataInner::load().await))
     }
 
     pub fn export_data(&self) -> (Vec<UserBalance>, Vec<GuildConfig>) {
@@ -117,8 +956,17 @@ pub struct DataInner {
         dashmap::DashMap<serenity::GuildId, dashmap::DashMap<serenity::UserId, u32>>,
     // Map of guild_id -> guild configuration
     pub guild_configs: dashmap::DashMap<serenity::GuildId, GuildConfig>,
-    // Cache from the bot's context
-    pub cache: serenity::Cache,
+    // Wakes the scheduler when a vote deadline may have moved earlier
+    pub scheduler_wakeup: tokio::sync::Notify,
+    // Names for rendering users, not saved
+    pub names: NameCache,
+    // Balances ordered for leaderboards, kept in step with guild_balances
+    rankings: RwLock<RankingIndex>,
+    // Reward roles waiting to be changed in Discord, by member and role; a later
+    // change to the same role replaces an earlier one. Not saved.
+    reward_queue: Mutex<BTreeMap<(serenity::GuildId, serenity::UserId, RoleId), bool>>,
+    // Wakes the reward worker when role changes are queued
+    pub reward_wakeup: tokio::sync::Notify,
 }
 
 impl Default for DataInner {
@@ -133,7 +981,11 @@ impl DataInner {
         Self {
             guild_balances: dashmap::DashMap::new(),
             guild_configs: dashmap::DashMap::new(),
-            cache: serenity::Cache::default(),
+            scheduler_wakeup: tokio::sync::Notify::new(),
+            names: NameCache::new(),
+            rankings: RwLock::new(RankingIndex::new()),
+            reward_queue: Mutex::new(BTreeMap::new()),
+            reward_wakeup: tokio::sync::Notify::new(),
         }
     }
 
@@ -173,12 +1025,14 @@ impl DataInner {
                 .or_insert_with(dashmap::DashMap::new);
 
             // Insert the user's balance
-            guild_map.insert(user_id, user_balance.balance);
+            let previous = guild_map.insert(user_id, user_balance.balance);
+            self.update_ranking(guild_id, user_id, previous, Some(user_balance.balance));
         }
 
         // Import guild configs
-        for guild_config in configs {
+        for mut guild_config in configs {
             let guild_id = serenity::GuildId::new(guild_config.guild_id);
+            guild_config.migrate_legacy();
             self.guild_configs.insert(guild_id, guild_config);
         }
 
@@ -250,9 +1104,7 @@ impl DataInner {
 
             configs.push(GuildConfig {
                 guild_id,
-                giver_role_id: config.giver_role_id,
-                vote_config: config.vote_config.clone(),
-                vote_status: config.vote_status.clone(),
+                ..config.clone()
             });
         }
 
@@ -295,79 +1147,462 @@ impl DataInner {
         Ok(())
     }
 
-    /// Expire votes that have ended
-    pub fn expire_votes(&self) -> Vec<serenity::GuildId> {
-        let mut expired_votes = Vec::new();
-        for guild_entry in &self.guild_configs {
-            let guild_id = *guild_entry.key();
-            let config = guild_entry.value();
-
-            if config.vote_status.active {
-                // Check if the vote has expired
-                let now = chrono::Utc::now();
-                if let Some(end_time) = config.vote_status.end_time {
-                    if now > end_time {
-                        // Auto-end the vote
-                        self.end_vote(guild_id).unwrap_or_else(|_| {
-                            tracing::error!("Failed to end vote for guild {}", guild_id);
-                            false
-                        });
-                        expired_votes.push(guild_id);
+    /// End every open proposal whose time ran out by `now`
+    pub fn expire_votes(&self, now: chrono::DateTime<chrono::Utc>) -> Vec<ExpiredVote> {
+        // Collect first so no map guard is held while ending the votes
+        let due: Vec<_> = self
+            .guild_configs
+            .iter()
+            .flat_map(|entry| {
+                let guild_id = *entry.key();
+                entry
+                    .active_proposals
+                    .iter()
+                    .filter(|(_, status)| {
+                        status.phase(&entry.vote_config(status.action.kind()), now)
+                            == VotePhase::Ended
+                    })
+                    .map(|(proposal_id, _)| (guild_id, *proposal_id))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Another caller may have finished a vote in the meantime, which is skipped
+        due.into_iter()
+            .filter_map(|(guild_id, proposal_id)| {
+                self.finish_vote(guild_id, now, |config| {
+                    let status = config
+                        .active_proposals
+                        .get(&proposal_id)
+                        .ok_or(VoteError::NotActive)?;
+                    match status.phase(&config.vote_config(status.action.kind()), now) {
+                        VotePhase::Ended => Ok((proposal_id, status.action.kind())),
+                        _ => Err(VoteError::NotActive),
                     }
-                }
-            }
-        }
-        expired_votes
+                })
+                .ok()
+            })
+            .collect()
     }
 
-    /// Get all guild IDs
-    pub fn get_guild_ids(&self) -> Vec<serenity::GuildId> {
+    /// Earliest end time or pending reminder of any open proposal
+    pub fn next_vote_deadline(&self) -> Option<chrono::DateTime<chrono::Utc>> {
         self.guild_configs
             .iter()
-            .map(|entry| {
-                let guild_id = *entry.key();
-                guild_id
+            .flat_map(|entry| {
+                entry
+                    .active_proposals
+                    .values()
+                    .flat_map(|status| {
+                        let reminder =
+                            status.reminder_time(&entry.vote_config(status.action.kind()));
+                        status.end_time.into_iter().chain(reminder)
+                    })
+                    .min()
             })
-            .collect::<Vec<_>>()
-    }
-
-    /// Get a user's balance in a specific guild
-    pub fn get_guild_balance(&self, guild_id: serenity::GuildId, user_id: serenity::UserId) -> u32 {
-        self.guild_balances
-            .get(&guild_id)
-            .and_then(|guild_map| guild_map.get(&user_id).map(|bal| *bal))
-            .unwrap_or(0)
+            .min()
     }
 
-    /// Get a user's total balance across all guilds
-    pub fn get_total_balance(&self, user_id: serenity::UserId) -> u32 {
-        self.guild_balances
+    /// Earliest time a scheduled leaderboard post is due
+    pub fn next_leaderboard_post(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.guild_configs
             .iter()
-            .filter_map(|guild_entry| guild_entry.value().get(&user_id).map(|bal| *bal))
-            .sum()
+            .filter_map(|entry| entry.leaderboard_schedule.as_ref()?.next_run())
+            .min()
     }
 
-    /// Add coins to a user's balance in a specific guild
-    pub fn add_coins(
+    /// Mark the leaderboard posts due at `now` as run and return them. A schedule
+    /// that missed several runs while the bot was offline posts once.
+    pub fn due_leaderboard_posts(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<LeaderboardPost> {
+        // Find the due guilds under shared guards, then claim each post on its own
+        let due: Vec<_> = self
+            .guild_configs
+            .iter()
+            .filter(|entry| {
+                entry
+                    .leaderboard_schedule
+                    .as_ref()
+                    .and_then(LeaderboardSchedule::next_run)
+                    .is_some_and(|next| next <= now)
+            })
+            .map(|entry| *entry.key())
+            .collect();
+
+        // A post claimed or a schedule changed in the meantime is skipped
+        due.into_iter()
+            .filter_map(|guild_id| {
+                let mut config = self.guild_configs.get_mut(&guild_id)?;
+                let schedule = config.leaderboard_schedule.as_mut()?;
+                if schedule.next_run().is_none_or(|next| next > now) {
+                    return None;
+                }
+                let post = LeaderboardPost {
+                    guild_id,
+                    channel_id: serenity::ChannelId::new(schedule.channel_id),
+                    previous: schedule.standings.clone(),
+                    previous_run: schedule.last_run,
+                };
+                schedule.last_run = now;
+                Some(post)
+            })
+            .collect()
+    }
+
+    /// Remember the standings shown in a guild's latest scheduled post
+    pub fn set_leaderboard_standings(
+        &self,
+        guild_id: serenity::GuildId,
+        standings: BTreeMap<u64, Standing>,
+    ) {
+        if let Some(mut config) = self.guild_configs.get_mut(&guild_id) {
+            if let Some(schedule) = config.leaderboard_schedule.as_mut() {
+                schedule.standings = standings;
+            }
+        }
+    }
+
+    /// Post the leaderboard in `channel_id` on a cron-like schedule, or stop with
+    /// `None`. Returns `false` if the spec isn't valid.
+    pub fn set_leaderboard_schedule(
+        &self,
+        guild_id: serenity::GuildId,
+        schedule: Option<(serenity::ChannelId, String)>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        if let Some((_, spec)) = &schedule {
+            if CronSpec::parse(spec).is_none() {
+                return false;
+            }
+        }
+        {
+            let mut config = self.config_mut(guild_id);
+            config.leaderboard_schedule = schedule.map(|(channel_id, spec)| LeaderboardSchedule {
+                channel_id: channel_id.get(),
+                spec,
+                last_run: now,
+                standings: BTreeMap::new(),
+            });
+        }
+        self.scheduler_wakeup.notify_one();
+        true
+    }
+
+    /// When the earliest current season ends
+    pub fn next_season_end(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.guild_configs
+            .iter()
+            .filter_map(|entry| entry.season.as_ref()?.ends_at)
+            .min()
+    }
+
+    /// End the seasons due at `now`: archive their final standings, reset the
+    /// balances and start the next season
+    pub fn end_due_seasons(&self, now: chrono::DateTime<chrono::Utc>) -> Vec<SeasonEnd> {
+        let due: Vec<_> = self
+            .guild_configs
+            .iter()
+            .filter(|entry| {
+                entry
+                    .season
+                    .as_ref()
+                    .and_then(|season| season.ends_at)
+                    .is_some_and(|end| end <= now)
+            })
+            .map(|entry| *entry.key())
+            .collect();
+        due.into_iter()
+            .filter_map(|guild_id| self.end_season(guild_id, now))
+            .collect()
+    }
+
+    /// End a guild's season if it's still due. The season is claimed under one
+    /// config guard, so only one caller ends it, and its standings are taken from the
+    /// balances the reset replaced, so no give slips in between.
+    fn end_season(
+        &self,
+        guild_id: serenity::GuildId,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<SeasonEnd> {
+        let (ended, previous_award) = {
+            let mut config = self.guild_configs.get_mut(&guild_id)?;
+            let GuildConfig {
+                season,
+                season_history,
+                ..
+            } = &mut *config;
+            let season = season.as_mut()?;
+            if season.ends_at.is_none_or(|end| end > now) {
+                return None;
+            }
+
+            let ended = season.clone();
+            let previous_award = season_history.last().and_then(|previous| {
+                let role_id = serenity::RoleId::new(previous.award_role?);
+                let users = previous.awarded.iter().map(|&id| serenity::UserId::new(id));
+                Some((role_id, users.collect()))
+            });
+            season.number += 1;
+            season.started = now;
+            season.ends_at = CronSpec::parse(&season.spec).and_then(|spec| spec.next_after(now));
+            (ended, previous_award)
+        };
+
+        let (balances, _, _) =
+            self.replace_balances(guild_id, ResetMode::Zero, "season_reset", None);
+        tracing::info!(
+            "Reset balances in guild {} at the end of a season",
+            guild_id
+        );
+
+        let standings = top_standings(&balances, SEASON_STANDINGS);
+        let awarded = match ended.award_role {
+            Some(_) => standings
+                .iter()
+                .filter(|(_, standing)| standing.rank <= ended.winners as usize)
+                .map(|(&user_id, _)| user_id)
+                .collect(),
+            None => Vec::new(),
+        };
+        let record = SeasonRecord {
+            number: ended.number,
+            started: ended.started,
+            ended: now,
+            standings,
+            award_role: ended.award_role,
+            awarded,
+        };
+        if let Some(mut config) = self.guild_configs.get_mut(&guild_id) {
+            config.season_history.push(record.clone());
+            let excess = config
+                .season_history
+                .len()
+                .saturating_sub(MAX_SEASON_HISTORY);
+            config.season_history.drain(..excess);
+        }
+
+        Some(SeasonEnd {
+            guild_id,
+            channel_id: ended.channel_id.map(serenity::ChannelId::new),
+            record,
+            previous_award,
+        })
+    }
+
+    /// Run seasons on `season`'s schedule, or stop with `None`. A new spec ends the
+    /// current season at its next run; otherwise the end stays. Returns `false` if
+    /// the spec isn't valid.
+    pub fn set_season(
+        &self,
+        guild_id: serenity::GuildId,
+        season: Option<SeasonConfig>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        let spec = match &season {
+            Some(season) => match CronSpec::parse(&season.spec) {
+                Some(spec) => Some(spec),
+                None => return false,
+            },
+            None => None,
+        };
+        {
+            let mut config = self.config_mut(guild_id);
+            let next_number = config.season_history.last().map_or(1, |s| s.number + 1);
+            config.season = season.zip(spec).map(|(mut season, spec)| {
+                match &config.season {
+                    Some(current) => {
+                        season.number = current.number;
+                        season.started = current.started;
+                        season.ends_at = if current.spec == season.spec {
+                            current.ends_at
+                        } else {
+                            spec.next_after(now)
+                        };
+                    }
+                    None => {
+                        season.number = next_number;
+                        season.started = now;
+                        season.ends_at = spec.next_after(now);
+                    }
+                }
+                season
+            });
+        }
+        self.scheduler_wakeup.notify_one();
+        true
+    }
+
+    /// Get a guild's season settings, if seasons are on
+    pub fn get_season(&self, guild_id: serenity::GuildId) -> Option<SeasonConfig> {
+        self.guild_configs
+            .get(&guild_id)
+            .and_then(|config| config.season.clone())
+    }
+
+    /// Get a guild's finished seasons, oldest first
+    pub fn get_season_history(&self, guild_id: serenity::GuildId) -> Vec<SeasonRecord> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.season_history.clone())
+            .unwrap_or_default()
+    }
+
+    /// Mark the reminders due at `now` as sent and return them. Votes that ended
+    /// or were cancelled first have no reminder left to send.
+    pub fn due_reminders(&self, now: chrono::DateTime<chrono::Utc>) -> Vec<VoteReminder> {
+        // Find the guilds with a reminder due under shared guards first
+        let guilds: Vec<_> = self
+            .guild_configs
+            .iter()
+            .filter(|entry| {
+                entry.active_proposals.values().any(|status| {
+                    status.reminder_due(&entry.vote_config(status.action.kind()), now)
+                })
+            })
+            .map(|entry| *entry.key())
+            .collect();
+
+        let mut due = Vec::new();
+        for guild_id in guilds {
+            // Reminders sent or votes ended in the meantime are no longer due
+            let Some(mut config) = self.guild_configs.get_mut(&guild_id) else {
+                continue;
+            };
+            let subscribers = config.reminder_subscribers.clone();
+            let vote_configs: BTreeMap<_, _> = config
+                .active_proposals
+                .values()
+                .map(|status| status.action.kind())
+                .map(|kind| (kind, config.vote_config(kind)))
+                .collect();
+            for status in config.active_proposals.values_mut() {
+                let vote_config = &vote_configs[&status.action.kind()];
+                if !status.reminder_due(vote_config, now) {
+                    continue;
+                }
+
+                status.reminded = true;
+                due.push(VoteReminder {
+                    guild_id,
+                    channel_id: status.channel_id.map(serenity::ChannelId::new),
+                    proposal_id: status.proposal_id,
+                    action: status.action.clone(),
+                    message_id: status.message_id.map(serenity::MessageId::new),
+                    end_time: status.end_time.unwrap_or(now),
+                    role_id: vote_config.reminder_role.map(RoleId::new),
+                    user_ids: subscribers
+                        .iter()
+                        .filter(|user_id| !status.has_voted(**user_id))
+                        .map(|user_id| serenity::UserId::new(*user_id))
+                        .collect(),
+                });
+            }
+        }
+        due
+    }
+
+    /// Turn vote reminders by DM on or off for a member. Returns whether the
+    /// setting changed.
+    pub fn set_reminder_subscription(
         &self,
         guild_id: serenity::GuildId,
         user_id: serenity::UserId,
-        amount: u32,
-    ) -> u32 {
+        enabled: bool,
+    ) -> bool {
+        let mut config = self.config_mut(guild_id);
+        let subscribed = config.reminder_subscribers.contains(&user_id.get());
+        match (subscribed, enabled) {
+            (false, true) => config.reminder_subscribers.push(user_id.get()),
+            (true, false) => config
+                .reminder_subscribers
+                .retain(|id| *id != user_id.get()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Get all guild IDs
+    pub fn get_guild_ids(&self) -> Vec<serenity::GuildId> {
+        self.guild_configs
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>()
+    }
+
+    /// Get a user's balance in a specific guild
+    pub fn get_guild_balance(&self, guild_id: serenity::GuildId, user_id: serenity::UserId) -> u32 {
+        self.guild_balances
+            .get(&guild_id)
+            .and_then(|guild_map| guild_map.get(&user_id).map(|bal| *bal))
+            .unwrap_or(0)
+    }
+
+    /// Get a user's total balance across all guilds
+    pub fn get_total_balance(&self, user_id: serenity::UserId) -> u32 {
+        self.read_rankings().total(user_id)
+    }
+
+    fn read_rankings(&self) -> std::sync::RwLockReadGuard<'_, RankingIndex> {
+        self.rankings.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Keep the rankings in step with a balance change. Callers hold the balance's
+    /// map guard, so changes to one user reach the rankings in order.
+    fn update_ranking(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        previous: Option<u32>,
+        balance: Option<u32>,
+    ) {
+        self.rankings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .update(guild_id, user_id, previous, balance);
+    }
+
+    /// Change a user's balance in a guild under one guard, creating it from 0.
+    /// Returns the previous and new balance.
+    pub fn modify_balance(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        change: impl FnOnce(u32) -> u32,
+    ) -> (u32, u32) {
         // Get or create the guild's balance map
         let guild_map = self
             .guild_balances
             .entry(guild_id)
             .or_insert_with(dashmap::DashMap::new);
 
-        // Get the previous balance
-        let previous_balance = guild_map.get(&user_id).map(|bal| *bal).unwrap_or(0);
+        match guild_map.entry(user_id) {
+            dashmap::Entry::Occupied(mut entry) => {
+                let previous = *entry.get();
+                let balance = change(previous);
+                *entry.get_mut() = balance;
+                self.update_ranking(guild_id, user_id, Some(previous), Some(balance));
+                (previous, balance)
+            }
+            dashmap::Entry::Vacant(entry) => {
+                let balance = change(0);
+                entry.insert(balance);
+                self.update_ranking(guild_id, user_id, None, Some(balance));
+                (0, balance)
+            }
+        }
+    }
 
-        // Update the user's balance
-        let new_balance = *guild_map
-            .entry(user_id)
-            .and_modify(|bal| *bal += amount)
-            .or_insert(amount);
+    /// Add coins to a user's balance in a specific guild
+    pub fn add_coins(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        amount: u32,
+    ) -> u32 {
+        let (previous_balance, new_balance) =
+            self.modify_balance(guild_id, user_id, |balance| balance.saturating_add(amount));
 
         // Log the balance change
         crate::logging::log_balance_change(
@@ -378,6 +1613,7 @@ impl DataInner {
             "add_coins",
             None,
         );
+        self.record_balance_change(guild_id, user_id, previous_balance, new_balance, None);
 
         new_balance
     }
@@ -389,359 +1625,1123 @@ impl DataInner {
         user_id: serenity::UserId,
         amount: u32,
     ) -> u32 {
-        // Get or create the guild's balance map
-        let guild_map = self
-            .guild_balances
-            .entry(guild_id)
-            .or_insert_with(dashmap::DashMap::new);
-
-        // Get the previous balance
-        let previous_balance = guild_map.get(&user_id).map(|bal| *bal).unwrap_or(0);
-
-        // Update the user's balance
-        let new_balance = guild_map
-            .entry(user_id)
-            .and_modify(|bal| {
-                if *bal >= amount {
-                    *bal -= amount;
-                } else {
-                    *bal = 0;
-                }
-            })
-            .or_insert(0);
-
-        let new_balance_value = *new_balance;
+        let (previous_balance, new_balance) =
+            self.modify_balance(guild_id, user_id, |balance| balance.saturating_sub(amount));
 
         // Log the balance change
         crate::logging::log_balance_change(
             guild_id.get(),
             user_id.get(),
             previous_balance,
-            new_balance_value,
+            new_balance,
             "remove_coins",
             None,
         );
+        self.record_balance_change(guild_id, user_id, previous_balance, new_balance, None);
 
-        new_balance_value
+        new_balance
     }
 
-    /// Get top users by balance in a specific guild
-    pub fn get_guild_top_users(
+    /// Up to `count` users of a guild's ranking, or the global one for `None`,
+    /// starting at position `start`. Users are ordered richest first, and equal
+    /// balances by user ID so pages of the ranking stay stable.
+    pub fn get_ranking_page(
         &self,
-        guild_id: serenity::GuildId,
-        limit: usize,
-    ) -> Vec<(serenity::UserId, u32)> {
-        if let Some(guild_map) = self.guild_balances.get(&guild_id) {
-            let mut users: Vec<(serenity::UserId, u32)> = guild_map
-                .iter()
-                .map(|entry| (*entry.key(), *entry.value()))
-                .collect();
+        guild_id: Option<serenity::GuildId>,
+        start: usize,
+        count: usize,
+    ) -> Vec<RankedUser> {
+        self.read_rankings()
+            .board(guild_id)
+            .map(|board| board.page(start, count))
+            .unwrap_or_default()
+    }
+
+    /// Number of users in a guild's ranking, or the global one for `None`
+    pub fn get_ranked_users(&self, guild_id: Option<serenity::GuildId>) -> usize {
+        self.read_rankings()
+            .board(guild_id)
+            .map_or(0, |board| board.len())
+    }
 
-            users.sort_by(|a, b| b.1.cmp(&a.1));
-            users.truncate(limit);
+    /// Where a user stands in a guild's ranking, or the global one for `None`
+    pub fn get_rank_info(
+        &self,
+        guild_id: Option<serenity::GuildId>,
+        user_id: serenity::UserId,
+    ) -> Option<RankInfo> {
+        self.read_rankings()
+            .board(guild_id)
+            .and_then(|board| board.rank_info(user_id))
+    }
 
-            users
-        } else {
-            Vec::new()
+    /// Add an economy role to a guild. Returns `false` if it was already present.
+    pub fn add_economy_role(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: EconomyRole,
+        role_id: serenity::RoleId,
+    ) -> bool {
+        let mut config = self.config_mut(guild_id);
+        let roles = config.role_list_mut(kind);
+        if roles.contains(&role_id.get()) {
+            return false;
         }
+        roles.push(role_id.get());
+        true
     }
 
-    /// Get top users by total balance across all guilds
-    pub fn get_global_top_users(&self, limit: usize) -> Vec<(serenity::UserId, u32)> {
-        // Collect all user balances across all guilds
-        let user_totals: dashmap::DashMap<serenity::UserId, u32> = dashmap::DashMap::new();
+    /// Remove an economy role from a guild. Returns `false` if it was not present.
+    pub fn remove_economy_role(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: EconomyRole,
+        role_id: serenity::RoleId,
+    ) -> bool {
+        let Some(mut config) = self.guild_configs.get_mut(&guild_id) else {
+            return false;
+        };
+        let roles = config.role_list_mut(kind);
+        let before = roles.len();
+        roles.retain(|r| *r != role_id.get());
+        roles.len() != before
+    }
 
-        for guild_entry in &self.guild_balances {
-            for user_entry in guild_entry.value() {
-                user_totals
-                    .entry(*user_entry.key())
-                    .and_modify(|bal| *bal += *user_entry.value())
-                    .or_insert(*user_entry.value());
-            }
-        }
+    /// Get the economy roles of a kind for a guild
+    pub fn get_economy_roles(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: EconomyRole,
+    ) -> Vec<serenity::RoleId> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| {
+                config
+                    .role_list(kind)
+                    .iter()
+                    .copied()
+                    .map(RoleId::new)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        // Convert to vector and sort
-        let mut users: Vec<(serenity::UserId, u32)> = user_totals
-            .iter()
-            .map(|entry| (*entry.key(), *entry.value()))
-            .collect();
+    /// Put a user on the allowlist or denylist, or clear them from both with `None`
+    pub fn set_user_access(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        access: Option<UserAccess>,
+    ) {
+        let mut config = self.config_mut(guild_id);
+        let user_id_u64 = user_id.get();
 
-        users.sort_by(|a, b| b.1.cmp(&a.1));
-        users.truncate(limit);
+        config.user_allowlist.retain(|id| *id != user_id_u64);
+        config.user_denylist.retain(|id| *id != user_id_u64);
 
-        users
+        match access {
+            Some(UserAccess::Allow) => config.user_allowlist.push(user_id_u64),
+            Some(UserAccess::Deny) => config.user_denylist.push(user_id_u64),
+            None => {}
+        }
     }
 
-    /// Set the giver role for a guild
-    pub fn set_giver_role(&self, guild_id: serenity::GuildId, role_id: Option<serenity::RoleId>) {
-        let role_id_u64 = role_id.map(RoleId::get);
+    /// Get a user's allowlist or denylist entry
+    pub fn get_user_access(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+    ) -> Option<UserAccess> {
+        self.guild_configs
+            .get(&guild_id)
+            .and_then(|config| config.user_access(user_id))
+    }
 
+    /// A guild's configuration for changing, created with the defaults if it has none
+    fn config_mut(
+        &self,
+        guild_id: serenity::GuildId,
+    ) -> dashmap::mapref::one::RefMut<'_, serenity::GuildId, GuildConfig> {
         self.guild_configs
             .entry(guild_id)
-            .and_modify(|config| config.giver_role_id = role_id_u64)
             .or_insert_with(|| GuildConfig {
                 guild_id: guild_id.get(),
-                giver_role_id: role_id_u64,
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
-            });
+                ..Default::default()
+            })
     }
 
-    /// Get the giver role for a guild
-    pub fn get_giver_role(&self, guild_id: serenity::GuildId) -> Option<serenity::RoleId> {
+    /// Get the full configuration for a guild, or the defaults if it has none
+    pub fn get_guild_config(&self, guild_id: serenity::GuildId) -> GuildConfig {
         self.guild_configs
             .get(&guild_id)
-            .and_then(|config| config.giver_role_id.map(serenity::RoleId::new))
-    }
-
-    /// Check if a user has the giver role
-    pub fn has_giver_role(&self, guild_id: serenity::GuildId, member: &serenity::Member) -> bool {
-        // Server owner always has permission
-        // Get guild owner ID.
-        if member.user.id
-            == member
-                .guild_id
-                .to_guild_cached(&self.cache)
-                .map(|g| g.owner_id)
-                .unwrap_or_default()
-        {
-            return true;
-        }
+            .map(|config| config.clone())
+            .unwrap_or_else(|| GuildConfig {
+                guild_id: guild_id.get(),
+                ..Default::default()
+            })
+    }
+
+    /// Export a guild's settings to YAML.
+    ///
+    /// Only settings are exported; runtime state such as the current vote and giver
+    /// usage stays with the guild, as do the leaderboard schedule and seasons, which
+    /// name the guild's channels and roles.
+    pub fn export_guild_settings(
+        &self,
+        guild_id: serenity::GuildId,
+    ) -> Result<String, serde_yaml::Error> {
+        let settings = GuildConfig {
+            vote_status: VoteStatus::default(),
+            active_proposals: BTreeMap::new(),
+            last_votes: BTreeMap::new(),
+            next_proposal_id: 0,
+            reset_snapshots: Vec::new(),
+            treasury: 0,
+            vote_history: Vec::new(),
+            activity: HashMap::new(),
+            reminder_subscribers: Vec::new(),
+            daily_changes: BTreeMap::new(),
+            lifetime_changes: HashMap::new(),
+            leaderboard_schedule: None,
+            season: None,
+            season_history: Vec::new(),
+            giver_usage: HashMap::new(),
+            ..self.get_guild_config(guild_id)
+        };
+        serde_yaml::to_string(&settings)
+    }
 
-        // Check if the user has the giver role
-        if let Some(giver_role_id) = self.get_giver_role(guild_id) {
-            return member.roles.contains(&giver_role_id);
+    /// Import settings exported with [`Self::export_guild_settings`] into a guild,
+    /// possibly from a different guild. The guild keeps its runtime state.
+    pub fn import_guild_settings(
+        &self,
+        guild_id: serenity::GuildId,
+        yaml_str: &str,
+    ) -> Result<GuildConfig, serde_yaml::Error> {
+        let mut settings: GuildConfig = serde_yaml::from_str(yaml_str)?;
+        settings.migrate_legacy();
+        if settings.currency.has_mentions() {
+            return Err(serde::de::Error::custom(
+                "currency texts can't contain mentions",
+            ));
         }
 
-        // If no giver role is set, only the server owner can give coins
-        false
+        let current = self.get_guild_config(guild_id);
+        let config = GuildConfig {
+            guild_id: guild_id.get(),
+            vote_status: VoteStatus::default(),
+            active_proposals: current.active_proposals,
+            last_votes: current.last_votes,
+            next_proposal_id: current.next_proposal_id,
+            reset_snapshots: current.reset_snapshots,
+            treasury: current.treasury,
+            vote_history: current.vote_history,
+            activity: current.activity,
+            reminder_subscribers: current.reminder_subscribers,
+            daily_changes: current.daily_changes,
+            lifetime_changes: current.lifetime_changes,
+            leaderboard_schedule: current.leaderboard_schedule,
+            season: current.season,
+            season_history: current.season_history,
+            giver_usage: current.giver_usage,
+            ..settings
+        };
+        self.guild_configs.insert(guild_id, config.clone());
+
+        Ok(config)
     }
 
-    /// Flip a coin and return the result
-    pub fn flip_coin() -> bool {
-        let mut rng = rand::rng();
-        rng.random_bool(0.5)
+    /// Get the currency for a guild, or the default currency for global views
+    pub fn get_currency(&self, guild_id: Option<serenity::GuildId>) -> CurrencyConfig {
+        guild_id
+            .and_then(|guild_id| self.guild_configs.get(&guild_id))
+            .map(|config| config.currency.clone())
+            .unwrap_or_default()
     }
 
-    /// Reset all data
-    pub fn reset(&self) {
-        self.guild_balances.clear();
-        self.guild_configs.clear();
+    /// Set the currency for a guild
+    pub fn set_currency(&self, guild_id: serenity::GuildId, currency: CurrencyConfig) {
+        self.config_mut(guild_id).currency = currency;
     }
 
-    /// Get vote config for a guild
-    pub fn get_vote_config(&self, guild_id: serenity::GuildId) -> VoteConfig {
+    /// Get the configured response locale for a guild
+    pub fn get_locale(&self, guild_id: serenity::GuildId) -> Option<String> {
         self.guild_configs
             .get(&guild_id)
-            .map(|config| config.vote_config.clone())
-            .unwrap_or_default()
+            .and_then(|config| config.locale.clone())
     }
 
-    /// Set vote config for a guild
-    pub fn set_vote_config(&self, guild_id: serenity::GuildId, vote_config: &VoteConfig) {
-        let my_vote_config = vote_config.clone();
-        self.guild_configs
-            .entry(guild_id)
-            .and_modify(|config| config.vote_config = my_vote_config.clone())
-            .or_insert_with(|| GuildConfig {
-                guild_id: guild_id.get(),
-                giver_role_id: None,
-                vote_config: my_vote_config,
-                vote_status: VoteStatus::default(),
-            });
+    /// Set or clear the response locale for a guild
+    pub fn set_locale(&self, guild_id: serenity::GuildId, locale: Option<String>) {
+        self.config_mut(guild_id).locale = locale;
     }
 
-    /// Get vote status for a guild
-    pub fn get_vote_status(&self, guild_id: serenity::GuildId) -> VoteStatus {
+    /// Get the giver policy for a guild
+    pub fn get_giver_policy(&self, guild_id: serenity::GuildId) -> GiverPolicy {
         self.guild_configs
             .get(&guild_id)
-            .map(|config| config.vote_status.clone())
+            .map(|config| config.giver_policy.clone())
             .unwrap_or_default()
     }
 
-    /// Start a vote in a guild
-    pub fn start_vote(
+    /// Set the giver policy for a guild
+    pub fn set_giver_policy(&self, guild_id: serenity::GuildId, policy: GiverPolicy) {
+        self.config_mut(guild_id).giver_policy = policy;
+    }
+
+    /// Check a give against the guild's giver policy and, if allowed, count it
+    /// against the giver's budget.
+    ///
+    /// Returns the giver's remaining budget for the current window, or `None` if
+    /// the giver has no budget limit.
+    pub fn reserve_mint(
         &self,
         guild_id: serenity::GuildId,
-        initiator_id: serenity::UserId,
-    ) -> Result<chrono::DateTime<chrono::Utc>, &'static str> {
-        let mut config_ref = if let Some(config) = self.guild_configs.get_mut(&guild_id) {
-            config
-        } else {
-            // Create a new config if it doesn't exist
-            let config = GuildConfig {
-                guild_id: guild_id.get(),
-                giver_role_id: None,
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
-            };
-            self.guild_configs.insert(guild_id, config);
-            self.guild_configs.get_mut(&guild_id).unwrap()
+        giver_id: serenity::UserId,
+        giver_roles: &[serenity::RoleId],
+        amount: u32,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<u32>, MintError> {
+        let Some(mut config) = self.guild_configs.get_mut(&guild_id) else {
+            // No config means no policy
+            return Ok(None);
         };
 
-        // Check if a vote is already active
-        if config_ref.vote_status.active {
-            return Err("A vote is already active in this server");
+        let limits = config.giver_policy.limits_for(giver_roles);
+        if let Some(max) = limits.max_per_give {
+            if amount > max {
+                return Err(MintError::ExceedsMaxPerGive { max });
+            }
         }
 
-        // Check if a vote was recently completed (cooldown period)
-        if let Some(last_vote_time) = config_ref.vote_status.last_vote_time {
-            let cooldown_duration =
-                chrono::Duration::hours(i64::from(config_ref.vote_config.cooldown_hours));
-            let now = chrono::Utc::now();
+        let Some(budget) = limits.budget else {
+            return Ok(None);
+        };
 
-            if now < last_vote_time + cooldown_duration {
-                return Err(
-                    "A vote was recently completed. Please wait for the cooldown period to end",
-                );
-            }
+        let period = config.giver_policy.budget_period;
+        let window_start = period.window_start(now);
+        let usage = config
+            .giver_usage
+            .entry(giver_id.get())
+            .or_insert(GiverUsage {
+                window_start,
+                minted: 0,
+            });
+
+        // A new window resets the giver's usage
+        if usage.window_start != window_start {
+            usage.window_start = window_start;
+            usage.minted = 0;
         }
 
-        // Start the vote
-        let now = chrono::Utc::now();
-        let duration =
-            chrono::Duration::minutes(i64::from(config_ref.vote_config.duration_minutes));
-        let end_time = now + duration;
-
-        config_ref.vote_status = VoteStatus {
-            active: true,
-            start_time: Some(now),
-            end_time: Some(end_time),
-            initiator_id: Some(initiator_id.get()),
-            yes_votes: vec![initiator_id.get()], // Initiator automatically votes yes
-            no_votes: vec![],
-            last_vote_time: None,
-        };
+        let remaining = budget.saturating_sub(usage.minted);
+        if amount > remaining {
+            return Err(MintError::BudgetExhausted { remaining, period });
+        }
 
-        Ok(end_time)
+        usage.minted += amount;
+        Ok(Some(remaining - amount))
     }
 
-    /// Cast a vote
-    pub fn cast_vote(
+    /// Get a giver's remaining budget for the current window, or `None` if unlimited
+    pub fn remaining_mint_budget(
         &self,
         guild_id: serenity::GuildId,
-        user_id: serenity::UserId,
-        vote_yes: bool,
-    ) -> Result<(), &'static str> {
-        let mut config_ref = match self.guild_configs.get_mut(&guild_id) {
-            Some(config) => config,
-            None => return Err("No vote is active in this server"),
-        };
-
-        // Check if a vote is active
-        if !config_ref.vote_status.active {
-            return Err("No vote is active in this server");
-        }
-
-        // Check if the vote has expired
-        let now = chrono::Utc::now();
-        if let Some(end_time) = config_ref.vote_status.end_time {
-            if now > end_time {
-                // Auto-end the vote
-                self.end_vote(guild_id)?;
-                return Err("The vote has ended");
-            }
-        }
+        giver_id: serenity::UserId,
+        giver_roles: &[serenity::RoleId],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<u32> {
+        let config = self.guild_configs.get(&guild_id)?;
+        let budget = config.giver_policy.limits_for(giver_roles).budget?;
+        let window_start = config.giver_policy.budget_period.window_start(now);
 
-        let user_id_u64 = user_id.get();
+        let minted = config
+            .giver_usage
+            .get(&giver_id.get())
+            .filter(|usage| usage.window_start == window_start)
+            .map_or(0, |usage| usage.minted);
 
-        // Remove user from both vote lists to avoid duplicate votes
-        config_ref
-            .vote_status
-            .yes_votes
-            .retain(|id| *id != user_id_u64);
-        config_ref
-            .vote_status
-            .no_votes
-            .retain(|id| *id != user_id_u64);
-
-        // Add user's vote
-        if vote_yes {
-            config_ref.vote_status.yes_votes.push(user_id_u64);
-        } else {
-            config_ref.vote_status.no_votes.push(user_id_u64);
-        }
+        Some(budget.saturating_sub(minted))
+    }
 
-        Ok(())
+    /// Flip a coin and return the result
+    pub fn flip_coin() -> bool {
+        let mut rng = rand::rng();
+        rng.random_bool(0.5)
     }
 
-    /// End a vote and process the results
-    pub fn end_vote(&self, guild_id: serenity::GuildId) -> Result<bool, &'static str> {
-        let mut config_ref = match self.guild_configs.get_mut(&guild_id) {
-            Some(config) => config,
-            None => return Err("No vote is active in this server"),
-        };
+    /// Reset all data
+    pub fn reset(&self) {
+        self.guild_balances.clear();
+        self.guild_configs.clear();
+        self.rankings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Get the thresholds for a proposal type in a guild
+    pub fn get_vote_config(&self, guild_id: serenity::GuildId, kind: ProposalKind) -> VoteConfig {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.vote_config(kind))
+            .unwrap_or_else(|| GuildConfig::default().vote_config(kind))
+    }
+
+    /// Get the thresholds for a proposal type in a guild, with the quorum resolved
+    /// against the members active now
+    pub fn get_effective_vote_config(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: ProposalKind,
+    ) -> VoteConfig {
+        let now = chrono::Utc::now();
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.effective_vote_config(kind, now))
+            .unwrap_or_else(|| GuildConfig::default().effective_vote_config(kind, now))
+    }
+
+    /// Get how many members of a guild were active in the last `days` days
+    pub fn get_active_members(&self, guild_id: serenity::GuildId, days: u32) -> usize {
+        self.guild_configs
+            .get(&guild_id)
+            .map_or(0, |config| config.active_members(days, chrono::Utc::now()))
+    }
 
-        // Check if a vote is active
-        if !config_ref.vote_status.active {
-            return Err("No vote is active in this server");
+    /// Note that a member was active in a guild. Updates are coarse, and members
+    /// inactive for longer than any quorum counts are forgotten.
+    pub fn record_activity(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        now: chrono::DateTime<chrono::Utc>,
+    ) {
+        let resolution = chrono::Duration::minutes(ACTIVITY_RESOLUTION_MINUTES);
+        let fresh = |config: &GuildConfig| {
+            config
+                .activity
+                .get(&user_id.get())
+                .is_some_and(|seen| now - *seen < resolution)
+        };
+        // Most events come from members seen recently, which only need a read
+        if self.guild_configs.get(&guild_id).is_some_and(|c| fresh(&c)) {
+            return;
         }
 
-        let yes_votes = config_ref.vote_status.yes_votes.len();
-        let no_votes = config_ref.vote_status.no_votes.len();
-        let total_votes = yes_votes + no_votes;
+        let mut config = self.config_mut(guild_id);
+        config.activity.insert(user_id.get(), now);
+        let forget_before = now - chrono::Duration::days(i64::from(MAX_ACTIVITY_DAYS));
+        config.activity.retain(|_, seen| *seen >= forget_before);
+    }
 
-        // Record the vote end time
-        let now = chrono::Utc::now();
-        config_ref.vote_status.last_vote_time = Some(now);
-        config_ref.vote_status.active = false;
+    /// Follow up a balance change once its guard is released: count it towards the
+    /// windowed leaderboards and queue the reward roles whose thresholds it crossed.
+    /// Pass the `giver` for coins one member gave another.
+    pub fn record_balance_change(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        previous_balance: u32,
+        new_balance: u32,
+        giver: Option<serenity::UserId>,
+    ) {
+        let change = i64::from(new_balance) - i64::from(previous_balance);
+        if change == 0 {
+            return;
+        }
+        let today = chrono::Utc::now().date_naive();
+        let rewards: Vec<_> = {
+            let mut config = self.config_mut(guild_id);
+            config.record_change(
+                user_id.get(),
+                change,
+                giver.map(serenity::UserId::get),
+                today,
+            );
+            config
+                .balance_rewards
+                .iter()
+                .filter_map(|reward| {
+                    Some(RoleChange {
+                        guild_id,
+                        user_id,
+                        role_id: RoleId::new(reward.role_id),
+                        add: reward.crossed(previous_balance, new_balance)?,
+                    })
+                })
+                .collect()
+        };
+        self.queue_role_changes(rewards);
+    }
 
-        // Check if there are enough votes
-        if total_votes < config_ref.vote_config.min_votes as usize {
-            return Ok(false); // Not enough votes, vote fails
+    /// Queue reward role changes for the reward worker
+    pub fn queue_role_changes(&self, changes: impl IntoIterator<Item = RoleChange>) {
+        let mut queue = self
+            .reward_queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut queued = false;
+        for change in changes {
+            queue.insert(
+                (change.guild_id, change.user_id, change.role_id),
+                change.add,
+            );
+            queued = true;
+        }
+        drop(queue);
+        if queued {
+            self.reward_wakeup.notify_one();
         }
+    }
 
-        // Calculate the percentage of yes votes
-        let yes_percentage = (yes_votes as f64 / total_votes as f64) * 100.0;
+    /// Take up to `count` queued reward role changes
+    pub fn take_role_changes(&self, count: usize) -> Vec<RoleChange> {
+        let mut queue = self
+            .reward_queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        std::iter::from_fn(|| queue.pop_first())
+            .take(count)
+            .map(|((guild_id, user_id, role_id), add)| RoleChange {
+                guild_id,
+                user_id,
+                role_id,
+                add,
+            })
+            .collect()
+    }
 
-        // Check if the majority threshold is met
-        let vote_passed = yes_percentage >= f64::from(config_ref.vote_config.majority_percentage);
+    /// Number of reward role changes waiting to be made
+    pub fn pending_role_changes(&self) -> usize {
+        self.reward_queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
 
-        // If the vote passed, reset all balances in the guild
-        if vote_passed {
-            if let Some(guild_balances) = self.guild_balances.get_mut(&guild_id) {
-                guild_balances.clear();
-                tracing::info!(
-                    "Reset all balances in guild {} due to successful vote",
-                    guild_id
-                );
-                // self.save().await.unwrap_or_else(|_| {
-                //     tracing::error!("Failed to save data after vote");
-                // });
+    /// Grant `role_id` at `threshold`, replacing its current threshold if it has one
+    pub fn set_balance_reward(&self, guild_id: serenity::GuildId, reward: BalanceReward) {
+        let mut config = self.config_mut(guild_id);
+        config
+            .balance_rewards
+            .retain(|current| current.role_id != reward.role_id);
+        config.balance_rewards.push(reward);
+        config
+            .balance_rewards
+            .sort_by_key(|reward| (reward.threshold, reward.role_id));
+    }
+
+    /// Stop granting `role_id`. Returns `false` if it wasn't a reward.
+    pub fn remove_balance_reward(&self, guild_id: serenity::GuildId, role_id: RoleId) -> bool {
+        let Some(mut config) = self.guild_configs.get_mut(&guild_id) else {
+            return false;
+        };
+        let before = config.balance_rewards.len();
+        config
+            .balance_rewards
+            .retain(|reward| reward.role_id != role_id.get());
+        config.balance_rewards.len() != before
+    }
+
+    /// Get a guild's reward roles, lowest threshold first
+    pub fn get_balance_rewards(&self, guild_id: serenity::GuildId) -> Vec<BalanceReward> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.balance_rewards.clone())
+            .unwrap_or_default()
+    }
+
+    /// Reward role changes that bring `users` in line with their balances.
+    ///
+    /// Every role a balance calls for is queued without checking what the member
+    /// already holds, since adding or removing a role twice is harmless.
+    pub fn reward_sync_changes(
+        &self,
+        guild_id: serenity::GuildId,
+        users: &[serenity::UserId],
+    ) -> Vec<RoleChange> {
+        let rewards = self.get_balance_rewards(guild_id);
+        let mut changes = Vec::new();
+        for &user_id in users {
+            let balance = self.get_guild_balance(guild_id, user_id);
+            for reward in &rewards {
+                if let Some(add) = reward.target(balance) {
+                    changes.push(RoleChange {
+                        guild_id,
+                        user_id,
+                        role_id: RoleId::new(reward.role_id),
+                        add,
+                    });
+                }
+            }
+        }
+        changes
+    }
+
+    /// Balance changes per user since `since` (or since tracking began for `None`),
+    /// in a guild or across all guilds for `None`
+    pub fn get_balance_changes(
+        &self,
+        guild_id: Option<serenity::GuildId>,
+        since: Option<chrono::NaiveDate>,
+    ) -> HashMap<serenity::UserId, BalanceChanges> {
+        let mut totals: HashMap<serenity::UserId, BalanceChanges> = HashMap::new();
+        let mut add = |config: &GuildConfig| {
+            for (user_id, changes) in config.changes_since(since) {
+                totals
+                    .entry(serenity::UserId::new(user_id))
+                    .or_default()
+                    .add(&changes);
             }
+        };
+        match guild_id {
+            Some(guild_id) => {
+                if let Some(config) = self.guild_configs.get(&guild_id) {
+                    add(&config);
+                }
+            }
+            None => self.guild_configs.iter().for_each(|config| add(&config)),
         }
+        totals
+    }
+
+    /// Set the thresholds for a proposal type in a guild
+    pub fn set_vote_config(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: ProposalKind,
+        vote_config: &VoteConfig,
+    ) {
+        let mut config = self.config_mut(guild_id);
+        *config.vote_config_mut(kind) = vote_config.clone();
+        drop(config);
+
+        // Reminder times of open votes may have moved
+        self.scheduler_wakeup.notify_one();
+    }
+
+    /// Get when the cooldown after a guild's last vote of a proposal type ends, if
+    /// it's still running
+    pub fn get_cooldown_end(
+        &self,
+        guild_id: serenity::GuildId,
+        kind: ProposalKind,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.guild_configs
+            .get(&guild_id)
+            .and_then(|config| config.cooldown_end(kind, now))
+    }
 
-        Ok(vote_passed)
+    /// Get a guild's open proposals, oldest first
+    pub fn get_open_proposals(&self, guild_id: serenity::GuildId) -> Vec<VoteStatus> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.open_proposals().into_iter().cloned().collect())
+            .unwrap_or_default()
     }
 
-    /// Check if a vote has expired and end it if necessary
-    pub fn check_vote_expiry(&self, guild_id: serenity::GuildId) -> Option<bool> {
+    /// Get an open proposal by number
+    pub fn get_proposal(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: u32,
+    ) -> Option<VoteStatus> {
         let config = self.guild_configs.get(&guild_id)?;
+        config.active_proposals.get(&proposal_id).cloned()
+    }
+
+    /// Remember the public message of a proposal, so it can be updated when it ends
+    pub fn set_vote_message(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: u32,
+        message_id: serenity::MessageId,
+    ) {
+        if let Some(mut config) = self.guild_configs.get_mut(&guild_id) {
+            if let Some(status) = config.active_proposals.get_mut(&proposal_id) {
+                status.message_id = Some(message_id.get());
+            }
+        }
+    }
+
+    /// Snapshots of a guild's recent resets, newest first
+    pub fn get_reset_snapshots(&self, guild_id: serenity::GuildId) -> Vec<ResetSnapshot> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.reset_snapshots.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Archived votes of a guild, newest first
+    pub fn get_vote_history(&self, guild_id: serenity::GuildId) -> Vec<VoteRecord> {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.vote_history.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get how long resets can be restored in a guild
+    pub fn get_reset_grace_hours(&self, guild_id: serenity::GuildId) -> u32 {
+        self.guild_configs
+            .get(&guild_id)
+            .map_or(DEFAULT_RESET_GRACE_HOURS, |config| {
+                config.reset_grace_hours()
+            })
+    }
+
+    /// Set how long resets can be restored in a guild, capped at
+    /// [`MAX_RESET_GRACE_HOURS`]
+    pub fn set_reset_grace_hours(&self, guild_id: serenity::GuildId, hours: u32) {
+        self.config_mut(guild_id).reset_grace_hours = Some(hours.min(MAX_RESET_GRACE_HOURS));
+    }
+
+    /// Get how reset votes change balances in a guild, unless opened with a mode
+    pub fn get_reset_mode(&self, guild_id: serenity::GuildId) -> ResetMode {
+        self.guild_configs
+            .get(&guild_id)
+            .map(|config| config.reset_mode)
+            .unwrap_or_default()
+    }
+
+    /// Set how reset votes change balances in a guild
+    pub fn set_reset_mode(&self, guild_id: serenity::GuildId, mode: ResetMode) {
+        self.config_mut(guild_id).reset_mode = mode;
+    }
+
+    /// Get the coins collected in a guild's treasury
+    pub fn get_treasury(&self, guild_id: serenity::GuildId) -> u32 {
+        self.guild_configs
+            .get(&guild_id)
+            .map_or(0, |config| config.treasury)
+    }
+
+    /// Undo a reset, by snapshot name or the latest one. Coins earned or spent
+    /// since the reset are kept. Returns the restored snapshot.
+    pub fn restore_reset(
+        &self,
+        guild_id: serenity::GuildId,
+        name: Option<&str>,
+        admin_id: serenity::UserId,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ResetSnapshot, RestoreError> {
+        // Mark the snapshot restored first, so two admins can't both restore it
+        let snapshot = {
+            let mut config = self
+                .guild_configs
+                .get_mut(&guild_id)
+                .ok_or(RestoreError::NoSnapshot)?;
+            let grace_hours = config.reset_grace_hours();
+            let snapshot = match name {
+                Some(name) => config
+                    .reset_snapshots
+                    .iter_mut()
+                    .find(|snapshot| snapshot.name == name)
+                    .ok_or(RestoreError::UnknownSnapshot)?,
+                None => config
+                    .reset_snapshots
+                    .last_mut()
+                    .ok_or(RestoreError::NoSnapshot)?,
+            };
+            if snapshot.restored_at.is_some() {
+                return Err(RestoreError::AlreadyRestored);
+            }
+            if now >= snapshot.restorable_until(grace_hours) {
+                return Err(RestoreError::GraceExpired);
+            }
+            snapshot.restored_at = Some(now);
+            snapshot.restored_by = Some(admin_id.get());
+            let snapshot = snapshot.clone();
+            config.treasury = config.treasury.saturating_sub(snapshot.to_treasury);
+            snapshot
+        };
 
-        // Check if a vote is active
-        if !config.vote_status.active {
-            return None;
+        // Undo each user's change, keeping anything earned or spent since
+        for (user_id, before, after) in snapshot.changes() {
+            let user_id = serenity::UserId::new(user_id);
+            let (previous_balance, new_balance) =
+                self.modify_balance(guild_id, user_id, |balance| {
+                    if before > after {
+                        balance.saturating_add(before - after)
+                    } else {
+                        balance.saturating_sub(after - before)
+                    }
+                });
+            crate::logging::log_balance_change(
+                guild_id.get(),
+                user_id.get(),
+                previous_balance,
+                new_balance,
+                "reset_restore",
+                Some(admin_id.get()),
+            );
+            self.record_balance_change(guild_id, user_id, previous_balance, new_balance, None);
         }
+        tracing::info!(
+            "Restored {} in guild {} for {} users",
+            snapshot.name,
+            guild_id,
+            snapshot.changes().count()
+        );
+
+        Ok(snapshot)
+    }
+
+    /// Open a proposal in a guild, returning its number and end time. The
+    /// initiator must be eligible to vote on it.
+    pub fn start_proposal(
+        &self,
+        guild_id: serenity::GuildId,
+        action: ProposalAction,
+        initiator: &Voter,
+        channel_id: serenity::ChannelId,
+    ) -> Result<(u32, chrono::DateTime<chrono::Utc>), VoteError> {
+        let now = chrono::Utc::now();
+        let balance = self.get_guild_balance(guild_id, serenity::UserId::new(initiator.user_id));
+        let started = {
+            let mut config = self.config_mut(guild_id);
+            let kind = action.kind();
+            let vote_config = config.vote_config(kind);
+            vote_config.check_eligibility(initiator, balance, now)?;
+            let proposal_id = config.next_proposal_id + 1;
+            let mut status = config.closed_vote(kind);
+            let end_time = status.start(
+                &vote_config,
+                proposal_id,
+                action,
+                initiator.user_id,
+                channel_id.get(),
+                now,
+            )?;
+            config.active_proposals.insert(proposal_id, status);
+            config.next_proposal_id = proposal_id;
+            (proposal_id, end_time)
+        };
 
-        // Check if the vote has expired
+        // The new vote may end before the scheduler's next wakeup
+        self.scheduler_wakeup.notify_one();
+
+        Ok(started)
+    }
+
+    /// Cast a vote on an open proposal, or the only open one when none is given.
+    /// Returns the proposal as it stands with the ballot counted.
+    ///
+    /// Ballots cast after the end time are refused; the scheduler finishes the vote.
+    pub fn cast_vote(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: Option<u32>,
+        voter: &Voter,
+        vote_yes: bool,
+    ) -> Result<VoteStatus, VoteError> {
         let now = chrono::Utc::now();
-        if let Some(end_time) = config.vote_status.end_time {
-            if now > end_time {
-                // End the vote
-                match self.end_vote(guild_id) {
-                    Ok(result) => return Some(result),
-                    Err(_) => return None,
+        let balance = self.get_guild_balance(guild_id, serenity::UserId::new(voter.user_id));
+        let mut config = self
+            .guild_configs
+            .get_mut(&guild_id)
+            .ok_or(VoteError::NotActive)?;
+        let (proposal_id, kind) = config.find_proposal(proposal_id)?;
+        let vote_config = config.vote_config(kind);
+        let status = config
+            .active_proposals
+            .get_mut(&proposal_id)
+            .ok_or(VoteError::NotActive)?;
+        // Ineligible voters only hear about it while the vote is open
+        if matches!(status.phase(&vote_config, now), VotePhase::Active { .. }) {
+            vote_config.check_eligibility(voter, balance, now)?;
+        }
+        status.cast(&vote_config, voter.user_id, vote_yes, balance, now)?;
+        Ok(status.clone())
+    }
+
+    /// Tally a proposal's ballots with its kind's weighting and current balances
+    pub fn tally(&self, guild_id: serenity::GuildId, status: &VoteStatus) -> Tally {
+        let weighting = self
+            .get_vote_config(guild_id, status.action.kind())
+            .weighting;
+        let balances = self.balance_snapshot(guild_id);
+        status.tally(weighting, |user_id| {
+            balances.get(&user_id).copied().unwrap_or(0)
+        })
+    }
+
+    /// Copy of a guild's balances, for weighting ballots without holding a map lock
+    fn balance_snapshot(&self, guild_id: serenity::GuildId) -> HashMap<u64, u32> {
+        self.guild_balances
+            .get(&guild_id)
+            .map(|balances| {
+                balances
+                    .iter()
+                    .map(|entry| (entry.key().get(), *entry.value()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// End an open proposal, or the only open one, now and apply the result
+    pub fn end_vote(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: Option<u32>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ExpiredVote, VoteError> {
+        self.finish_vote(guild_id, now, |config| config.find_proposal(proposal_id))
+    }
+
+    /// Cancel an open proposal, or the only open one, without applying it.
+    /// Returns the cancelled proposal.
+    pub fn cancel_vote(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: Option<u32>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<VoteStatus, VoteError> {
+        let balances = self.balance_snapshot(guild_id);
+        let mut config = self
+            .guild_configs
+            .get_mut(&guild_id)
+            .ok_or(VoteError::NotActive)?;
+        let (proposal_id, kind) = config.find_proposal(proposal_id)?;
+        let vote_config = config.effective_vote_config(kind, now);
+        let status = config
+            .active_proposals
+            .get_mut(&proposal_id)
+            .ok_or(VoteError::NotActive)?;
+        // Tallied first, as cancelling forgets the ballots of a secret vote
+        let tally = status.tally(vote_config.weighting, |user_id| {
+            balances.get(&user_id).copied().unwrap_or(0)
+        });
+        status.cancel(&vote_config, now)?;
+        let status = config
+            .close_proposal(proposal_id)
+            .ok_or(VoteError::NotActive)?;
+
+        config.archive_vote(VoteRecord::new(
+            &status,
+            &vote_config,
+            tally,
+            VoteResult::Cancelled,
+            now,
+        ));
+        Ok(status)
+    }
+
+    /// Give an open proposal, or the only open one, more time. Returns the
+    /// extended proposal.
+    pub fn extend_vote(
+        &self,
+        guild_id: serenity::GuildId,
+        proposal_id: Option<u32>,
+        minutes: u32,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<VoteStatus, VoteError> {
+        let extended = {
+            let mut config = self
+                .guild_configs
+                .get_mut(&guild_id)
+                .ok_or(VoteError::NotActive)?;
+            let (proposal_id, kind) = config.find_proposal(proposal_id)?;
+            let vote_config = config.vote_config(kind);
+            let status = config
+                .active_proposals
+                .get_mut(&proposal_id)
+                .ok_or(VoteError::NotActive)?;
+            status.extend(&vote_config, minutes, now)?;
+            status.clone()
+        };
+
+        // The scheduler may be waiting for the old end time
+        self.scheduler_wakeup.notify_one();
+
+        Ok(extended)
+    }
+
+    /// Finish the proposal picked by `select` under one config guard, then apply
+    /// it once the guard is released
+    fn finish_vote(
+        &self,
+        guild_id: serenity::GuildId,
+        now: chrono::DateTime<chrono::Utc>,
+        select: impl FnOnce(&GuildConfig) -> Result<(u32, ProposalKind), VoteError>,
+    ) -> Result<ExpiredVote, VoteError> {
+        // Weights use the balances as the vote ends, read before taking the guard
+        let balances = self.balance_snapshot(guild_id);
+        let (status, outcome) = {
+            let mut config = self
+                .guild_configs
+                .get_mut(&guild_id)
+                .ok_or(VoteError::NotActive)?;
+            let (proposal_id, kind) = select(&config)?;
+            // The quorum counts the members active as the vote ends
+            let vote_config = config.effective_vote_config(kind, now);
+            let outcome = config
+                .active_proposals
+                .get_mut(&proposal_id)
+                .ok_or(VoteError::NotActive)?
+                .finish(
+                    &vote_config,
+                    |user_id| balances.get(&user_id).copied().unwrap_or(0),
+                    now,
+                )?;
+            let status = config
+                .close_proposal(proposal_id)
+                .ok_or(VoteError::NotActive)?;
+
+            let result = if outcome.passed {
+                VoteResult::Passed
+            } else {
+                VoteResult::Failed
+            };
+            config.archive_vote(VoteRecord::new(
+                &status,
+                &vote_config,
+                outcome.tally,
+                result,
+                now,
+            ));
+            (status, outcome)
+        };
+
+        if outcome.passed {
+            self.apply_proposal(guild_id, &status);
+        }
+
+        let VoteOutcome { tally, passed } = outcome;
+        Ok(ExpiredVote {
+            guild_id,
+            status,
+            tally,
+            passed,
+        })
+    }
+
+    /// Apply a reset to a guild's balances, returning the balances before and after
+    /// it and the coins moved into the treasury
+    fn replace_balances(
+        &self,
+        guild_id: serenity::GuildId,
+        mode: ResetMode,
+        reason: &str,
+        initiator_id: Option<u64>,
+    ) -> (BTreeMap<u64, u32>, BTreeMap<u64, u32>, u32) {
+        // Read and replace the balances under one guard, so no give slips in between
+        let (balances, after, to_treasury) = match self.guild_balances.get_mut(&guild_id) {
+            Some(guild_balances) => {
+                let balances: BTreeMap<u64, u32> = guild_balances
+                    .iter()
+                    .map(|entry| (entry.key().get(), *entry.value()))
+                    .collect();
+                let (after, to_treasury) = mode.apply(&balances);
+                guild_balances.clear();
+                for (&user_id, &balance) in &after {
+                    if balance > 0 {
+                        guild_balances.insert(serenity::UserId::new(user_id), balance);
+                    }
+                }
+                for (&user_id, &balance) in &balances {
+                    let remaining = after.get(&user_id).copied().filter(|&after| after > 0);
+                    let user_id = serenity::UserId::new(user_id);
+                    self.update_ranking(guild_id, user_id, Some(balance), remaining);
                 }
+                (balances, after, to_treasury)
+            }
+            None => (BTreeMap::new(), BTreeMap::new(), 0),
+        };
+        for (&user_id, &balance) in &balances {
+            let new_balance = after.get(&user_id).copied().unwrap_or(0);
+            if new_balance != balance {
+                crate::logging::log_balance_change(
+                    guild_id.get(),
+                    user_id,
+                    balance,
+                    new_balance,
+                    reason,
+                    initiator_id,
+                );
+                let user_id = serenity::UserId::new(user_id);
+                self.record_balance_change(guild_id, user_id, balance, new_balance, None);
             }
         }
+        (balances, after, to_treasury)
+    }
 
-        None
+    /// Carry out a passed proposal
+    fn apply_proposal(&self, guild_id: serenity::GuildId, status: &VoteStatus) {
+        match &status.action {
+            ProposalAction::Reset | ProposalAction::PartialReset { .. } => {
+                let mode = status.action.reset_mode().unwrap_or_default();
+                let (balances, after, to_treasury) =
+                    self.replace_balances(guild_id, mode, "vote_reset", status.initiator_id);
+                tracing::info!(
+                    "Reset balances in guild {} due to successful vote ({:?})",
+                    guild_id,
+                    mode
+                );
+
+                let snapshot = ResetSnapshot {
+                    name: format!("reset-{}", status.proposal_id),
+                    proposal_id: status.proposal_id,
+                    taken_at: chrono::Utc::now(),
+                    balances,
+                    mode,
+                    after: after
+                        .into_iter()
+                        .filter(|&(_, balance)| balance > 0)
+                        .collect(),
+                    to_treasury,
+                    restored_at: None,
+                    restored_by: None,
+                };
+                if let Some(mut config) = self.guild_configs.get_mut(&guild_id) {
+                    config.treasury = config.treasury.saturating_add(to_treasury);
+                    config.reset_snapshots.push(snapshot);
+                    let excess = config
+                        .reset_snapshots
+                        .len()
+                        .saturating_sub(MAX_RESET_SNAPSHOTS);
+                    config.reset_snapshots.drain(..excess);
+                }
+            }
+            ProposalAction::Setting { kind, field, value } => {
+                if let Some(mut config) = self.guild_configs.get_mut(&guild_id) {
+                    config.vote_config_mut(*kind).set(*field, *value);
+                }
+            }
+            ProposalAction::Grant { user_id, amount } => {
+                let user_id = serenity::UserId::new(*user_id);
+                let (previous_balance, new_balance) =
+                    self.modify_balance(guild_id, user_id, |balance| {
+                        balance.saturating_add(*amount)
+                    });
+                crate::logging::log_balance_change(
+                    guild_id.get(),
+                    user_id.get(),
+                    previous_balance,
+                    new_balance,
+                    "proposal_grant",
+                    status.initiator_id,
+                );
+                self.record_balance_change(guild_id, user_id, previous_balance, new_balance, None);
+            }
+            ProposalAction::GiverRole { role_id, add } => {
+                let role_id = RoleId::new(*role_id);
+                if *add {
+                    self.add_economy_role(guild_id, EconomyRole::Giver, role_id);
+                } else {
+                    self.remove_economy_role(guild_id, EconomyRole::Giver, role_id);
+                }
+            }
+            ProposalAction::Poll { .. } => {}
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::voting::{VoteField, VoteWeighting};
 
     /// Helper function to create a test user ID
     fn test_user_id(id: u64) -> serenity::UserId {
@@ -758,6 +2758,21 @@ mod tests {
         serenity::RoleId::new(id)
     }
 
+    /// Helper function to create a test voter with an old account and no roles
+    fn test_voter(id: u64) -> Voter {
+        Voter {
+            user_id: id,
+            account_created: chrono::DateTime::UNIX_EPOCH,
+            joined_at: Some(chrono::DateTime::UNIX_EPOCH),
+            roles: vec![],
+        }
+    }
+
+    /// Helper function to create a test channel ID
+    fn test_channel_id(id: u64) -> serenity::ChannelId {
+        serenity::ChannelId::new(id)
+    }
+
     #[test]
     fn test_get_balance_empty() {
         let data = Data::new();
@@ -808,7 +2823,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_guild_top_users() {
+    fn test_get_guild_ranking() {
         let data = Data::new();
         let guild_id = test_guild_id(1);
 
@@ -818,11 +2833,15 @@ mod tests {
         data.add_coins(guild_id, test_user_id(3), 200);
         data.add_coins(guild_id, test_user_id(4), 75);
 
-        // Get top 3 users
-        let top_users = data.get_guild_top_users(guild_id, 3);
+        // Get the full ranking
+        let top_users: Vec<_> = data
+            .get_ranking_page(Some(guild_id), 0, usize::MAX)
+            .into_iter()
+            .map(|user| (user.user_id, user.balance))
+            .collect();
 
         // Check the order and values
-        assert_eq!(top_users.len(), 3);
+        assert_eq!(top_users.len(), 4);
         assert_eq!(top_users[0].0, test_user_id(3));
         assert_eq!(top_users[0].1, 200);
         assert_eq!(top_users[1].0, test_user_id(1));
@@ -832,7 +2851,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_global_top_users() {
+    fn test_get_global_ranking() {
         let data = Data::new();
         let guild1 = test_guild_id(1);
         let guild2 = test_guild_id(2);
@@ -846,8 +2865,12 @@ mod tests {
         data.add_coins(guild1, test_user_id(3), 75);
         data.add_coins(guild2, test_user_id(3), 75); // Same user in different guild
 
-        // Get top 3 users globally
-        let top_users = data.get_global_top_users(3);
+        // Get the ranking globally
+        let top_users: Vec<_> = data
+            .get_ranking_page(None, 0, usize::MAX)
+            .into_iter()
+            .map(|user| (user.user_id, user.balance))
+            .collect();
 
         // Check the number of users
         assert_eq!(top_users.len(), 3);
@@ -895,92 +2918,367 @@ mod tests {
     }
 
     #[test]
-    fn test_set_get_giver_role() {
+    fn test_add_remove_economy_roles() {
         let data = Data::new();
         let guild_id = test_guild_id(1);
-        let role_id = test_role_id(123);
 
-        // Initially, no giver role is set
-        assert_eq!(data.get_giver_role(guild_id), None);
-
-        // Set a giver role
-        data.set_giver_role(guild_id, Some(role_id));
+        // Initially, no giver roles are set
+        assert!(
+            data.get_economy_roles(guild_id, EconomyRole::Giver)
+                .is_empty()
+        );
 
-        // Check that the giver role is set
-        assert_eq!(data.get_giver_role(guild_id), Some(role_id));
+        // Add giver roles, ignoring duplicates
+        assert!(data.add_economy_role(guild_id, EconomyRole::Giver, test_role_id(1)));
+        assert!(data.add_economy_role(guild_id, EconomyRole::Giver, test_role_id(2)));
+        assert!(!data.add_economy_role(guild_id, EconomyRole::Giver, test_role_id(1)));
+        assert_eq!(
+            data.get_economy_roles(guild_id, EconomyRole::Giver),
+            vec![test_role_id(1), test_role_id(2)]
+        );
 
-        // Clear the giver role
-        data.set_giver_role(guild_id, None);
+        // Admin roles are kept separately
+        assert!(data.add_economy_role(guild_id, EconomyRole::Admin, test_role_id(3)));
+        assert_eq!(
+            data.get_economy_roles(guild_id, EconomyRole::Admin),
+            vec![test_role_id(3)]
+        );
 
-        // Check that the giver role is cleared
-        assert_eq!(data.get_giver_role(guild_id), None);
+        // Remove a giver role
+        assert!(data.remove_economy_role(guild_id, EconomyRole::Giver, test_role_id(1)));
+        assert!(!data.remove_economy_role(guild_id, EconomyRole::Giver, test_role_id(1)));
+        assert_eq!(
+            data.get_economy_roles(guild_id, EconomyRole::Giver),
+            vec![test_role_id(2)]
+        );
     }
 
-    // FIXME: This needs a mock for serenity::Member I think.
-    // #[test]
-    // fn test_has_giver_role() {
-    //     let data = Data::new();
-    //     let guild_id = test_guild_id(1);
-    //     let role_id = test_role_id(123);
-
-    //     // Initially, no giver role is set
-    //     assert!(!data.has_giver_role(guild_id, &serenity::Member::default()));
-
-    //     // Set a giver role
-    //     data.set_giver_role(guild_id, Some(role_id));
+    #[test]
+    fn test_can_give_hierarchy() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let giver_role = test_role_id(10);
+        let admin_role = test_role_id(20);
 
-    //     // Check that a user with the role has permission
-    //     let member = serenity::MemberBuilder::new()
-    //         .user(serenity::User::default())
-    //         .roles(vec![role_id])
-    //         .guild_id(guild_id)
-    //         .build();
+        data.add_economy_role(guild_id, EconomyRole::Giver, giver_role);
+        data.add_economy_role(guild_id, EconomyRole::Admin, admin_role);
+        data.set_user_access(guild_id, test_user_id(3), Some(UserAccess::Allow));
+        data.set_user_access(guild_id, test_user_id(4), Some(UserAccess::Deny));
 
-    //     assert!(data.has_giver_role(guild_id, &member));
+        let config = data.guild_configs.get(&guild_id).unwrap();
 
-    //     // Check that a user without the role doesn't have permission
-    //     let asdf = Member::default().;
-    //     let member = serenity::Member {
-    //         user: serenity::User::default(),
-    //         roles: vec![],
-    //         guild_id,
-    //         ..Default::default()
-    //     };
+        // Giver and admin roles can give, no roles cannot
+        assert!(config.can_give(test_user_id(1), &[giver_role]));
+        assert!(config.can_give(test_user_id(2), &[admin_role]));
+        assert!(!config.can_give(test_user_id(5), &[]));
 
-    //     assert!(!data.has_giver_role(guild_id, &member));
-    // }
+        // Only admin roles are economy admins
+        assert!(config.is_economy_admin(&[admin_role]));
+        assert!(!config.is_economy_admin(&[giver_role]));
 
-    #[test]
-    fn test_flip_coin() {
-        // Flip the coin multiple times to ensure it returns both true and false
-        let mut heads_count = 0;
-        let mut tails_count = 0;
+        // Allowlist works without roles, denylist overrides roles
+        assert!(config.can_give(test_user_id(3), &[]));
+        assert!(!config.can_give(test_user_id(4), &[giver_role, admin_role]));
+        drop(config);
 
-        for _ in 0..100 {
-            if DataInner::flip_coin() {
-                heads_count += 1;
-            } else {
-                tails_count += 1;
-            }
-        }
+        // Moving a user from the denylist to the allowlist
+        data.set_user_access(guild_id, test_user_id(4), Some(UserAccess::Allow));
+        assert_eq!(
+            data.get_user_access(guild_id, test_user_id(4)),
+            Some(UserAccess::Allow)
+        );
 
-        // Both heads and tails should occur
-        assert!(heads_count > 0);
-        assert!(tails_count > 0);
+        // Clearing removes the user from both lists
+        data.set_user_access(guild_id, test_user_id(4), None);
+        assert_eq!(data.get_user_access(guild_id, test_user_id(4)), None);
     }
 
     #[test]
-    fn test_parse_yaml() {
-        let yaml_str = r#"
-balances:
-  - guild_id: 1
-    user_id: 123
-    balance: 100
-  - guild_id: 1
-    user_id: 456
-    balance: 200
-  - guild_id: 2
-    user_id: 123
+    fn test_export_import_guild_settings() {
+        let data = Data::new();
+        let source = test_guild_id(1);
+        let target = test_guild_id(2);
+
+        data.add_economy_role(source, EconomyRole::Giver, test_role_id(10));
+        data.set_user_access(source, test_user_id(5), Some(UserAccess::Deny));
+        data.set_vote_config(
+            source,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 3,
+                ..Default::default()
+            },
+        );
+        data.set_vote_config(
+            source,
+            ProposalKind::Poll,
+            &VoteConfig {
+                min_votes: 4,
+                ..ProposalKind::Poll.default_config()
+            },
+        );
+        data.start_proposal(
+            source,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(1),
+        )
+        .unwrap();
+
+        // The target guild has a vote of its own running
+        data.start_proposal(
+            target,
+            ProposalAction::Reset,
+            &test_voter(2),
+            test_channel_id(2),
+        )
+        .unwrap();
+
+        let yaml = data.export_guild_settings(source).unwrap();
+        let imported = data.import_guild_settings(target, &yaml).unwrap();
+
+        // Settings are copied under the target guild's ID
+        assert_eq!(imported.guild_id, 2);
+        assert_eq!(imported.giver_role_ids, vec![10]);
+        assert_eq!(imported.user_denylist, vec![5]);
+        assert_eq!(
+            data.get_vote_config(target, ProposalKind::Reset).min_votes,
+            3
+        );
+        assert_eq!(
+            data.get_vote_config(target, ProposalKind::Poll).min_votes,
+            4
+        );
+        assert!(!yaml.contains("proposals:"));
+
+        // Runtime state is not exported and the target keeps its own
+        let status = data.get_proposal(target, 1).unwrap();
+        assert!(status.active);
+        assert_eq!(status.initiator_id, Some(2));
+
+        // Invalid YAML is rejected without touching the config
+        assert!(
+            data.import_guild_settings(target, "giver_role_ids: [")
+                .is_err()
+        );
+        assert_eq!(
+            data.get_vote_config(target, ProposalKind::Reset).min_votes,
+            3
+        );
+    }
+
+    #[test]
+    fn test_currency_format() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+
+        // Defaults, also used for global views
+        let currency = data.get_currency(Some(guild_id));
+        assert_eq!(currency, data.get_currency(None));
+        assert_eq!(currency.format(1), "1 AndyCoin");
+        assert_eq!(currency.format(5), "5 AndyCoins");
+        assert_eq!(currency.format_short(5), "5 AndyCoins");
+
+        data.set_currency(
+            guild_id,
+            CurrencyConfig {
+                name: "Gold Piece".to_string(),
+                plural: "Gold Pieces".to_string(),
+                symbol: Some("gp".to_string()),
+                emoji: Some("🪙".to_string()),
+            },
+        );
+
+        let currency = data.get_currency(Some(guild_id));
+        assert_eq!(currency.format(1), "🪙 1 Gold Piece");
+        assert_eq!(currency.format(0), "🪙 0 Gold Pieces");
+        assert_eq!(currency.format_short(12), "🪙 12 gp");
+        assert_eq!(data.get_currency(None), CurrencyConfig::default());
+
+        // Partially configured currencies fill in the defaults
+        let parsed: CurrencyConfig = serde_yaml::from_str("symbol: AC").unwrap();
+        assert_eq!(parsed.plural, "AndyCoins");
+        assert_eq!(parsed.format_short(3), "3 AC");
+
+        // Texts that could ping someone are caught, custom emoji aren't
+        assert!(!currency.has_mentions());
+        let emoji: CurrencyConfig = serde_yaml::from_str("emoji: '<:gold:123>'").unwrap();
+        assert!(!emoji.has_mentions());
+        let pinging: CurrencyConfig = serde_yaml::from_str("symbol: '<@&5>'").unwrap();
+        assert!(pinging.has_mentions());
+        assert!(
+            data.import_guild_settings(guild_id, "currency:\n  name: '@everyone'")
+                .is_err()
+        );
+        assert_eq!(data.get_currency(Some(guild_id)).name, "Gold Piece");
+    }
+
+    #[test]
+    fn test_budget_window_start() {
+        use chrono::TimeZone;
+
+        // Thursday afternoon
+        let now = chrono::Utc
+            .with_ymd_and_hms(2025, 3, 13, 15, 30, 0)
+            .unwrap();
+
+        assert_eq!(
+            BudgetPeriod::Daily.window_start(now),
+            chrono::Utc.with_ymd_and_hms(2025, 3, 13, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            BudgetPeriod::Weekly.window_start(now),
+            chrono::Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_giver_policy_limits_for() {
+        let mut policy = GiverPolicy {
+            default_limits: MintLimits {
+                max_per_give: Some(10),
+                budget: Some(50),
+            },
+            ..Default::default()
+        };
+        policy.set_role_limits(
+            test_role_id(1),
+            Some(MintLimits {
+                max_per_give: Some(20),
+                budget: Some(100),
+            }),
+        );
+        policy.set_role_limits(
+            test_role_id(2),
+            Some(MintLimits {
+                max_per_give: Some(5),
+                budget: None,
+            }),
+        );
+
+        // No matching role falls back to the default
+        assert_eq!(policy.limits_for(&[test_role_id(9)]), policy.default_limits);
+
+        // A single matching role uses its own limits
+        assert_eq!(
+            policy.limits_for(&[test_role_id(1)]),
+            MintLimits {
+                max_per_give: Some(20),
+                budget: Some(100),
+            }
+        );
+
+        // Several matching roles combine to the most permissive limits
+        assert_eq!(
+            policy.limits_for(&[test_role_id(1), test_role_id(2)]),
+            MintLimits {
+                max_per_give: Some(20),
+                budget: None,
+            }
+        );
+
+        // Clearing a role's limits removes it
+        policy.set_role_limits(test_role_id(2), None);
+        assert_eq!(policy.role_limits.len(), 1);
+    }
+
+    #[test]
+    fn test_reserve_mint() {
+        use chrono::TimeZone;
+
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let giver = test_user_id(1);
+        let monday = chrono::Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+        // Without a policy there are no limits
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 1000, monday),
+            Ok(None)
+        );
+
+        data.set_giver_policy(
+            guild_id,
+            GiverPolicy {
+                default_limits: MintLimits {
+                    max_per_give: Some(30),
+                    budget: Some(50),
+                },
+                budget_period: BudgetPeriod::Weekly,
+                role_limits: Vec::new(),
+            },
+        );
+
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 31, monday),
+            Err(MintError::ExceedsMaxPerGive { max: 30 })
+        );
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 30, monday),
+            Ok(Some(20))
+        );
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 25, monday),
+            Err(MintError::BudgetExhausted {
+                remaining: 20,
+                period: BudgetPeriod::Weekly
+            })
+        );
+        assert_eq!(
+            data.remaining_mint_budget(guild_id, giver, &[], monday),
+            Some(20)
+        );
+
+        // Later in the same week the budget is still spent
+        let friday = monday + chrono::Duration::days(4);
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 20, friday),
+            Ok(Some(0))
+        );
+
+        // The next week starts with a fresh budget
+        let next_monday = monday + chrono::Duration::days(7);
+        assert_eq!(
+            data.remaining_mint_budget(guild_id, giver, &[], next_monday),
+            Some(50)
+        );
+        assert_eq!(
+            data.reserve_mint(guild_id, giver, &[], 10, next_monday),
+            Ok(Some(40))
+        );
+    }
+
+    #[test]
+    fn test_flip_coin() {
+        // Flip the coin multiple times to ensure it returns both true and false
+        let mut heads_count = 0;
+        let mut tails_count = 0;
+
+        for _ in 0..100 {
+            if DataInner::flip_coin() {
+                heads_count += 1;
+            } else {
+                tails_count += 1;
+            }
+        }
+
+        // Both heads and tails should occur
+        assert!(heads_count > 0);
+        assert!(tails_count > 0);
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        let yaml_str = r#"
+balances:
+  - guild_id: 1
+    user_id: 123
+    balance: 100
+  - guild_id: 1
+    user_id: 456
+    balance: 200
+  - guild_id: 2
+    user_id: 123
     balance: 50
 configs:
   - guild_id: 1
@@ -1039,14 +3337,12 @@ configs:
             GuildConfig {
                 guild_id: 1,
                 giver_role_id: Some(789),
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
+                ..Default::default()
             },
             GuildConfig {
                 guild_id: 2,
                 giver_role_id: None,
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
+                ..Default::default()
             },
         ];
 
@@ -1074,12 +3370,22 @@ configs:
         assert_eq!(data.get_total_balance(test_user_id(123)), 150); // 100 + 50
         assert_eq!(data.get_total_balance(test_user_id(456)), 200);
 
-        // Check guild configs
+        // Check guild configs, with the legacy giver role migrated into the list
         assert_eq!(
-            data.get_giver_role(test_guild_id(1)),
-            Some(test_role_id(789))
+            data.get_economy_roles(test_guild_id(1), EconomyRole::Giver),
+            vec![test_role_id(789)]
+        );
+        assert!(
+            data.get_economy_roles(test_guild_id(2), EconomyRole::Giver)
+                .is_empty()
+        );
+        assert_eq!(
+            data.guild_configs
+                .get(&test_guild_id(1))
+                .unwrap()
+                .giver_role_id,
+            None
         );
-        assert_eq!(data.get_giver_role(test_guild_id(2)), None);
     }
 
     #[test]
@@ -1092,8 +3398,8 @@ configs:
         data.add_coins(test_guild_id(2), test_user_id(123), 50);
 
         // Set some guild configs
-        data.set_giver_role(test_guild_id(1), Some(test_role_id(789)));
-        data.set_giver_role(test_guild_id(2), None);
+        data.add_economy_role(test_guild_id(1), EconomyRole::Giver, test_role_id(789));
+        data.add_economy_role(test_guild_id(2), EconomyRole::Admin, test_role_id(790));
 
         // Export data
         let (mut balances, mut configs) = data.export_data();
@@ -1101,7 +3407,7 @@ configs:
         // Sort by guild_id and user_id to ensure consistent order for testing
         balances.sort_by(|a, b| a.guild_id.cmp(&b.guild_id).then(a.user_id.cmp(&b.user_id)));
 
-        configs.sort_by(|a, b| a.guild_id.cmp(&b.guild_id));
+        configs.sort_by_key(|config| config.guild_id);
 
         // Check the exported balances
         assert_eq!(balances.len(), 3);
@@ -1118,9 +3424,10 @@ configs:
         // Check the exported configs
         assert_eq!(configs.len(), 2);
         assert_eq!(configs[0].guild_id, 1);
-        assert_eq!(configs[0].giver_role_id, Some(789));
+        assert_eq!(configs[0].giver_role_ids, vec![789]);
         assert_eq!(configs[1].guild_id, 2);
-        assert_eq!(configs[1].giver_role_id, None);
+        assert!(configs[1].giver_role_ids.is_empty());
+        assert_eq!(configs[1].admin_role_ids, vec![790]);
     }
 
     #[test]
@@ -1147,14 +3454,12 @@ configs:
             GuildConfig {
                 guild_id: 1,
                 giver_role_id: Some(789),
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
+                ..Default::default()
             },
             GuildConfig {
                 guild_id: 2,
                 giver_role_id: None,
-                vote_config: VoteConfig::default(),
-                vote_status: VoteStatus::default(),
+                ..Default::default()
             },
         ];
 
@@ -1188,4 +3493,1062 @@ configs:
         assert_eq!(parsed_configs[1].guild_id, 2);
         assert_eq!(parsed_configs[1].giver_role_id, None);
     }
+
+    #[test]
+    fn test_expire_votes() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let other_guild = test_guild_id(2);
+
+        let (_, end_time) = data
+            .start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap();
+        let (_, other_end) = data
+            .start_proposal(
+                other_guild,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(20),
+            )
+            .unwrap();
+        data.cast_vote(guild_id, None, &test_voter(2), false)
+            .unwrap();
+        assert_eq!(data.next_vote_deadline(), Some(end_time.min(other_end)));
+
+        // Nothing is due before the votes end
+        assert!(
+            data.expire_votes(end_time - chrono::Duration::seconds(1))
+                .is_empty()
+        );
+
+        // Only the first guild's vote is due
+        set_end_time(&data, other_guild, end_time + chrono::Duration::hours(1));
+        let expired = data.expire_votes(end_time);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].guild_id, guild_id);
+        assert_eq!(expired[0].status.proposal_id, 1);
+        assert_eq!(expired[0].status.channel_id, Some(10));
+        assert_eq!(expired[0].status.action, ProposalAction::Reset);
+        assert_eq!(
+            expired[0].tally,
+            Tally {
+                yes_votes: 1,
+                no_votes: 1,
+                yes_weight: 1,
+                no_weight: 1,
+            }
+        );
+        assert!(!expired[0].passed);
+        assert!(data.get_proposal(guild_id, 1).is_none());
+        assert!(data.get_proposal(other_guild, 1).is_some());
+
+        // An ended vote is not expired twice
+        assert!(data.expire_votes(end_time).is_empty());
+        assert_eq!(
+            data.next_vote_deadline(),
+            Some(end_time + chrono::Duration::hours(1))
+        );
+    }
+
+    /// Move the end of a guild's first proposal
+    fn set_end_time(
+        data: &Data,
+        guild_id: serenity::GuildId,
+        end_time: chrono::DateTime<chrono::Utc>,
+    ) {
+        data.guild_configs
+            .get_mut(&guild_id)
+            .unwrap()
+            .active_proposals
+            .get_mut(&1)
+            .unwrap()
+            .end_time = Some(end_time);
+    }
+
+    /// Run `f` on another thread, failing instead of hanging if it deadlocks
+    fn within_deadline<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(f());
+        });
+        rx.recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap_or_else(|e| panic!("vote operations did not finish: {e}"))
+    }
+
+    #[test]
+    fn test_cast_after_end_time() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        data.start_proposal(
+            guild_id,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(10),
+        )
+        .unwrap();
+        set_end_time(
+            &data,
+            guild_id,
+            chrono::Utc::now() - chrono::Duration::seconds(1),
+        );
+
+        // Late ballots are refused without touching the map again
+        let late = data.clone();
+        let result = within_deadline(move || late.cast_vote(guild_id, None, &test_voter(2), true));
+        assert_eq!(result, Err(VoteError::Ended));
+
+        // The vote stays open until it is finished
+        assert!(data.get_proposal(guild_id, 1).unwrap().active);
+        let expired = data.expire_votes(chrono::Utc::now());
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].tally.yes_votes, 1);
+        assert_eq!(expired[0].tally.no_votes, 0);
+    }
+
+    #[test]
+    fn test_weighted_vote_eligibility() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Poll,
+            &VoteConfig {
+                min_votes: 2,
+                min_balance: 10,
+                required_role: Some(7),
+                weighting: VoteWeighting::Balance,
+                ..ProposalKind::Poll.default_config()
+            },
+        );
+        data.add_coins(guild_id, test_user_id(1), 100);
+        data.add_coins(guild_id, test_user_id(2), 20);
+        data.add_coins(guild_id, test_user_id(3), 5);
+        let member = |id| Voter {
+            roles: vec![7],
+            ..test_voter(id)
+        };
+        let poll = ProposalAction::Poll {
+            question: "Pizza?".to_string(),
+        };
+
+        // The initiator must be eligible too
+        assert_eq!(
+            data.start_proposal(guild_id, poll.clone(), &test_voter(1), test_channel_id(10)),
+            Err(VoteError::MissingRole { role_id: 7 })
+        );
+        data.start_proposal(guild_id, poll, &member(1), test_channel_id(10))
+            .unwrap();
+
+        assert_eq!(
+            data.cast_vote(guild_id, None, &member(3), false),
+            Err(VoteError::BalanceTooLow { min_balance: 10 })
+        );
+        let status = data.cast_vote(guild_id, None, &member(2), false).unwrap();
+
+        // The single yes ballot outweighs the no ballot
+        assert_eq!(data.tally(guild_id, &status).yes_weight, 100);
+        let ended = data
+            .end_vote(guild_id, Some(1), chrono::Utc::now())
+            .unwrap();
+        assert_eq!(
+            ended.tally,
+            Tally {
+                yes_votes: 1,
+                no_votes: 1,
+                yes_weight: 100,
+                no_weight: 20,
+            }
+        );
+        assert!(ended.passed);
+    }
+
+    #[test]
+    fn test_end_vote_resets_balances() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let other_guild = test_guild_id(2);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 1,
+                ..Default::default()
+            },
+        );
+        data.add_coins(guild_id, test_user_id(1), 50);
+        data.add_coins(other_guild, test_user_id(1), 20);
+
+        data.start_proposal(
+            guild_id,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(10),
+        )
+        .unwrap();
+        let now = chrono::Utc::now();
+        let ended = data.end_vote(guild_id, Some(1), now).unwrap();
+        assert!(ended.passed);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 0);
+        assert_eq!(data.get_guild_balance(other_guild, test_user_id(1)), 20);
+
+        assert_eq!(
+            data.end_vote(guild_id, Some(1), now),
+            Err(VoteError::UnknownProposal)
+        );
+        assert_eq!(
+            data.start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10)
+            ),
+            Err(VoteError::Cooldown)
+        );
+    }
+
+    #[test]
+    fn test_restore_reset() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let admin = test_user_id(9);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 1,
+                cooldown_hours: 0,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            data.restore_reset(guild_id, None, admin, chrono::Utc::now()),
+            Err(RestoreError::NoSnapshot)
+        );
+
+        data.add_coins(guild_id, test_user_id(1), 50);
+        data.add_coins(guild_id, test_user_id(2), 30);
+        data.start_proposal(
+            guild_id,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(10),
+        )
+        .unwrap();
+        assert!(
+            data.end_vote(guild_id, Some(1), chrono::Utc::now())
+                .unwrap()
+                .passed
+        );
+
+        let snapshots = data.get_reset_snapshots(guild_id);
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].name, "reset-1");
+        assert_eq!(snapshots[0].total(), 80);
+
+        // Coins earned after the reset are kept
+        data.add_coins(guild_id, test_user_id(2), 5);
+        let now = chrono::Utc::now();
+        assert_eq!(
+            data.restore_reset(guild_id, Some("reset-7"), admin, now),
+            Err(RestoreError::UnknownSnapshot)
+        );
+        let restored = data.restore_reset(guild_id, None, admin, now).unwrap();
+        assert_eq!(restored.restored_by, Some(admin.get()));
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 50);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 35);
+        assert_eq!(
+            data.restore_reset(guild_id, Some("reset-1"), admin, now),
+            Err(RestoreError::AlreadyRestored)
+        );
+
+        // A second reset can only be restored within the grace window
+        data.set_reset_grace_hours(guild_id, 2);
+        data.start_proposal(
+            guild_id,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(10),
+        )
+        .unwrap();
+        data.end_vote(guild_id, Some(2), chrono::Utc::now())
+            .unwrap();
+        let late = chrono::Utc::now() + chrono::Duration::hours(2);
+        assert_eq!(
+            data.restore_reset(guild_id, Some("reset-2"), admin, late),
+            Err(RestoreError::GraceExpired)
+        );
+        assert_eq!(data.get_reset_snapshots(guild_id)[0].name, "reset-2");
+    }
+
+    #[test]
+    fn test_partial_reset() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let admin = test_user_id(9);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 1,
+                cooldown_hours: 0,
+                ..Default::default()
+            },
+        );
+        data.add_coins(guild_id, test_user_id(1), 90);
+        data.add_coins(guild_id, test_user_id(2), 10);
+
+        // The server's mode is used by reset votes opened without one
+        data.set_reset_mode(guild_id, ResetMode::Tax { percent: 10 });
+        let action = ProposalAction::reset(data.get_reset_mode(guild_id));
+        data.start_proposal(guild_id, action, &test_voter(1), test_channel_id(10))
+            .unwrap();
+        data.end_vote(guild_id, Some(1), chrono::Utc::now())
+            .unwrap();
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 81);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 9);
+        assert_eq!(data.get_treasury(guild_id), 10);
+
+        data.restore_reset(guild_id, None, admin, chrono::Utc::now())
+            .unwrap();
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 90);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 10);
+        assert_eq!(data.get_treasury(guild_id), 0);
+
+        // Restoring a redistribution takes the gains back
+        let action = ProposalAction::reset(ResetMode::Redistribute);
+        data.start_proposal(guild_id, action, &test_voter(1), test_channel_id(10))
+            .unwrap();
+        data.end_vote(guild_id, Some(2), chrono::Utc::now())
+            .unwrap();
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 50);
+        data.restore_reset(guild_id, None, admin, chrono::Utc::now())
+            .unwrap();
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 90);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 10);
+    }
+
+    #[test]
+    fn test_cancel_and_extend_vote() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 1,
+                ..Default::default()
+            },
+        );
+        data.add_coins(guild_id, test_user_id(1), 50);
+        let (_, end_time) = data
+            .start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap();
+
+        let now = chrono::Utc::now();
+        let extended = data.extend_vote(guild_id, None, 30, now).unwrap();
+        let later = end_time + chrono::Duration::minutes(30);
+        assert_eq!(extended.end_time, Some(later));
+        assert_eq!(data.next_vote_deadline(), Some(later));
+        assert!(data.expire_votes(end_time).is_empty());
+
+        // The passing vote is cancelled, so the balances stay
+        let cancelled = data.cancel_vote(guild_id, Some(1), now).unwrap();
+        assert_eq!(cancelled.proposal_id, 1);
+        assert_eq!(data.next_vote_deadline(), None);
+        assert!(data.expire_votes(later).is_empty());
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(1)), 50);
+        assert_eq!(
+            data.cancel_vote(guild_id, Some(1), now).err(),
+            Some(VoteError::UnknownProposal)
+        );
+
+        // Without a cooldown, a new vote can start right away
+        assert!(
+            data.start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10)
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_balance_changes() {
+        let mut config = GuildConfig::default();
+        let today = chrono::Utc::now().date_naive();
+        let last_month = today - chrono::Duration::days(i64::from(MAX_CHANGE_DAYS));
+
+        config.record_change(1, 50, None, last_month);
+        config.record_change(1, 20, Some(2), today);
+        config.record_change(1, -5, None, today);
+
+        // Days past the window are dropped, but lifetime totals keep them
+        assert_eq!(
+            config.daily_changes.keys().collect::<Vec<_>>(),
+            vec![&today]
+        );
+        let window = config.changes_since(Some(last_month));
+        assert_eq!(window[&1].net(), 15);
+        assert_eq!(window[&2].given, 20);
+        let lifetime = config.changes_since(None);
+        assert_eq!(
+            lifetime[&1],
+            BalanceChanges {
+                earned: 45,
+                received: 20,
+                given: 0
+            }
+        );
+
+        // Exported settings leave the changes behind
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        data.add_coins(guild_id, test_user_id(1), 10);
+        let yaml = data.export_guild_settings(guild_id).unwrap();
+        assert!(!yaml.contains("lifetime_changes"));
+        assert_eq!(
+            data.get_balance_changes(Some(guild_id), None)[&test_user_id(1)].earned,
+            10
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_schedule() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let channel_id = test_channel_id(10);
+        let set_up: chrono::DateTime<chrono::Utc> = "2025-01-01T12:00:00Z".parse().unwrap();
+        let friday: chrono::DateTime<chrono::Utc> = "2025-01-03T18:00:00Z".parse().unwrap();
+
+        assert!(!data.set_leaderboard_schedule(
+            guild_id,
+            Some((channel_id, "every friday".to_string())),
+            set_up
+        ));
+        assert_eq!(data.next_leaderboard_post(), None);
+        assert!(data.set_leaderboard_schedule(
+            guild_id,
+            Some((channel_id, "0 18 * * 5".to_string())),
+            set_up
+        ));
+        assert_eq!(data.next_leaderboard_post(), Some(friday));
+        assert!(data.due_leaderboard_posts(set_up).is_empty());
+
+        // Two missed Fridays post once, and not again once marked as run
+        let late = friday + chrono::Duration::days(8);
+        let due = data.due_leaderboard_posts(late);
+        assert_eq!(
+            due,
+            vec![LeaderboardPost {
+                guild_id,
+                channel_id,
+                previous: BTreeMap::new(),
+                previous_run: set_up,
+            }]
+        );
+        assert!(data.due_leaderboard_posts(late).is_empty());
+        let standings = BTreeMap::from([(
+            1,
+            Standing {
+                rank: 1,
+                balance: 50,
+            },
+        )]);
+        data.set_leaderboard_standings(guild_id, standings.clone());
+
+        // The run and standings survive a restart
+        let yaml = serde_yaml::to_string(&data.get_guild_config(guild_id)).unwrap();
+        let config: GuildConfig = serde_yaml::from_str(&yaml).unwrap();
+        let schedule = config.leaderboard_schedule.unwrap();
+        assert_eq!(schedule.last_run, late);
+        assert_eq!(schedule.standings, standings);
+        assert_eq!(
+            schedule.next_run(),
+            Some(friday + chrono::Duration::days(14))
+        );
+
+        // The schedule stays with the guild on export
+        let yaml = data.export_guild_settings(guild_id).unwrap();
+        assert!(!yaml.contains("leaderboard_schedule"));
+
+        data.set_leaderboard_schedule(guild_id, None, late);
+        assert_eq!(data.next_leaderboard_post(), None);
+    }
+
+    #[test]
+    fn test_seasons() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let start: chrono::DateTime<chrono::Utc> = "2025-01-15T12:00:00Z".parse().unwrap();
+        let first_end: chrono::DateTime<chrono::Utc> = "2025-02-01T00:00:00Z".parse().unwrap();
+        let second_end: chrono::DateTime<chrono::Utc> = "2025-03-01T00:00:00Z".parse().unwrap();
+        let season = |winners| SeasonConfig {
+            spec: "@monthly".to_string(),
+            award_role: Some(test_role_id(7).get()),
+            winners,
+            ..Default::default()
+        };
+
+        assert!(!data.set_season(
+            guild_id,
+            Some(SeasonConfig {
+                spec: "monthly".to_string(),
+                ..Default::default()
+            }),
+            start
+        ));
+        assert!(data.set_season(guild_id, Some(season(2)), start));
+        assert_eq!(data.next_season_end(), Some(first_end));
+        assert!(data.end_due_seasons(start).is_empty());
+
+        data.add_coins(guild_id, test_user_id(1), 50);
+        data.add_coins(guild_id, test_user_id(2), 80);
+        data.add_coins(guild_id, test_user_id(3), 50);
+        data.add_coins(guild_id, test_user_id(4), 10);
+
+        // Ties share a place, so both second places win
+        let ends = data.end_due_seasons(first_end);
+        assert_eq!(ends.len(), 1);
+        let record = &ends[0].record;
+        assert_eq!(record.number, 1);
+        assert_eq!(record.started, start);
+        assert_eq!(
+            record.ranked(),
+            vec![
+                (
+                    test_user_id(2),
+                    Standing {
+                        rank: 1,
+                        balance: 80
+                    }
+                ),
+                (
+                    test_user_id(1),
+                    Standing {
+                        rank: 2,
+                        balance: 50
+                    }
+                ),
+                (
+                    test_user_id(3),
+                    Standing {
+                        rank: 2,
+                        balance: 50
+                    }
+                ),
+                (
+                    test_user_id(4),
+                    Standing {
+                        rank: 3,
+                        balance: 10
+                    }
+                ),
+            ]
+        );
+        assert_eq!(record.awarded, vec![1, 2, 3]);
+        assert_eq!(ends[0].previous_award, None);
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(2)), 0);
+        assert_eq!(data.get_total_balance(test_user_id(1)), 0);
+        assert!(data.end_due_seasons(first_end).is_empty());
+
+        // Changing settings keeps the season running; a new spec moves its end
+        assert!(data.set_season(guild_id, Some(season(1)), first_end));
+        let current = data.get_season(guild_id).unwrap();
+        assert_eq!((current.number, current.started), (2, first_end));
+        assert_eq!(current.ends_at, Some(second_end));
+
+        data.add_coins(guild_id, test_user_id(4), 5);
+        let ends = data.end_due_seasons(second_end);
+        assert_eq!(ends[0].record.awarded, vec![4]);
+        assert_eq!(
+            ends[0].previous_award,
+            Some((
+                test_role_id(7),
+                vec![test_user_id(1), test_user_id(2), test_user_id(3)]
+            ))
+        );
+        assert_eq!(data.get_season_history(guild_id).len(), 2);
+
+        // Turning seasons off keeps the history, and turning them on again carries
+        // on the numbering
+        data.set_season(guild_id, None, second_end);
+        assert_eq!(data.next_season_end(), None);
+        // A season stopped after it was found due leaves the balances alone
+        data.add_coins(guild_id, test_user_id(5), 20);
+        let late = second_end + chrono::Duration::days(60);
+        assert!(data.end_season(guild_id, late).is_none());
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(5)), 20);
+        assert!(data.set_season(guild_id, Some(season(1)), second_end));
+        assert_eq!(data.get_season(guild_id).unwrap().number, 3);
+    }
+
+    #[test]
+    fn test_balance_rewards() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let user_id = test_user_id(1);
+        let change = |role: u64, add| RoleChange {
+            guild_id,
+            user_id,
+            role_id: test_role_id(role),
+            add,
+        };
+        data.set_balance_reward(
+            guild_id,
+            BalanceReward {
+                role_id: test_role_id(7).get(),
+                threshold: 100,
+                remove_below: false,
+            },
+        );
+        data.set_balance_reward(
+            guild_id,
+            BalanceReward {
+                role_id: test_role_id(8).get(),
+                threshold: 50,
+                remove_below: true,
+            },
+        );
+        assert_eq!(
+            data.get_balance_rewards(guild_id)
+                .iter()
+                .map(|reward| reward.threshold)
+                .collect::<Vec<_>>(),
+            vec![50, 100]
+        );
+
+        // Only crossing a threshold queues a change
+        data.add_coins(guild_id, user_id, 60);
+        data.add_coins(guild_id, user_id, 10);
+        assert_eq!(data.take_role_changes(10), vec![change(8, true)]);
+        data.add_coins(guild_id, user_id, 50);
+        assert_eq!(data.take_role_changes(10), vec![change(7, true)]);
+        data.remove_coins(guild_id, user_id, 80);
+        assert_eq!(data.take_role_changes(10), vec![change(8, false)]);
+
+        // Changes to the same role collapse into the latest, and are taken in batches
+        data.add_coins(guild_id, user_id, 30);
+        data.remove_coins(guild_id, user_id, 30);
+        assert_eq!(data.pending_role_changes(), 1);
+        assert_eq!(data.take_role_changes(10), vec![change(8, false)]);
+        data.add_coins(guild_id, test_user_id(2), 200);
+        assert_eq!(data.take_role_changes(1).len(), 1);
+        assert_eq!(data.take_role_changes(10).len(), 1);
+        assert!(data.take_role_changes(10).is_empty());
+
+        // A sync queues every role the balances call for, held or not
+        let users = vec![user_id, test_user_id(2), test_user_id(3)];
+        let other = |user, role, add| RoleChange {
+            user_id: test_user_id(user),
+            ..change(role, add)
+        };
+        assert_eq!(
+            data.reward_sync_changes(guild_id, &users),
+            vec![
+                change(8, false),
+                other(2, 8, true),
+                other(2, 7, true),
+                other(3, 8, false),
+            ]
+        );
+
+        // Rewards are settings, so they're exported
+        let yaml = data.export_guild_settings(guild_id).unwrap();
+        assert!(yaml.contains("balance_rewards"));
+        assert!(data.remove_balance_reward(guild_id, test_role_id(8)));
+        assert!(!data.remove_balance_reward(guild_id, test_role_id(8)));
+    }
+
+    #[test]
+    fn test_vote_history() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                min_votes: 1,
+                majority_percentage: 50,
+                cooldown_hours: 0,
+                ..Default::default()
+            },
+        );
+        let start = || {
+            data.start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap()
+        };
+
+        // One vote passes, the next is cancelled
+        start();
+        data.cast_vote(guild_id, None, &test_voter(2), true)
+            .unwrap();
+        data.cast_vote(guild_id, None, &test_voter(3), false)
+            .unwrap();
+        let now = chrono::Utc::now();
+        assert!(data.end_vote(guild_id, None, now).unwrap().passed);
+        start();
+        data.cast_vote(guild_id, None, &test_voter(2), false)
+            .unwrap();
+        data.cancel_vote(guild_id, None, now).unwrap();
+
+        let history = data.get_vote_history(guild_id);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].proposal_id, 2);
+        assert_eq!(history[0].result, VoteResult::Cancelled);
+        assert_eq!(history[0].no_votes, vec![2]);
+        assert_eq!(history[1].proposal_id, 1);
+        assert_eq!(history[1].result, VoteResult::Passed);
+        assert_eq!(history[1].initiator_id, Some(1));
+        assert_eq!(history[1].yes_votes, vec![1, 2]);
+        assert_eq!(history[1].no_votes, vec![3]);
+        assert_eq!(history[1].config.min_votes, 1);
+        assert_eq!(history[1].closed_at, now);
+
+        // History stays with the guild on export
+        let yaml = data.export_guild_settings(guild_id).unwrap();
+        let imported = data.import_guild_settings(test_guild_id(2), &yaml).unwrap();
+        assert!(imported.vote_history.is_empty());
+    }
+
+    #[test]
+    fn test_quorum_of_active_members() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let now = chrono::Utc::now();
+        data.set_vote_config(
+            guild_id,
+            ProposalKind::Reset,
+            &VoteConfig {
+                majority_percentage: 50,
+                quorum: Quorum::ActiveMembers {
+                    percent: 50,
+                    days: 30,
+                },
+                ..Default::default()
+            },
+        );
+
+        // Four members were active in the window, one only before it
+        for user in 1..=4 {
+            data.record_activity(guild_id, test_user_id(user), now);
+        }
+        data.record_activity(guild_id, test_user_id(5), now - chrono::Duration::days(40));
+        assert_eq!(data.get_active_members(guild_id, 30), 4);
+        assert_eq!(data.get_active_members(guild_id, 60), 5);
+        assert_eq!(
+            data.get_effective_vote_config(guild_id, ProposalKind::Reset)
+                .min_votes,
+            2
+        );
+        // The stored config keeps its own minimum
+        assert_eq!(
+            data.get_vote_config(guild_id, ProposalKind::Reset)
+                .min_votes,
+            10
+        );
+
+        // Activity within the resolution doesn't move the timestamp
+        let soon = now + chrono::Duration::minutes(5);
+        data.record_activity(guild_id, test_user_id(1), soon);
+        assert_eq!(
+            data.get_guild_config(guild_id).activity[&test_user_id(1).get()],
+            now
+        );
+
+        // A vote below the quorum fails even though everyone voted yes
+        data.start_proposal(
+            guild_id,
+            ProposalAction::Reset,
+            &test_voter(1),
+            test_channel_id(10),
+        )
+        .unwrap();
+        let finished = data.end_vote(guild_id, None, now).unwrap();
+        assert!(!finished.passed);
+        assert_eq!(finished.tally.yes_votes, 1);
+        assert_eq!(data.get_vote_history(guild_id)[0].config.min_votes, 2);
+
+        // Members inactive for longer than any quorum counts are forgotten
+        let other_guild = test_guild_id(2);
+        data.record_activity(other_guild, test_user_id(1), now);
+        let much_later = now + chrono::Duration::days(i64::from(MAX_ACTIVITY_DAYS) + 1);
+        data.record_activity(other_guild, test_user_id(2), much_later);
+        let activity = data.get_guild_config(other_guild).activity;
+        assert_eq!(
+            activity.keys().collect::<Vec<_>>(),
+            vec![&test_user_id(2).get()]
+        );
+    }
+
+    #[test]
+    fn test_vote_reminders() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let reminders = VoteConfig {
+            reminder_minutes: Some(10),
+            reminder_role: Some(test_role_id(7).get()),
+            ..Default::default()
+        };
+        data.set_vote_config(guild_id, ProposalKind::Reset, &reminders);
+        data.set_vote_config(guild_id, ProposalKind::Poll, &reminders);
+        assert!(data.set_reminder_subscription(guild_id, test_user_id(2), true));
+        assert!(data.set_reminder_subscription(guild_id, test_user_id(3), true));
+        assert!(!data.set_reminder_subscription(guild_id, test_user_id(3), true));
+        assert!(data.set_reminder_subscription(guild_id, test_user_id(4), true));
+        assert!(data.set_reminder_subscription(guild_id, test_user_id(4), false));
+
+        let (id, end_time) = data
+            .start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap();
+        data.cast_vote(guild_id, Some(id), &test_voter(2), true)
+            .unwrap();
+        let due = end_time - chrono::Duration::minutes(10);
+        assert_eq!(data.next_vote_deadline(), Some(due));
+
+        // Only opted-in members who haven't voted are DMed, once
+        assert!(
+            data.due_reminders(due - chrono::Duration::seconds(1))
+                .is_empty()
+        );
+        let sent = data.due_reminders(due);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].proposal_id, id);
+        assert_eq!(sent[0].role_id, Some(test_role_id(7)));
+        assert_eq!(sent[0].user_ids, vec![test_user_id(3)]);
+        assert!(data.due_reminders(due).is_empty());
+        assert_eq!(data.next_vote_deadline(), Some(end_time));
+
+        // A vote cancelled before its reminder never sends it
+        let (poll_id, _) = data
+            .start_proposal(
+                guild_id,
+                ProposalAction::Poll {
+                    question: "Remind?".to_string(),
+                },
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap();
+        data.cancel_vote(guild_id, Some(poll_id), chrono::Utc::now())
+            .unwrap();
+        assert!(
+            data.due_reminders(end_time - chrono::Duration::seconds(1))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_expiry_during_casting() {
+        const CASTERS: u64 = 8;
+        const BALLOTS: u64 = 200;
+
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let (_, end_time) = data
+            .start_proposal(
+                guild_id,
+                ProposalAction::Reset,
+                &test_voter(1),
+                test_channel_id(10),
+            )
+            .unwrap();
+
+        let counts = within_deadline(move || {
+            let casters: Vec<_> = (0..CASTERS)
+                .map(|thread| {
+                    let data = data.clone();
+                    std::thread::spawn(move || {
+                        let (mut yes, mut no) = (0, 0);
+                        for i in 0..BALLOTS {
+                            let voter = test_voter(1000 + thread * BALLOTS + i);
+                            let vote_yes = i % 3 != 0;
+                            match data.cast_vote(guild_id, Some(1), &voter, vote_yes) {
+                                Ok(_) if vote_yes => yes += 1,
+                                Ok(_) => no += 1,
+                                Err(e) => assert_eq!(e, VoteError::UnknownProposal),
+                            }
+                        }
+                        (yes, no)
+                    })
+                })
+                .collect();
+
+            // Several finishers race the casters and each other
+            let finishers: Vec<_> = (0..3)
+                .map(|_| {
+                    let data = data.clone();
+                    std::thread::spawn(move || {
+                        while data
+                            .get_proposal(guild_id, 1)
+                            .is_some_and(|status| status.yes_votes.len() < 50)
+                        {
+                            std::thread::yield_now();
+                        }
+                        data.expire_votes(end_time)
+                    })
+                })
+                .collect();
+
+            let cast: Vec<(usize, usize)> =
+                casters.into_iter().map(|t| t.join().unwrap()).collect();
+            let expired: Vec<ExpiredVote> = finishers
+                .into_iter()
+                .flat_map(|t| t.join().unwrap())
+                .collect();
+            (cast, expired)
+        });
+
+        // The vote ended exactly once and counted every accepted ballot
+        let (cast, expired) = counts;
+        assert_eq!(expired.len(), 1);
+        let yes: usize = cast.iter().map(|(yes, _)| yes).sum();
+        let no: usize = cast.iter().map(|(_, no)| no).sum();
+        assert_eq!(expired[0].tally.yes_votes, yes + 1);
+        assert_eq!(expired[0].tally.no_votes, no);
+    }
+
+    #[test]
+    fn test_concurrent_proposals() {
+        let data = Data::new();
+        let guild_id = test_guild_id(1);
+        let channel_id = test_channel_id(10);
+        data.add_coins(guild_id, test_user_id(3), 5);
+        for kind in [
+            ProposalKind::Setting,
+            ProposalKind::Grant,
+            ProposalKind::GiverRole,
+        ] {
+            data.set_vote_config(
+                guild_id,
+                kind,
+                &VoteConfig {
+                    min_votes: 2,
+                    ..kind.default_config()
+                },
+            );
+        }
+
+        let setting = ProposalAction::Setting {
+            kind: ProposalKind::Reset,
+            field: VoteField::MinVotes,
+            value: 7,
+        };
+        let grant = ProposalAction::Grant {
+            user_id: 3,
+            amount: 40,
+        };
+        let role = ProposalAction::GiverRole {
+            role_id: 20,
+            add: true,
+        };
+        let poll = ProposalAction::Poll {
+            question: "Pizza on Friday?".to_string(),
+        };
+        // Several proposals of one type can run at once
+        let actions = [setting, grant.clone(), role, poll, grant.clone()];
+        for (action, expected_id) in actions.into_iter().zip(1..) {
+            let (id, _) = data
+                .start_proposal(guild_id, action, &test_voter(1), channel_id)
+                .unwrap();
+            assert_eq!(id, expected_id);
+        }
+
+        // With several open, ballots need a proposal number
+        assert_eq!(
+            data.cast_vote(guild_id, None, &test_voter(2), true),
+            Err(VoteError::ChooseProposal)
+        );
+        assert_eq!(
+            data.cast_vote(guild_id, Some(9), &test_voter(2), true),
+            Err(VoteError::UnknownProposal)
+        );
+        for id in 1..=5 {
+            assert_eq!(
+                data.cast_vote(guild_id, Some(id), &test_voter(2), true)
+                    .map(|status| status.proposal_id),
+                Ok(id)
+            );
+        }
+        assert_eq!(data.get_open_proposals(guild_id).len(), 5);
+
+        // Passed proposals are applied when they end
+        let now = chrono::Utc::now();
+        let ended = now + chrono::Duration::hours(1);
+        let expired = data.expire_votes(ended);
+        assert_eq!(expired.len(), 5);
+        assert!(expired.iter().all(|vote| vote.passed));
+        assert_eq!(
+            data.get_vote_config(guild_id, ProposalKind::Reset)
+                .min_votes,
+            7
+        );
+        assert_eq!(data.get_guild_balance(guild_id, test_user_id(3)), 85);
+        assert_eq!(data.get_guild_config(guild_id).giver_role_ids, vec![20]);
+        assert!(data.get_open_proposals(guild_id).is_empty());
+
+        // Cooldowns are per type; polls have none by default
+        assert_eq!(
+            data.get_cooldown_end(guild_id, ProposalKind::Grant, now),
+            Some(ended + chrono::Duration::hours(1))
+        );
+        assert_eq!(
+            data.start_proposal(guild_id, grant, &test_voter(2), channel_id),
+            Err(VoteError::Cooldown)
+        );
+        assert_eq!(
+            data.get_cooldown_end(guild_id, ProposalKind::Poll, ended),
+            None
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_vote() {
+        let yaml = r#"
+guild_id: 1
+vote_status:
+  active: true
+  start_time: null
+  end_time: null
+  initiator_id: 5
+  yes_votes: [5]
+  no_votes: []
+  last_vote_time: null
+"#;
+        let mut config: GuildConfig = serde_yaml::from_str(yaml).unwrap();
+        config.migrate_legacy();
+
+        let status = &config.active_proposals[&1];
+        assert!(status.active);
+        assert_eq!(status.proposal_id, 1);
+        assert_eq!(status.action, ProposalAction::Reset);
+        assert_eq!(config.next_proposal_id, 1);
+        assert!(config.vote_status.is_unused());
+        assert!(
+            !serde_yaml::to_string(&config)
+                .unwrap()
+                .contains("vote_status")
+        );
+    }
 }
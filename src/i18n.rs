@@ -0,0 +1,312 @@
+//! Localized bot responses.
+//!
+//! Messages live in Fluent catalogs under `locales/`, one per Discord locale, and
+//! are compiled into the binary. A reply uses the interaction's locale when there
+//! is a catalog for it, then the guild's configured locale, then English.
+//!
+//! The non-English catalogs also carry the slash command localizations: `cmd-*`
+//! messages hold a command's name, with its description and parameters as
+//! attributes, and `choice-*` messages hold choice names.
+
+use crate::{Context, Data, Error};
+use fluent::{FluentArgs, FluentResource, bundle::FluentBundle};
+use intl_memoizer::concurrent::IntlLangMemoizer;
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Locale used when nothing better is available
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Catalogs by Discord locale code
+const CATALOGS: &[(&str, &str)] = &[
+    ("en-US", include_str!("../locales/en-US.ftl")),
+    ("de", include_str!("../locales/de.ftl")),
+    ("es-ES", include_str!("../locales/es-ES.ftl")),
+    ("pt-BR", include_str!("../locales/pt-BR.ftl")),
+];
+
+type Bundle = FluentBundle<FluentResource, IntlLangMemoizer>;
+
+static BUNDLES: LazyLock<HashMap<&'static str, Bundle>> = LazyLock::new(|| {
+    CATALOGS
+        .iter()
+        .map(|(locale, source)| (*locale, build_bundle(locale, source)))
+        .collect()
+});
+
+/// Parse a catalog, panicking on errors since catalogs are part of the build
+fn build_bundle(locale: &str, source: &str) -> Bundle {
+    let langid = locale
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid locale {locale}: {e}"));
+    let resource = FluentResource::try_new(source.to_string())
+        .unwrap_or_else(|(_, errors)| panic!("invalid catalog {locale}: {errors:?}"));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // Unicode isolation marks show up as junk in Discord messages
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .unwrap_or_else(|errors| panic!("duplicate messages in {locale}: {errors:?}"));
+    bundle
+}
+
+/// Languages a guild can choose for its responses
+#[derive(Clone, Copy, Debug, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum Language {
+    #[name = "English"]
+    English,
+    #[name = "Deutsch"]
+    German,
+    #[name = "Español"]
+    Spanish,
+    #[name = "Português (Brasil)"]
+    Portuguese,
+}
+
+impl Language {
+    /// Discord locale code of the language's catalog
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::German => "de",
+            Language::Spanish => "es-ES",
+            Language::Portuguese => "pt-BR",
+        }
+    }
+}
+
+/// Find the catalog for a Discord locale, falling back to one for the same language
+/// (e.g. "es-419" uses "es-ES")
+pub fn supported_locale(locale: &str) -> Option<&'static str> {
+    let language = |code: &str| code.split('-').next().unwrap_or_default().to_lowercase();
+
+    CATALOGS
+        .iter()
+        .map(|(code, _)| *code)
+        .find(|code| code.eq_ignore_ascii_case(locale))
+        .or_else(|| {
+            CATALOGS
+                .iter()
+                .map(|(code, _)| *code)
+                .find(|code| language(code) == language(locale))
+        })
+}
+
+/// Locale for messages in a guild that aren't a reply to anyone
+pub fn guild_locale(data: &Data, guild_id: serenity::GuildId) -> &'static str {
+    data.get_locale(guild_id)
+        .as_deref()
+        .and_then(supported_locale)
+        .unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Locale for replying to a command
+pub fn locale(ctx: Context<'_>) -> &'static str {
+    interaction_locale(ctx.data(), ctx.locale().unwrap_or_default(), ctx.guild_id())
+}
+
+/// Locale for replying to an interaction from a user with `user_locale`
+pub fn interaction_locale(
+    data: &Data,
+    user_locale: &str,
+    guild_id: Option<serenity::GuildId>,
+) -> &'static str {
+    supported_locale(user_locale).unwrap_or_else(|| match guild_id {
+        Some(guild_id) => guild_locale(data, guild_id),
+        None => DEFAULT_LOCALE,
+    })
+}
+
+/// Format a message attribute from one catalog, if it has it
+fn format(
+    locale: &str,
+    id: &str,
+    attribute: Option<&str>,
+    args: Option<&FluentArgs<'_>>,
+) -> Option<String> {
+    let bundle = BUNDLES.get(locale)?;
+    let message = bundle.get_message(id)?;
+    let pattern = match attribute {
+        Some(attribute) => message.get_attribute(attribute)?.value(),
+        None => message.value()?,
+    };
+
+    let mut errors = vec![];
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        tracing::warn!("Errors formatting {id} in {locale}: {errors:?}");
+    }
+    Some(text.into_owned())
+}
+
+/// Get a message in `locale`, falling back to English and then the message ID
+pub fn get(locale: &str, id: &str, args: &FluentArgs<'_>) -> String {
+    format(locale, id, None, Some(args))
+        .or_else(|| format(DEFAULT_LOCALE, id, None, Some(args)))
+        .unwrap_or_else(|| {
+            tracing::warn!("Missing message {id}");
+            id.to_string()
+        })
+}
+
+/// Get a localized message, e.g. `tr!(locale, "balance-self-server", amount = amount)`
+macro_rules! tr {
+    ($locale:expr, $id:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut args = fluent::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)*
+        $crate::i18n::get($locale, $id, &args)
+    }};
+}
+pub(crate) use tr;
+
+/// Catalog key for a choice name, e.g. "choice-vote-yes" for "Vote yes"
+fn choice_key(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("choice-{slug}")
+}
+
+/// Register name and description localizations for commands and their subcommands
+pub fn localize_commands(commands: &mut [poise::Command<Data, Error>]) {
+    for command in commands {
+        let key = format!("cmd-{}", command.qualified_name.replace(' ', "-"));
+
+        for (locale, _) in CATALOGS.iter().filter(|(l, _)| *l != DEFAULT_LOCALE) {
+            let locale = locale.to_string();
+            if let Some(name) = format(&locale, &key, None, None) {
+                command.name_localizations.insert(locale.clone(), name);
+            }
+            if let Some(description) = format(&locale, &key, Some("description"), None) {
+                command
+                    .description_localizations
+                    .insert(locale.clone(), description);
+            }
+
+            for parameter in &mut command.parameters {
+                if let Some(name) = format(&locale, &key, Some(&parameter.name), None) {
+                    parameter.name_localizations.insert(locale.clone(), name);
+                }
+                let description_attr = format!("{}-description", parameter.name);
+                if let Some(description) = format(&locale, &key, Some(&description_attr), None) {
+                    parameter
+                        .description_localizations
+                        .insert(locale.clone(), description);
+                }
+                for choice in &mut parameter.choices {
+                    if let Some(name) = format(&locale, &choice_key(&choice.name), None, None) {
+                        choice.localizations.insert(locale.clone(), name);
+                    }
+                }
+            }
+        }
+
+        localize_commands(&mut command.subcommands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to list the message IDs in a catalog
+    fn message_ids(source: &str) -> Vec<String> {
+        let mut ids: Vec<String> = source
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id.to_string()))
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_catalogs_are_complete() {
+        let english = message_ids(CATALOGS[0].1);
+        let mut localized: Option<Vec<String>> = None;
+
+        for (locale, source) in &CATALOGS[1..] {
+            let ids = message_ids(source);
+            let (commands, messages): (Vec<_>, Vec<_>) = ids
+                .into_iter()
+                .partition(|id| id.starts_with("cmd-") || id.starts_with("choice-"));
+
+            assert_eq!(messages, english, "{locale} is missing or adds messages");
+
+            // Every translation localizes the same commands
+            match &localized {
+                Some(expected) => assert_eq!(&commands, expected, "{locale} commands"),
+                None => localized = Some(commands),
+            }
+        }
+    }
+
+    #[test]
+    fn test_supported_locale() {
+        assert_eq!(supported_locale("en-US"), Some("en-US"));
+        assert_eq!(supported_locale("de"), Some("de"));
+        assert_eq!(supported_locale("es-419"), Some("es-ES"));
+        assert_eq!(supported_locale("pt-br"), Some("pt-BR"));
+        assert_eq!(supported_locale("en-GB"), Some("en-US"));
+        assert_eq!(supported_locale("fr"), None);
+    }
+
+    #[test]
+    fn test_get_with_fallback() {
+        assert_eq!(
+            tr!("de", "balance-self-server", amount = "5 AndyCoins"),
+            "Du hast 5 AndyCoins auf diesem Server."
+        );
+        assert_eq!(
+            tr!("fr", "balance-self-server", amount = "5 AndyCoins"),
+            "You have 5 AndyCoins in this server."
+        );
+        assert_eq!(tr!("de", "no-such-message"), "no-such-message");
+    }
+
+    #[test]
+    fn test_localize_commands() {
+        let mut commands = crate::commands::_all_commands();
+        localize_commands(&mut commands);
+
+        let give = commands.iter().find(|c| c.name == "give").unwrap();
+        assert_eq!(give.name_localizations.get("de").unwrap(), "geben");
+        assert!(
+            give.parameters[0]
+                .description_localizations
+                .contains_key("pt-BR")
+        );
+
+        // Discord rejects names with spaces or uppercase letters
+        fn check(commands: &[poise::Command<Data, Error>]) {
+            for command in commands {
+                let names = command.name_localizations.values().chain(
+                    command
+                        .parameters
+                        .iter()
+                        .flat_map(|p| p.name_localizations.values()),
+                );
+                for name in names {
+                    assert!(
+                        name.chars().count() <= 32
+                            && !name.contains(' ')
+                            && name.to_lowercase() == *name,
+                        "invalid localized name {name}"
+                    );
+                }
+                check(&command.subcommands);
+            }
+        }
+        check(&commands);
+    }
+}
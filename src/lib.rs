@@ -0,0 +1,4 @@
+//! Library part of the bot: modules with no Discord or data-file dependencies,
+//! shared with the benchmarks.
+
+pub mod ranking;
@@ -1,9 +1,16 @@
-use data::DataInner;
 use poise::serenity_prelude as serenity;
 
 mod commands;
+mod cron;
 mod data;
+mod i18n;
 mod logging;
+mod names;
+mod permissions;
+mod rewards;
+mod scheduler;
+mod vote_message;
+mod voting;
 
 pub use data::Data;
 
@@ -20,27 +27,84 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let token = std::env::var("DISCORD_TOKEN").expect("missing DISCORD_TOKEN");
     let intents = serenity::GatewayIntents::non_privileged();
 
-    let data_inner = DataInner::load().await;
-    data_inner.expire_votes();
-    let data = Data(data_inner);
+    // Bot owners are configured in addition to the application owner
+    let owners = std::env::var("BOT_OWNERS")
+        .map(|value| permissions::parse_owners(&value))
+        .unwrap_or_default();
+
+    let data = Data::load().await;
+
+    let mut commands = vec![
+        commands::give::give(),
+        commands::balance::balance(),
+        commands::leaderboard::leaderboard(),
+        commands::leaderboard::rank(),
+        commands::seasons::seasons(),
+        commands::config::config(),
+        commands::config::config_admin(),
+        commands::config::flip(),
+        commands::vote::vote(),
+        commands::vote::propose(),
+        commands::vote::vote_admin(),
+    ];
+    i18n::localize_commands(&mut commands);
 
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
-            commands: vec![
-                commands::give::give(),
-                commands::balance::balance(),
-                commands::leaderboard::leaderboard(),
-                commands::config::config(),
-                commands::config::role(),
-                commands::config::flip(),
-                commands::vote::vote(),
-                commands::vote::vote_admin(),
-            ],
+            commands,
+            owners,
+            event_handler: |ctx, event, _framework, data| {
+                Box::pin(async move {
+                    data.names.record_event(event);
+
+                    // Messages, commands and buttons count as activity for quorums
+                    match event {
+                        serenity::FullEvent::Message { new_message } if !new_message.author.bot => {
+                            if let Some(guild_id) = new_message.guild_id {
+                                data.record_activity(
+                                    guild_id,
+                                    new_message.author.id,
+                                    *new_message.timestamp,
+                                );
+                            }
+                        }
+                        serenity::FullEvent::InteractionCreate { interaction } => {
+                            let member = match interaction {
+                                serenity::Interaction::Command(command) => {
+                                    command.guild_id.map(|guild_id| (guild_id, command.user.id))
+                                }
+                                serenity::Interaction::Component(component) => component
+                                    .guild_id
+                                    .map(|guild_id| (guild_id, component.user.id)),
+                                _ => None,
+                            };
+                            if let Some((guild_id, user_id)) = member {
+                                data.record_activity(guild_id, user_id, chrono::Utc::now());
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // Buttons are routed by custom ID, so they work across restarts
+                    if let serenity::FullEvent::InteractionCreate {
+                        interaction: serenity::Interaction::Component(component),
+                    } = event
+                    {
+                        vote_message::handle_component(ctx, component, data).await?;
+                        commands::leaderboard::handle_component(ctx, component, data).await?;
+                    }
+                    Ok(())
+                })
+            },
             ..Default::default()
         })
         .setup(|ctx, _ready, framework| {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
+
+                // Votes that ended while the bot was offline are finished right away
+                scheduler::spawn(ctx.clone(), data.clone());
+                rewards::spawn(ctx.http.clone(), data.clone());
                 tracing::info!(
                     "Bot is ready! Registered {} commands.",
                     framework.options().commands.len()
@@ -0,0 +1,427 @@
+//! Cache of user names and guild nicknames for rendering users in messages.
+//!
+//! Names are fed from gateway events (messages and interactions) and expire after
+//! [`NAME_TTL`]. The bot only asks for non-privileged intents, so member events
+//! never arrive. Lookups fall back to serenity's member and user cache, then fetch
+//! whatever is still missing from the API a few users at a time. Users the API
+//! doesn't know are remembered as missing for the same TTL, so they aren't fetched
+//! again on every render. Server lookups prefer the member's nickname, so a user
+//! must also have a cached nickname entry (even an empty one) to count as known in
+//! a guild.
+
+use dashmap::DashMap;
+use poise::serenity_prelude as serenity;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// How long a cached name is trusted before it is looked up again
+pub const NAME_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Most names kept per map before older ones are evicted
+const MAX_CACHED_NAMES: usize = 50_000;
+
+/// Users fetched from the API at the same time
+const FETCH_BATCH: usize = 10;
+
+struct Cached<T> {
+    value: T,
+    cached_at: Instant,
+}
+
+impl<T> Cached<T> {
+    fn is_fresh(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.cached_at) < NAME_TTL
+    }
+}
+
+/// Result of looking a user up through the API
+enum Fetched {
+    Member(Box<serenity::Member>),
+    User(Box<serenity::User>),
+    /// The API doesn't know the user
+    Missing,
+    /// The request failed for another reason, like a rate limit, and may succeed later
+    Failed,
+}
+
+/// Whether an API error means the requested object doesn't exist
+fn is_not_found(error: &serenity::Error) -> bool {
+    matches!(
+        error,
+        serenity::Error::Http(e) if e.status_code() == Some(serenity::StatusCode::NOT_FOUND)
+    )
+}
+
+#[derive(Default)]
+pub struct NameCache {
+    // Map of user_id -> global display name, or username without one
+    users: DashMap<serenity::UserId, Cached<String>>,
+    // Map of (guild_id, user_id) -> nickname, `None` if the member has none
+    nicknames: DashMap<(serenity::GuildId, serenity::UserId), Cached<Option<String>>>,
+    // Users the API couldn't find
+    missing: DashMap<serenity::UserId, Cached<()>>,
+}
+
+/// Insert into a cache map, evicting stale entries and then the oldest ones when full
+fn insert_bounded<K: Eq + Hash + Clone, T>(
+    map: &DashMap<K, Cached<T>>,
+    key: K,
+    value: T,
+    now: Instant,
+) {
+    if map.len() >= MAX_CACHED_NAMES && !map.contains_key(&key) {
+        map.retain(|_, cached| cached.is_fresh(now));
+        if map.len() >= MAX_CACHED_NAMES {
+            let mut ages: Vec<_> = map
+                .iter()
+                .map(|entry| (entry.cached_at, entry.key().clone()))
+                .collect();
+            ages.sort_unstable_by_key(|(cached_at, _)| *cached_at);
+            // Free a tenth of the cache so eviction doesn't run on every insert
+            for (_, key) in ages.into_iter().take(MAX_CACHED_NAMES / 10) {
+                map.remove(&key);
+            }
+        }
+    }
+    map.insert(
+        key,
+        Cached {
+            value,
+            cached_at: now,
+        },
+    );
+}
+
+impl NameCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert_user(&self, user: &serenity::User) {
+        self.insert_user_at(user, Instant::now());
+    }
+
+    fn insert_user_at(&self, user: &serenity::User, now: Instant) {
+        insert_bounded(&self.users, user.id, user.display_name().to_string(), now);
+    }
+
+    pub fn insert_nickname(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        nick: Option<String>,
+    ) {
+        self.insert_nickname_at(guild_id, user_id, nick, Instant::now());
+    }
+
+    fn insert_nickname_at(
+        &self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        nick: Option<String>,
+        now: Instant,
+    ) {
+        insert_bounded(&self.nicknames, (guild_id, user_id), nick, now);
+    }
+
+    pub fn insert_member(&self, member: &serenity::Member) {
+        self.insert_user(&member.user);
+        self.insert_nickname(member.guild_id, member.user.id, member.nick.clone());
+    }
+
+    fn insert_missing_at(&self, user_id: serenity::UserId, now: Instant) {
+        insert_bounded(&self.missing, user_id, (), now);
+    }
+
+    /// Whether a user recently couldn't be fetched
+    fn is_missing(&self, user_id: serenity::UserId, now: Instant) -> bool {
+        self.missing
+            .get(&user_id)
+            .is_some_and(|missing| missing.is_fresh(now))
+    }
+
+    /// Learn names from a gateway event
+    pub fn record_event(&self, event: &serenity::FullEvent) {
+        match event {
+            serenity::FullEvent::Message { new_message } => {
+                self.insert_user(&new_message.author);
+                if let (Some(guild_id), Some(member)) = (new_message.guild_id, &new_message.member)
+                {
+                    self.insert_nickname(guild_id, new_message.author.id, member.nick.clone());
+                }
+            }
+            serenity::FullEvent::InteractionCreate { interaction } => match interaction {
+                serenity::Interaction::Command(command) => {
+                    self.insert_user(&command.user);
+                    if let Some(member) = &command.member {
+                        self.insert_member(member);
+                    }
+                    // Users picked as command options
+                    let resolved = &command.data.resolved;
+                    for user in resolved.users.values() {
+                        self.insert_user(user);
+                    }
+                    if let Some(guild_id) = command.guild_id {
+                        for (user_id, member) in &resolved.members {
+                            self.insert_nickname(guild_id, *user_id, member.nick.clone());
+                        }
+                    }
+                }
+                serenity::Interaction::Component(component) => {
+                    self.insert_user(&component.user);
+                    if let Some(member) = &component.member {
+                        self.insert_member(member);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Cached name of a user, preferring their nickname in `guild_id`
+    fn cached(
+        &self,
+        guild_id: Option<serenity::GuildId>,
+        user_id: serenity::UserId,
+        now: Instant,
+    ) -> Option<String> {
+        if let Some(guild_id) = guild_id {
+            let nick = self.nicknames.get(&(guild_id, user_id))?;
+            if !nick.is_fresh(now) {
+                return None;
+            }
+            if let Some(nick) = &nick.value {
+                return Some(nick.clone());
+            }
+        }
+        let user = self.users.get(&user_id)?;
+        user.is_fresh(now).then(|| user.value.clone())
+    }
+
+    /// Name to show for a user already at hand, remembering it for later lookups
+    pub fn display_name(
+        &self,
+        guild_id: Option<serenity::GuildId>,
+        user: &serenity::User,
+    ) -> String {
+        let now = Instant::now();
+        self.insert_user_at(user, now);
+        self.cached(guild_id, user.id, now)
+            .unwrap_or_else(|| user.display_name().to_string())
+    }
+
+    /// Name from serenity's own cache, copied into this one
+    fn serenity_cached(
+        &self,
+        ctx: &serenity::Context,
+        guild_id: Option<serenity::GuildId>,
+        user_id: serenity::UserId,
+    ) -> Option<String> {
+        match guild_id {
+            Some(guild_id) => {
+                let member = ctx.cache.guild(guild_id)?.members.get(&user_id).cloned()?;
+                self.insert_member(&member);
+                Some(member.display_name().to_string())
+            }
+            None => {
+                let user = ctx.cache.user(user_id)?.clone();
+                self.insert_user(&user);
+                Some(user.display_name().to_string())
+            }
+        }
+    }
+
+    /// Names of many users, by nickname in `guild_id` if given.
+    ///
+    /// Users missing from this cache are looked up in serenity's cache and then
+    /// fetched in batches. Users that can't be found are left out of the result.
+    pub async fn display_names(
+        &self,
+        ctx: &serenity::Context,
+        guild_id: Option<serenity::GuildId>,
+        user_ids: &[serenity::UserId],
+    ) -> HashMap<serenity::UserId, String> {
+        let now = Instant::now();
+        let mut names = HashMap::new();
+        let mut missing = Vec::new();
+        for &user_id in user_ids {
+            if let Some(name) = self.cached(guild_id, user_id, now) {
+                names.insert(user_id, name);
+            } else if let Some(name) = self.serenity_cached(ctx, guild_id, user_id) {
+                names.insert(user_id, name);
+            } else if !self.is_missing(user_id, now) {
+                missing.push(user_id);
+            }
+        }
+
+        for batch in missing.chunks(FETCH_BATCH) {
+            let mut fetches = tokio::task::JoinSet::new();
+            for &user_id in batch {
+                let http = ctx.http.clone();
+                fetches.spawn(async move { (user_id, fetch(&http, guild_id, user_id).await) });
+            }
+            while let Some(result) = fetches.join_next().await {
+                let (user_id, fetched) = match result {
+                    Ok(fetched) => fetched,
+                    Err(e) => {
+                        tracing::warn!("User fetch task failed: {e}");
+                        continue;
+                    }
+                };
+                let name = match fetched {
+                    Fetched::Member(member) => {
+                        self.insert_member(&member);
+                        member.display_name().to_string()
+                    }
+                    Fetched::User(user) => {
+                        self.insert_user(&user);
+                        if let Some(guild_id) = guild_id {
+                            self.insert_nickname(guild_id, user_id, None);
+                        }
+                        user.display_name().to_string()
+                    }
+                    Fetched::Missing => {
+                        self.insert_missing_at(user_id, Instant::now());
+                        continue;
+                    }
+                    Fetched::Failed => continue,
+                };
+                names.insert(user_id, name);
+            }
+        }
+        names
+    }
+}
+
+/// Look a user up through the API, as a member of `guild_id` if given
+async fn fetch(
+    http: &serenity::Http,
+    guild_id: Option<serenity::GuildId>,
+    user_id: serenity::UserId,
+) -> Fetched {
+    // Users who left the guild are still shown by their own name
+    if let Some(guild_id) = guild_id {
+        match guild_id.member(http, user_id).await {
+            Ok(member) => return Fetched::Member(Box::new(member)),
+            Err(e) if !is_not_found(&e) => {
+                tracing::debug!("Could not fetch member {user_id}: {e}");
+                return Fetched::Failed;
+            }
+            Err(_) => {}
+        }
+    }
+    match http.get_user(user_id).await {
+        Ok(user) => Fetched::User(Box::new(user)),
+        Err(e) => {
+            tracing::debug!("Could not fetch user {user_id}: {e}");
+            if is_not_found(&e) {
+                Fetched::Missing
+            } else {
+                Fetched::Failed
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to create a test user ID
+    fn test_user_id(id: u64) -> serenity::UserId {
+        serenity::UserId::new(id)
+    }
+
+    // Helper function to create a test guild ID
+    fn test_guild_id(id: u64) -> serenity::GuildId {
+        serenity::GuildId::new(id)
+    }
+
+    // Helper function to create a test user
+    fn test_user(id: u64, name: &str, global_name: Option<&str>) -> serenity::User {
+        let mut user = serenity::User::default();
+        user.id = test_user_id(id);
+        user.name = name.to_string();
+        user.global_name = global_name.map(str::to_string);
+        user
+    }
+
+    #[test]
+    fn test_cached_names() {
+        let cache = NameCache::new();
+        let guild = test_guild_id(1);
+        let now = Instant::now();
+
+        cache.insert_user_at(&test_user(1, "andy", Some("Andy")), now);
+        cache.insert_user_at(&test_user(2, "bob", None), now);
+        assert_eq!(
+            cache.cached(None, test_user_id(1), now).as_deref(),
+            Some("Andy")
+        );
+        assert_eq!(
+            cache.cached(None, test_user_id(2), now).as_deref(),
+            Some("bob")
+        );
+        assert_eq!(cache.cached(None, test_user_id(3), now), None);
+
+        // Server lookups need a nickname entry, and prefer the nickname
+        assert_eq!(cache.cached(Some(guild), test_user_id(1), now), None);
+        cache.insert_nickname_at(guild, test_user_id(1), Some("Andy C".to_string()), now);
+        cache.insert_nickname_at(guild, test_user_id(2), None, now);
+        assert_eq!(
+            cache.cached(Some(guild), test_user_id(1), now).as_deref(),
+            Some("Andy C")
+        );
+        assert_eq!(
+            cache.cached(Some(guild), test_user_id(2), now).as_deref(),
+            Some("bob")
+        );
+        assert_eq!(
+            cache.display_name(Some(guild), &test_user(1, "andy", None)),
+            "Andy C"
+        );
+
+        // Users the API couldn't find aren't fetched again until the TTL passes
+        assert!(!cache.is_missing(test_user_id(3), now));
+        cache.insert_missing_at(test_user_id(3), now);
+        assert!(cache.is_missing(test_user_id(3), now));
+        assert!(!cache.is_missing(test_user_id(3), now + NAME_TTL));
+
+        // Names expire after the TTL
+        let later = now + NAME_TTL;
+        assert_eq!(cache.cached(None, test_user_id(2), later), None);
+        assert_eq!(cache.cached(Some(guild), test_user_id(2), later), None);
+    }
+
+    #[test]
+    fn test_cache_is_bounded() {
+        let cache = NameCache::new();
+        let start = Instant::now();
+        for id in 1..=MAX_CACHED_NAMES as u64 {
+            let now = start + Duration::from_millis(id);
+            cache.insert_user_at(&test_user(id, "user", None), now);
+        }
+        assert_eq!(cache.users.len(), MAX_CACHED_NAMES);
+
+        // A new name evicts the oldest tenth
+        let now = start + Duration::from_secs(60);
+        let new_id = MAX_CACHED_NAMES as u64 + 1;
+        cache.insert_user_at(&test_user(new_id, "new", None), now);
+        assert_eq!(
+            cache.users.len(),
+            MAX_CACHED_NAMES - MAX_CACHED_NAMES / 10 + 1
+        );
+        assert_eq!(cache.cached(None, test_user_id(1), now), None);
+        assert_eq!(
+            cache.cached(None, test_user_id(new_id), now).as_deref(),
+            Some("new")
+        );
+        let newest = test_user_id(MAX_CACHED_NAMES as u64);
+        assert_eq!(cache.cached(None, newest, now).as_deref(), Some("user"));
+    }
+}
@@ -0,0 +1,297 @@
+//! Permission model for the bot.
+//!
+//! Every command decides who may use it through [`AccessLevel`], which combines the
+//! configured bot owners, the guild owner, the Discord administrator permission and
+//! the economy roles from the guild's [`GuildConfig`].
+
+use crate::{
+    Context, Error,
+    data::{GuildConfig, UserAccess},
+    i18n::{self, tr},
+};
+use poise::serenity_prelude as serenity;
+
+/// What a user may do in a guild, from least to most privileged
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccessLevel {
+    Member,
+    Giver,
+    EconomyAdmin,
+    Administrator,
+    GuildOwner,
+    BotOwner,
+}
+
+impl AccessLevel {
+    /// Description used when a user lacks the level
+    pub fn describe(self, locale: &str) -> String {
+        match self {
+            AccessLevel::Member => tr!(locale, "access-member"),
+            AccessLevel::Giver => tr!(locale, "access-giver"),
+            AccessLevel::EconomyAdmin => tr!(locale, "access-economy-admin"),
+            AccessLevel::Administrator => tr!(locale, "access-administrator"),
+            AccessLevel::GuildOwner => tr!(locale, "access-guild-owner"),
+            AccessLevel::BotOwner => tr!(locale, "access-bot-owner"),
+        }
+    }
+}
+
+/// The facts about a user that decide their access level
+pub struct Subject<'a> {
+    pub user_id: serenity::UserId,
+    pub roles: &'a [serenity::RoleId],
+    pub is_bot_owner: bool,
+    pub is_guild_owner: bool,
+    pub is_administrator: bool,
+}
+
+/// Resolve a user's access level from the guild config
+pub fn resolve(config: Option<&GuildConfig>, subject: &Subject<'_>) -> AccessLevel {
+    if subject.is_bot_owner {
+        AccessLevel::BotOwner
+    } else if subject.is_guild_owner {
+        AccessLevel::GuildOwner
+    } else if subject.is_administrator {
+        AccessLevel::Administrator
+    } else if config.is_some_and(|c| c.is_economy_admin(subject.roles)) {
+        AccessLevel::EconomyAdmin
+    } else if config.is_some_and(|c| c.can_give(subject.user_id, subject.roles)) {
+        AccessLevel::Giver
+    } else {
+        AccessLevel::Member
+    }
+}
+
+/// Check if a user may give coins.
+///
+/// Bot and guild owners always can. Everyone else is subject to the guild's
+/// denylist and allowlist before their access level is considered.
+pub fn can_give(config: Option<&GuildConfig>, subject: &Subject<'_>) -> bool {
+    if subject.is_bot_owner || subject.is_guild_owner {
+        return true;
+    }
+    match config.and_then(|c| c.user_access(subject.user_id)) {
+        Some(UserAccess::Deny) => false,
+        Some(UserAccess::Allow) => true,
+        None => resolve(config, subject) >= AccessLevel::Giver,
+    }
+}
+
+/// Gather the facts about the command author and run `f` on them with the guild config
+async fn with_author<T>(
+    ctx: Context<'_>,
+    f: impl FnOnce(Option<&GuildConfig>, &Subject<'_>) -> T,
+) -> T {
+    let user_id = ctx.author().id;
+    let is_bot_owner = ctx.framework().options().owners.contains(&user_id);
+
+    let member = ctx.author_member().await;
+    let roles = member.as_ref().map(|m| m.roles.clone()).unwrap_or_default();
+
+    let is_guild_owner = match ctx.partial_guild().await {
+        Some(guild) => guild.owner_id == user_id,
+        None => false,
+    };
+
+    // Interactions carry the member's resolved permissions, otherwise use the cache
+    let is_administrator = member.as_ref().is_some_and(|m| {
+        m.permissions
+            .or_else(|| ctx.guild().map(|g| g.member_permissions(m)))
+            .is_some_and(|p| p.administrator())
+    });
+
+    let subject = Subject {
+        user_id,
+        roles: &roles,
+        is_bot_owner,
+        is_guild_owner,
+        is_administrator,
+    };
+
+    let config = ctx
+        .guild_id()
+        .and_then(|guild_id| ctx.data().guild_configs.get(&guild_id));
+    f(config.as_deref(), &subject)
+}
+
+/// Get the command author's access level
+pub async fn access_level(ctx: Context<'_>) -> AccessLevel {
+    with_author(ctx, resolve).await
+}
+
+/// Check if the command author may give coins
+pub async fn author_can_give(ctx: Context<'_>) -> bool {
+    with_author(ctx, can_give).await
+}
+
+/// Require an access level, telling the author if they lack it
+pub async fn require(ctx: Context<'_>, level: AccessLevel) -> Result<bool, Error> {
+    if access_level(ctx).await >= level {
+        return Ok(true);
+    }
+
+    let locale = i18n::locale(ctx);
+    ctx.send(
+        poise::CreateReply::default()
+            .content(tr!(
+                locale,
+                "access-required",
+                level = level.describe(locale)
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(false)
+}
+
+/// Command check requiring an economy admin or above
+pub async fn economy_admin_check(ctx: Context<'_>) -> Result<bool, Error> {
+    require(ctx, AccessLevel::EconomyAdmin).await
+}
+
+/// Command check requiring a server administrator or above
+pub async fn administrator_check(ctx: Context<'_>) -> Result<bool, Error> {
+    require(ctx, AccessLevel::Administrator).await
+}
+
+/// Parse bot owner IDs from a comma separated list, skipping invalid entries
+pub fn parse_owners(value: &str) -> std::collections::HashSet<serenity::UserId> {
+    value
+        .split(',')
+        .filter_map(|id| id.trim().parse::<u64>().ok())
+        .filter(|id| *id != 0)
+        .map(serenity::UserId::new)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Data;
+    use crate::data::EconomyRole;
+
+    // Helper function to create a test user ID
+    fn test_user_id(id: u64) -> serenity::UserId {
+        serenity::UserId::new(id)
+    }
+
+    // Helper function to create a test role ID
+    fn test_role_id(id: u64) -> serenity::RoleId {
+        serenity::RoleId::new(id)
+    }
+
+    // Helper function to create a subject with no special status
+    fn member(user_id: u64, roles: &[serenity::RoleId]) -> Subject<'_> {
+        Subject {
+            user_id: test_user_id(user_id),
+            roles,
+            is_bot_owner: false,
+            is_guild_owner: false,
+            is_administrator: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_access_level() {
+        let data = Data::new();
+        let guild_id = serenity::GuildId::new(1);
+        let giver_role = [test_role_id(10)];
+        let admin_role = [test_role_id(20)];
+
+        data.add_economy_role(guild_id, EconomyRole::Giver, giver_role[0]);
+        data.add_economy_role(guild_id, EconomyRole::Admin, admin_role[0]);
+        let config = data.guild_configs.get(&guild_id);
+        let config = config.as_deref();
+
+        assert_eq!(resolve(config, &member(1, &[])), AccessLevel::Member);
+        assert_eq!(resolve(config, &member(1, &giver_role)), AccessLevel::Giver);
+        assert_eq!(
+            resolve(config, &member(1, &admin_role)),
+            AccessLevel::EconomyAdmin
+        );
+        assert_eq!(
+            resolve(
+                config,
+                &Subject {
+                    is_administrator: true,
+                    ..member(1, &[])
+                }
+            ),
+            AccessLevel::Administrator
+        );
+        assert_eq!(
+            resolve(
+                config,
+                &Subject {
+                    is_guild_owner: true,
+                    ..member(1, &[])
+                }
+            ),
+            AccessLevel::GuildOwner
+        );
+        assert_eq!(
+            resolve(
+                None,
+                &Subject {
+                    is_bot_owner: true,
+                    ..member(1, &[])
+                }
+            ),
+            AccessLevel::BotOwner
+        );
+    }
+
+    #[test]
+    fn test_can_give() {
+        let data = Data::new();
+        let guild_id = serenity::GuildId::new(1);
+        let giver_role = [test_role_id(10)];
+
+        data.add_economy_role(guild_id, EconomyRole::Giver, giver_role[0]);
+        data.set_user_access(guild_id, test_user_id(2), Some(UserAccess::Deny));
+        data.set_user_access(guild_id, test_user_id(3), Some(UserAccess::Allow));
+        let config = data.guild_configs.get(&guild_id);
+        let config = config.as_deref();
+
+        // Without a config only owners can give
+        assert!(!can_give(None, &member(1, &giver_role)));
+        assert!(can_give(
+            None,
+            &Subject {
+                is_guild_owner: true,
+                ..member(1, &[])
+            }
+        ));
+
+        assert!(can_give(config, &member(1, &giver_role)));
+        assert!(!can_give(config, &member(1, &[])));
+
+        // The denylist overrides roles and administrator, but not ownership
+        assert!(!can_give(config, &member(2, &giver_role)));
+        assert!(!can_give(
+            config,
+            &Subject {
+                is_administrator: true,
+                ..member(2, &[])
+            }
+        ));
+        assert!(can_give(
+            config,
+            &Subject {
+                is_guild_owner: true,
+                ..member(2, &[])
+            }
+        ));
+
+        // The allowlist works without roles
+        assert!(can_give(config, &member(3, &[])));
+    }
+
+    #[test]
+    fn test_parse_owners() {
+        let owners = parse_owners("123, 456,abc,,0");
+        assert_eq!(owners.len(), 2);
+        assert!(owners.contains(&test_user_id(123)));
+        assert!(owners.contains(&test_user_id(456)));
+    }
+}
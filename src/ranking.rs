@@ -0,0 +1,550 @@
+//! Ordered balance index for leaderboards and rank lookups.
+//!
+//! Every balance change updates a per-guild board and a global board of totals, so
+//! leaderboard pages and rank queries don't sort the balance maps. Each board keeps
+//! its users in an order-statistic treap sorted richest first (ties by user ID), and
+//! the distinct balances in a second treap for dense ranks. Pages cost
+//! `O(log n + page size)` and rank lookups `O(log n)`.
+//!
+//! This module only depends on serenity's ID types so the benchmarks can include it.
+
+use poise::serenity_prelude as serenity;
+use std::{cmp::Reverse, collections::HashMap};
+
+/// A user's place in a ranking. Equal balances share a rank, and the next lower
+/// balance gets the next rank (dense ranking).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RankedUser {
+    pub user_id: serenity::UserId,
+    pub balance: u32,
+    pub rank: usize,
+}
+
+/// Where a user stands in a ranking
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RankInfo {
+    pub rank: usize,
+    pub balance: u32,
+    /// Number of users in the ranking
+    pub ranked_users: usize,
+    /// Share of users with a lower balance, in percent
+    pub percentile: f64,
+    /// Coins needed to reach the next rank up, unless already first
+    pub to_next_rank: Option<u32>,
+}
+
+/// Balances in the index are summed across guilds, so they may not fit a `u32`
+fn clamp(balance: u64) -> u32 {
+    u32::try_from(balance).unwrap_or(u32::MAX)
+}
+
+struct Node<K> {
+    key: K,
+    priority: u64,
+    left: Option<usize>,
+    right: Option<usize>,
+    size: usize,
+}
+
+/// A sorted set that can also find the `n`th key and count the keys below a bound,
+/// both in `O(log n)` expected time. Nodes live in an arena and are reused.
+pub struct OrderedSet<K> {
+    nodes: Vec<Node<K>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    // State of the xorshift generator for node priorities
+    seed: u64,
+}
+
+impl<K: Ord + Clone> Default for OrderedSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone> OrderedSet<K> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn size(&self, node: Option<usize>) -> usize {
+        node.map_or(0, |node| self.nodes[node].size)
+    }
+
+    fn update(&mut self, node: usize) {
+        let (left, right) = (self.nodes[node].left, self.nodes[node].right);
+        self.nodes[node].size = 1 + self.size(left) + self.size(right);
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    /// Split a subtree into keys below `key` (or up to it, if `inclusive`) and the rest
+    fn split(
+        &mut self,
+        node: Option<usize>,
+        key: &K,
+        inclusive: bool,
+    ) -> (Option<usize>, Option<usize>) {
+        let Some(node) = node else {
+            return (None, None);
+        };
+        let goes_left = if inclusive {
+            self.nodes[node].key <= *key
+        } else {
+            self.nodes[node].key < *key
+        };
+        if goes_left {
+            let (left, right) = self.split(self.nodes[node].right, key, inclusive);
+            self.nodes[node].right = left;
+            self.update(node);
+            (Some(node), right)
+        } else {
+            let (left, right) = self.split(self.nodes[node].left, key, inclusive);
+            self.nodes[node].left = right;
+            self.update(node);
+            (left, Some(node))
+        }
+    }
+
+    /// Join two subtrees where every key in `left` is below every key in `right`
+    fn merge(&mut self, left: Option<usize>, right: Option<usize>) -> Option<usize> {
+        match (left, right) {
+            (None, tree) | (tree, None) => tree,
+            (Some(left), Some(right)) => {
+                if self.nodes[left].priority > self.nodes[right].priority {
+                    self.nodes[left].right = self.merge(self.nodes[left].right, Some(right));
+                    self.update(left);
+                    Some(left)
+                } else {
+                    self.nodes[right].left = self.merge(Some(left), self.nodes[right].left);
+                    self.update(right);
+                    Some(right)
+                }
+            }
+        }
+    }
+
+    /// Add a key. Returns `false` if it was already present.
+    pub fn insert(&mut self, key: K) -> bool {
+        let (left, right) = self.split(self.root, &key, false);
+        let (equal, right) = self.split(right, &key, true);
+        if equal.is_some() {
+            let middle = self.merge(left, equal);
+            self.root = self.merge(middle, right);
+            return false;
+        }
+
+        let node = Node {
+            key,
+            priority: self.next_priority(),
+            left: None,
+            right: None,
+            size: 1,
+        };
+        let node = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = node;
+                slot
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        let middle = self.merge(left, Some(node));
+        self.root = self.merge(middle, right);
+        true
+    }
+
+    /// Remove a key. Returns `false` if it wasn't present.
+    pub fn remove(&mut self, key: &K) -> bool {
+        let (left, right) = self.split(self.root, key, false);
+        let (equal, right) = self.split(right, key, true);
+        if let Some(node) = equal {
+            self.free.push(node);
+        }
+        self.root = self.merge(left, right);
+        equal.is_some()
+    }
+
+    /// Number of keys below `key`
+    #[must_use]
+    pub fn count_below(&self, key: &K) -> usize {
+        let mut count = 0;
+        let mut node = self.root;
+        while let Some(current) = node {
+            let current = &self.nodes[current];
+            if current.key < *key {
+                count += 1 + self.size(current.left);
+                node = current.right;
+            } else {
+                node = current.left;
+            }
+        }
+        count
+    }
+
+    /// The key at `index` in sorted order
+    #[must_use]
+    pub fn nth(&self, mut index: usize) -> Option<&K> {
+        let mut node = self.root;
+        while let Some(current) = node {
+            let current = &self.nodes[current];
+            let left_size = self.size(current.left);
+            match index.cmp(&left_size) {
+                std::cmp::Ordering::Less => node = current.left,
+                std::cmp::Ordering::Equal => return Some(&current.key),
+                std::cmp::Ordering::Greater => {
+                    index -= left_size + 1;
+                    node = current.right;
+                }
+            }
+        }
+        None
+    }
+
+    /// Up to `count` keys in sorted order, starting at `start`
+    #[must_use]
+    pub fn range(&self, start: usize, count: usize) -> Vec<K> {
+        let mut keys = Vec::with_capacity(count.min(self.len().saturating_sub(start)));
+        self.collect(self.root, start, start.saturating_add(count), 0, &mut keys);
+        keys
+    }
+
+    /// Collect the keys of a subtree whose sorted positions fall in `start..end`.
+    /// `offset` is the position of the subtree's first key.
+    fn collect(
+        &self,
+        node: Option<usize>,
+        start: usize,
+        end: usize,
+        offset: usize,
+        keys: &mut Vec<K>,
+    ) {
+        let Some(node) = node else {
+            return;
+        };
+        let current = &self.nodes[node];
+        let position = offset + self.size(current.left);
+        if start < position {
+            self.collect(current.left, start, end, offset, keys);
+        }
+        if (start..end).contains(&position) {
+            keys.push(current.key.clone());
+        }
+        if position + 1 < end {
+            self.collect(current.right, start, end, position + 1, keys);
+        }
+    }
+}
+
+/// Users ordered by balance, richest first
+#[derive(Default)]
+pub struct Board {
+    users: OrderedSet<(Reverse<u64>, serenity::UserId)>,
+    balances: HashMap<serenity::UserId, u64>,
+    // Distinct balances, with the number of users holding each
+    distinct: OrderedSet<Reverse<u64>>,
+    holders: HashMap<u64, usize>,
+}
+
+impl Board {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    #[must_use]
+    pub fn balance(&self, user_id: serenity::UserId) -> Option<u64> {
+        self.balances.get(&user_id).copied()
+    }
+
+    /// Set a user's balance, or take them off the board with `None`
+    pub fn set(&mut self, user_id: serenity::UserId, balance: Option<u64>) {
+        let previous = match balance {
+            Some(balance) => self.balances.insert(user_id, balance),
+            None => self.balances.remove(&user_id),
+        };
+        if previous == balance {
+            return;
+        }
+        if let Some(previous) = previous {
+            self.users.remove(&(Reverse(previous), user_id));
+            if let Some(holders) = self.holders.get_mut(&previous) {
+                *holders -= 1;
+                if *holders == 0 {
+                    self.holders.remove(&previous);
+                    self.distinct.remove(&Reverse(previous));
+                }
+            }
+        }
+        if let Some(balance) = balance {
+            self.users.insert((Reverse(balance), user_id));
+            let holders = self.holders.entry(balance).or_default();
+            if *holders == 0 {
+                self.distinct.insert(Reverse(balance));
+            }
+            *holders += 1;
+        }
+    }
+
+    /// Dense rank of a balance held on this board
+    fn rank_of(&self, balance: u64) -> usize {
+        self.distinct.count_below(&Reverse(balance)) + 1
+    }
+
+    /// Up to `count` users starting at position `start`, richest first
+    #[must_use]
+    pub fn page(&self, start: usize, count: usize) -> Vec<RankedUser> {
+        self.users
+            .range(start, count)
+            .into_iter()
+            .map(|(Reverse(balance), user_id)| RankedUser {
+                user_id,
+                balance: clamp(balance),
+                rank: self.rank_of(balance),
+            })
+            .collect()
+    }
+
+    /// Look up a user's rank, percentile and distance to the next rank
+    #[must_use]
+    pub fn rank_info(&self, user_id: serenity::UserId) -> Option<RankInfo> {
+        let balance = self.balance(user_id)?;
+        let rank = self.rank_of(balance);
+        // Users with a lower balance sort after everyone holding `balance`
+        let below = match balance.checked_sub(1) {
+            Some(lower) => {
+                self.len()
+                    - self
+                        .users
+                        .count_below(&(Reverse(lower), serenity::UserId::new(1)))
+            }
+            None => 0,
+        };
+        let to_next_rank = rank
+            .checked_sub(2)
+            .and_then(|index| self.distinct.nth(index))
+            .map(|Reverse(next)| clamp(next - balance));
+
+        Some(RankInfo {
+            rank,
+            balance: clamp(balance),
+            ranked_users: self.len(),
+            percentile: below as f64 / self.len() as f64 * 100.0,
+            to_next_rank,
+        })
+    }
+}
+
+/// Boards for every guild and for totals across guilds
+#[derive(Default)]
+pub struct RankingIndex {
+    guilds: HashMap<serenity::GuildId, Board>,
+    global: Board,
+    // Map of user_id -> (total balance, number of guilds with a balance)
+    totals: HashMap<serenity::UserId, (u64, usize)>,
+}
+
+impl RankingIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a balance change. `None` means the user has no balance entry.
+    pub fn update(
+        &mut self,
+        guild_id: serenity::GuildId,
+        user_id: serenity::UserId,
+        previous: Option<u32>,
+        balance: Option<u32>,
+    ) {
+        if previous == balance {
+            return;
+        }
+        let board = self.guilds.entry(guild_id).or_default();
+        board.set(user_id, balance.map(u64::from));
+        if board.is_empty() {
+            self.guilds.remove(&guild_id);
+        }
+
+        let (total, guilds) = self.totals.entry(user_id).or_default();
+        *total = *total - previous.map_or(0, u64::from) + balance.map_or(0, u64::from);
+        *guilds = *guilds + usize::from(balance.is_some()) - usize::from(previous.is_some());
+        let total = if *guilds == 0 {
+            self.totals.remove(&user_id);
+            None
+        } else {
+            Some(*total)
+        };
+        self.global.set(user_id, total);
+    }
+
+    /// Drop every balance, as after a data reset
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// A guild's board, or the global one for `None`
+    #[must_use]
+    pub fn board(&self, guild_id: Option<serenity::GuildId>) -> Option<&Board> {
+        match guild_id {
+            Some(guild_id) => self.guilds.get(&guild_id),
+            None => Some(&self.global),
+        }
+    }
+
+    /// A user's total balance across all guilds
+    #[must_use]
+    pub fn total(&self, user_id: serenity::UserId) -> u32 {
+        self.totals
+            .get(&user_id)
+            .map_or(0, |&(total, _)| clamp(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to create a test user ID
+    fn test_user_id(id: u64) -> serenity::UserId {
+        serenity::UserId::new(id)
+    }
+
+    // Helper function to create a test guild ID
+    fn test_guild_id(id: u64) -> serenity::GuildId {
+        serenity::GuildId::new(id)
+    }
+
+    #[test]
+    fn test_ordered_set() {
+        let mut set = OrderedSet::new();
+        for key in [50, 10, 40, 20, 30] {
+            assert!(set.insert(key));
+        }
+        assert!(!set.insert(30));
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.range(0, 10), vec![10, 20, 30, 40, 50]);
+        assert_eq!(set.range(1, 2), vec![20, 30]);
+        assert_eq!(set.range(5, 2), Vec::<i32>::new());
+        assert_eq!(set.nth(3), Some(&40));
+        assert_eq!(set.nth(5), None);
+        assert_eq!(set.count_below(&35), 3);
+        assert_eq!(set.count_below(&5), 0);
+
+        // Removed slots are reused
+        assert!(set.remove(&20));
+        assert!(!set.remove(&20));
+        assert!(set.insert(25));
+        assert_eq!(set.range(0, 10), vec![10, 25, 30, 40, 50]);
+        assert_eq!(set.nodes.len(), 5);
+
+        // Matches a sort on many keys
+        let mut set = OrderedSet::new();
+        let keys: Vec<u64> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+        for &key in &keys {
+            set.insert(key);
+        }
+        for key in keys.iter().filter(|key| *key % 3 == 0) {
+            set.remove(key);
+        }
+        let expected: Vec<u64> = (0..1000).filter(|key| key % 3 != 0).collect();
+        assert_eq!(set.range(0, usize::MAX), expected);
+        assert_eq!(
+            set.count_below(&500),
+            expected.iter().filter(|&&key| key < 500).count()
+        );
+    }
+
+    #[test]
+    fn test_board_ranks() {
+        let mut board = Board::default();
+        board.set(test_user_id(1), Some(100));
+        board.set(test_user_id(2), Some(80));
+        board.set(test_user_id(3), Some(80));
+        board.set(test_user_id(4), Some(50));
+
+        let page = board.page(0, 10);
+        let ranks: Vec<_> = page.iter().map(|user| (user.user_id, user.rank)).collect();
+        assert_eq!(
+            ranks,
+            vec![
+                (test_user_id(1), 1),
+                (test_user_id(2), 2),
+                (test_user_id(3), 2),
+                (test_user_id(4), 3)
+            ]
+        );
+        assert_eq!(board.page(2, 1)[0].user_id, test_user_id(3));
+
+        let info = board.rank_info(test_user_id(3)).unwrap();
+        assert_eq!(info.rank, 2);
+        assert_eq!(info.to_next_rank, Some(20));
+        assert!((info.percentile - 25.0).abs() < 1e-9);
+
+        // Moving a user updates the ranks around them
+        board.set(test_user_id(2), Some(120));
+        assert_eq!(board.rank_info(test_user_id(2)).unwrap().rank, 1);
+        assert_eq!(board.rank_info(test_user_id(3)).unwrap().rank, 3);
+        board.set(test_user_id(2), None);
+        assert_eq!(board.len(), 3);
+        assert_eq!(board.rank_info(test_user_id(2)), None);
+        assert_eq!(board.rank_info(test_user_id(3)).unwrap().rank, 2);
+    }
+
+    #[test]
+    fn test_ranking_index() {
+        let mut index = RankingIndex::new();
+        let guild1 = test_guild_id(1);
+        let guild2 = test_guild_id(2);
+
+        index.update(guild1, test_user_id(1), None, Some(100));
+        index.update(guild2, test_user_id(1), None, Some(50));
+        index.update(guild1, test_user_id(2), None, Some(120));
+        assert_eq!(index.total(test_user_id(1)), 150);
+
+        let global = index.board(None).unwrap();
+        assert_eq!(global.page(0, 1)[0].user_id, test_user_id(1));
+        assert_eq!(index.board(Some(guild1)).unwrap().len(), 2);
+
+        // Users stay ranked with a zero balance until their entry is gone
+        index.update(guild2, test_user_id(1), Some(50), Some(0));
+        assert_eq!(index.total(test_user_id(1)), 100);
+        index.update(guild1, test_user_id(1), Some(100), None);
+        index.update(guild2, test_user_id(1), Some(0), None);
+        assert_eq!(index.total(test_user_id(1)), 0);
+        assert_eq!(index.board(None).unwrap().len(), 1);
+        assert!(index.board(Some(guild2)).is_none());
+
+        index.clear();
+        assert!(index.board(None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+//! Background worker that applies reward roles in Discord.
+//!
+//! Every balance change queues the reward roles whose thresholds it crossed (see
+//! [`DataInner::record_balance_change`](crate::data::DataInner::record_balance_change)).
+//! The worker takes the queue in batches and pauses between them, so a burst such
+//! as a reset or `/config_admin reward sync` stays well inside Discord's rate limits.
+//! Changes to the same member's role that pile up in the meantime collapse into
+//! the latest one. The queue isn't saved; `/config_admin reward sync` catches up on
+//! changes lost to a restart.
+
+use crate::{Data, data::RoleChange};
+use poise::serenity_prelude as serenity;
+use std::{sync::Arc, time::Duration};
+
+/// Role changes made before pausing
+const BATCH_SIZE: usize = 10;
+
+/// Pause between batches
+const BATCH_PAUSE: Duration = Duration::from_secs(2);
+
+/// Start the reward worker task
+pub fn spawn(http: Arc<serenity::Http>, data: Data) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            // Register for wakeups before taking changes so none are missed
+            let wakeup = data.reward_wakeup.notified();
+
+            let batch = data.take_role_changes(BATCH_SIZE);
+            if batch.is_empty() {
+                wakeup.await;
+                continue;
+            }
+            for change in &batch {
+                apply(&http, change).await;
+            }
+            tokio::time::sleep(BATCH_PAUSE).await;
+        }
+    })
+}
+
+/// Add or remove one reward role
+async fn apply(http: &serenity::Http, change: &RoleChange) {
+    let RoleChange {
+        guild_id,
+        user_id,
+        role_id,
+        add,
+    } = *change;
+    let result = if add {
+        http.add_member_role(guild_id, user_id, role_id, Some("Balance reward"))
+            .await
+    } else {
+        http.remove_member_role(guild_id, user_id, role_id, Some("Balance reward"))
+            .await
+    };
+    if let Err(e) = result {
+        tracing::warn!(
+            "Failed to {} reward role {} for user {} in guild {}: {}",
+            if add { "add" } else { "remove" },
+            role_id,
+            user_id,
+            guild_id,
+            e
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Background scheduler for timed events.
+//!
+//! A single task sleeps until the earliest open proposal ends or is due a reminder,
+//! or until it is woken through [`DataInner::scheduler_wakeup`](crate::data::DataInner)
+//! because a vote started. Reminders ping the configured role in the vote's channel
+//! and DM opted-in members who haven't voted. Expired votes are ended, the data is
+//! saved, the vote message shows the result with its buttons disabled, and the
+//! result is posted in the channel where the vote was started.
+//!
+//! Scheduled leaderboard posts are due at the next run of their guild's cron spec.
+//! They are marked as run and their standings saved before posting, so a restart
+//! never posts the same run twice.
+//!
+//! Seasons end the same way: the final standings are archived, balances reset and
+//! the data saved, and only then does the award role move to the new winners and
+//! the standings get announced.
+
+use crate::{
+    Data, commands,
+    data::{ExpiredVote, SeasonEnd, VoteReminder},
+    i18n::{self, tr},
+    vote_message,
+    voting::ProposalAction,
+};
+use poise::serenity_prelude as serenity;
+
+/// Start the scheduler task
+pub fn spawn(ctx: serenity::Context, data: Data) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            // Register for wakeups before looking at deadlines so none are missed
+            let wakeup = data.scheduler_wakeup.notified();
+
+            run_due(&ctx, &data).await;
+
+            let deadline = data
+                .next_vote_deadline()
+                .into_iter()
+                .chain(data.next_leaderboard_post())
+                .chain(data.next_season_end())
+                .min();
+            match deadline {
+                Some(deadline) => {
+                    let wait = (deadline - chrono::Utc::now()).to_std().unwrap_or_default();
+                    tokio::select! {
+                        () = tokio::time::sleep(wait) => {}
+                        () = wakeup => {}
+                    }
+                }
+                None => wakeup.await,
+            }
+        }
+    })
+}
+
+/// Send due reminders, end expired votes and seasons, save and announce the
+/// results, then post scheduled leaderboards
+async fn run_due(ctx: &serenity::Context, data: &Data) {
+    let http = &ctx.http;
+    let now = chrono::Utc::now();
+    let reminders = data.due_reminders(now);
+    let expired = data.expire_votes(now);
+    let posts = data.due_leaderboard_posts(now);
+
+    // Render the leaderboards before saving, so their standings are saved with them,
+    // and before seasons end, so a post due with the end shows the final standings
+    let mut rendered = Vec::new();
+    for post in &posts {
+        let locale = i18n::guild_locale(data, post.guild_id);
+        let standings = commands::leaderboard::render_standings(
+            ctx,
+            data,
+            locale,
+            post.guild_id,
+            &post.previous,
+            post.previous_run,
+        )
+        .await;
+        if let Some((embed, standings)) = standings {
+            data.set_leaderboard_standings(post.guild_id, standings);
+            rendered.push((post.channel_id, embed));
+        }
+    }
+
+    let seasons = data.end_due_seasons(now);
+    if reminders.is_empty() && expired.is_empty() && posts.is_empty() && seasons.is_empty() {
+        return;
+    }
+
+    // Saved first so a restart doesn't send the reminders again
+    if let Err(e) = data.save().await {
+        tracing::error!("Failed to save data after running due votes: {}", e);
+    }
+
+    for reminder in &reminders {
+        tracing::info!(
+            "Reminding guild {} of proposal #{} ({} DMs)",
+            reminder.guild_id,
+            reminder.proposal_id,
+            reminder.user_ids.len()
+        );
+        remind(http, data, reminder).await;
+    }
+
+    for vote in &expired {
+        tracing::info!(
+            "Proposal #{} in guild {} ended: {} ({} yes / {} no)",
+            vote.status.proposal_id,
+            vote.guild_id,
+            if vote.passed { "passed" } else { "failed" },
+            vote.tally.yes_votes,
+            vote.tally.no_votes
+        );
+        publish_result(http, data, vote).await;
+    }
+
+    for end in &seasons {
+        tracing::info!(
+            "Season {} in guild {} ended with {} winners",
+            end.record.number,
+            end.guild_id,
+            end.record.awarded.len()
+        );
+        award_season(http, end).await;
+        announce_season(ctx, data, end).await;
+    }
+
+    for (channel_id, embed) in rendered {
+        let builder = serenity::CreateMessage::new().embed(embed);
+        if let Err(e) = channel_id.send_message(http, builder).await {
+            tracing::warn!(
+                "Failed to post scheduled leaderboard in channel {}: {}",
+                channel_id,
+                e
+            );
+        }
+    }
+}
+
+/// Move the season's award role from the previous winners to the new ones
+async fn award_season(http: &serenity::Http, end: &SeasonEnd) {
+    let reason = format!("Season {} ended", end.record.number);
+    let award_role = end.record.award_role.map(serenity::RoleId::new);
+    let awarded: Vec<_> = end
+        .record
+        .awarded
+        .iter()
+        .map(|&id| serenity::UserId::new(id))
+        .collect();
+
+    if let Some((role_id, previous)) = &end.previous_award {
+        for &user_id in previous {
+            // Winners who keep the same role aren't touched
+            if award_role == Some(*role_id) && awarded.contains(&user_id) {
+                continue;
+            }
+            if let Err(e) = http
+                .remove_member_role(end.guild_id, user_id, *role_id, Some(&reason))
+                .await
+            {
+                tracing::warn!(
+                    "Failed to remove season role {} from user {} in guild {}: {}",
+                    role_id,
+                    user_id,
+                    end.guild_id,
+                    e
+                );
+            }
+        }
+    }
+
+    let Some(role_id) = award_role else {
+        return;
+    };
+    for &user_id in &awarded {
+        if let Err(e) = http
+            .add_member_role(end.guild_id, user_id, role_id, Some(&reason))
+            .await
+        {
+            tracing::warn!(
+                "Failed to award season role {} to user {} in guild {}: {}",
+                role_id,
+                user_id,
+                end.guild_id,
+                e
+            );
+        }
+    }
+}
+
+/// Post a season's final standings in its announcement channel, if it has one
+async fn announce_season(ctx: &serenity::Context, data: &Data, end: &SeasonEnd) {
+    let Some(channel_id) = end.channel_id else {
+        return;
+    };
+    let locale = i18n::guild_locale(data, end.guild_id);
+    let embed = commands::seasons::render_season_end(ctx, data, locale, end).await;
+    let builder = serenity::CreateMessage::new().embed(embed);
+    if let Err(e) = channel_id.send_message(&ctx.http, builder).await {
+        tracing::warn!(
+            "Failed to announce the end of season {} in channel {}: {}",
+            end.record.number,
+            channel_id,
+            e
+        );
+    }
+}
+
+/// Ping the reminder role in the vote's channel and DM the opted-in members
+async fn remind(http: &serenity::Http, data: &Data, reminder: &VoteReminder) {
+    let locale = i18n::guild_locale(data, reminder.guild_id);
+    let currency = data.get_currency(Some(reminder.guild_id));
+    let action = reminder.action.describe(locale, &currency);
+    // Discord renders the relative time in each reader's own clock
+    let end_time = format!("<t:{}:R>", reminder.end_time.timestamp());
+
+    if let Some(channel_id) = reminder.channel_id {
+        let message = tr!(
+            locale,
+            "vote-reminder",
+            id = reminder.proposal_id,
+            action = action.as_str(),
+            end_time = end_time.as_str(),
+        );
+        let (content, mentions) = match reminder.role_id {
+            Some(role_id) => (
+                format!("<@&{role_id}> {message}"),
+                serenity::CreateAllowedMentions::new().roles([role_id]),
+            ),
+            None => (message, serenity::CreateAllowedMentions::new()),
+        };
+        let builder = serenity::CreateMessage::new()
+            .content(content)
+            .allowed_mentions(mentions);
+        if let Err(e) = channel_id.send_message(http, builder).await {
+            tracing::warn!(
+                "Failed to send vote reminder in channel {}: {}",
+                channel_id,
+                e
+            );
+        }
+    }
+
+    let link = match (reminder.channel_id, reminder.message_id) {
+        (Some(channel_id), Some(message_id)) => {
+            message_id.link(channel_id, Some(reminder.guild_id))
+        }
+        (Some(channel_id), None) => format!("<#{channel_id}>"),
+        _ => String::new(),
+    };
+    let dm = tr!(
+        locale,
+        "vote-reminder-dm",
+        id = reminder.proposal_id,
+        action = action.as_str(),
+        end_time = end_time.as_str(),
+        link = link,
+    );
+    for user_id in &reminder.user_ids {
+        // Members with DMs closed simply miss the reminder
+        let builder = serenity::CreateMessage::new()
+            .content(dm.as_str())
+            .allowed_mentions(serenity::CreateAllowedMentions::new());
+        if let Err(e) = user_id.direct_message(http, builder).await {
+            tracing::debug!("Failed to DM vote reminder to {}: {}", user_id, e);
+        }
+    }
+}
+
+/// Show a finished vote's result in its message and its channel
+pub async fn publish_result(http: &serenity::Http, data: &Data, vote: &ExpiredVote) {
+    close_message(http, data, vote).await;
+    announce(http, data, vote).await;
+}
+
+/// Show the result in the vote message and disable its buttons
+async fn close_message(http: &serenity::Http, data: &Data, vote: &ExpiredVote) {
+    let status = &vote.status;
+    let locale = i18n::guild_locale(data, vote.guild_id);
+    let config = data.get_effective_vote_config(vote.guild_id, status.action.kind());
+    let currency = data.get_currency(Some(vote.guild_id));
+    let content =
+        vote_message::render_result(locale, status, &config, &currency, &vote.tally, vote.passed);
+    vote_message::edit(http, locale, status, content, true).await;
+}
+
+/// Post a vote result in the channel where the vote was started
+async fn announce(http: &serenity::Http, data: &Data, vote: &ExpiredVote) {
+    let status = &vote.status;
+    let Some(channel_id) = status.channel_id.map(serenity::ChannelId::new) else {
+        return;
+    };
+
+    let locale = i18n::guild_locale(data, vote.guild_id);
+    let currency = data.get_currency(Some(vote.guild_id));
+    let message = match &status.action {
+        ProposalAction::Poll { question } => tr!(
+            locale,
+            "proposal-result-poll",
+            id = status.proposal_id,
+            question = question.as_str(),
+            yes = vote.tally.yes_votes,
+            no = vote.tally.no_votes,
+        ),
+        action => tr!(
+            locale,
+            if vote.passed {
+                "proposal-result-passed"
+            } else {
+                "proposal-result-failed"
+            },
+            id = status.proposal_id,
+            action = action.describe(locale, &currency),
+            yes = vote.tally.yes_votes,
+            no = vote.tally.no_votes,
+        ),
+    };
+    let config = data.get_effective_vote_config(vote.guild_id, status.action.kind());
+    let message = match vote_message::weighted_tally(locale, &config, &vote.tally) {
+        Some(weighted) => format!("{message}\n{weighted}"),
+        None => message,
+    };
+
+    // Poll questions are written by members, so they mustn't ping anyone
+    let builder = serenity::CreateMessage::new()
+        .content(message)
+        .allowed_mentions(serenity::CreateAllowedMentions::new());
+    if let Err(e) = channel_id.send_message(http, builder).await {
+        tracing::warn!(
+            "Failed to announce vote result in channel {}: {}",
+            channel_id,
+            e
+        );
+    }
+}
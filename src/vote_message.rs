@@ -0,0 +1,348 @@
+//! The public message of a proposal, with Yes/No buttons.
+//!
+//! The message shows the tallies and a relative end time, and is edited whenever
+//! someone votes with a button. When the vote ends the scheduler replaces the
+//! tallies with the result and disables the buttons. Buttons are routed by their
+//! custom ID (`vote:<proposal>:yes`), not by an in-memory collector, so messages
+//! keep working after a restart.
+
+use crate::{
+    Data, Error,
+    data::CurrencyConfig,
+    i18n::{self, tr},
+    logging,
+    voting::{ProposalAction, Tally, VoteConfig, VoteStatus, VoteWeighting, Voter},
+};
+use poise::serenity_prelude as serenity;
+
+const CUSTOM_ID_PREFIX: &str = "vote:";
+
+/// Custom ID of a proposal's Yes or No button
+pub fn custom_id(proposal_id: u32, vote_yes: bool) -> String {
+    let choice = if vote_yes { "yes" } else { "no" };
+    format!("{CUSTOM_ID_PREFIX}{proposal_id}:{choice}")
+}
+
+/// Proposal and choice of a button, if it is a vote button
+fn parse_custom_id(custom_id: &str) -> Option<(u32, bool)> {
+    let (proposal_id, choice) = custom_id.strip_prefix(CUSTOM_ID_PREFIX)?.split_once(':')?;
+    let vote_yes = match choice {
+        "yes" => true,
+        "no" => false,
+        _ => return None,
+    };
+    Some((proposal_id.parse().ok()?, vote_yes))
+}
+
+/// The Yes and No buttons, disabled once the vote has ended
+pub fn components(
+    locale: &str,
+    proposal_id: u32,
+    disabled: bool,
+) -> Vec<serenity::CreateActionRow> {
+    let yes = serenity::CreateButton::new(custom_id(proposal_id, true))
+        .label(tr!(locale, "button-yes"))
+        .style(serenity::ButtonStyle::Success)
+        .disabled(disabled);
+    let no = serenity::CreateButton::new(custom_id(proposal_id, false))
+        .label(tr!(locale, "button-no"))
+        .style(serenity::ButtonStyle::Danger)
+        .disabled(disabled);
+    vec![serenity::CreateActionRow::Buttons(vec![yes, no])]
+}
+
+/// Header shared by the open and the finished message
+fn header(
+    locale: &str,
+    status: &VoteStatus,
+    config: &VoteConfig,
+    currency: &CurrencyConfig,
+) -> String {
+    let initiator = status
+        .initiator_id
+        .map_or(tr!(locale, "unknown"), |id| format!("<@{id}>"));
+    tr!(
+        locale,
+        "vote-message",
+        id = status.proposal_id,
+        initiator = initiator,
+        action = status.action.describe(locale, currency),
+        min_votes = config.min_votes,
+        majority = config.majority_percentage,
+    )
+}
+
+/// Ballot as written to the command log, which keeps secret ballots secret
+pub fn vote_label(vote_yes: bool, secret: bool) -> &'static str {
+    match (secret, vote_yes) {
+        (true, _) => "SECRET",
+        (false, true) => "YES",
+        (false, false) => "NO",
+    }
+}
+
+/// Content of an open proposal's message
+pub fn render(
+    locale: &str,
+    status: &VoteStatus,
+    config: &VoteConfig,
+    currency: &CurrencyConfig,
+) -> String {
+    let mut lines = vec![header(locale, status, config, currency)];
+    if status.action == ProposalAction::Reset {
+        lines.push(tr!(
+            locale,
+            "proposal-reset-warning",
+            plural = currency.plural.as_str()
+        ));
+    }
+
+    // Discord renders the relative time and keeps it current
+    let end_time = status.end_time.map_or(tr!(locale, "unknown"), |end| {
+        format!("<t:{0}:t> (<t:{0}:R>)", end.timestamp())
+    });
+    if status.is_secret() {
+        lines.push(tr!(
+            locale,
+            "vote-message-secret-tally",
+            ballots = status.ballots(),
+            end_time = end_time,
+        ));
+    } else {
+        lines.push(tr!(
+            locale,
+            "vote-message-tally",
+            yes = status.yes_votes.len(),
+            no = status.no_votes.len(),
+            end_time = end_time,
+        ));
+    }
+    if config.weighting != VoteWeighting::OnePersonOneVote {
+        lines.push(tr!(
+            locale,
+            "vote-message-weighting",
+            weighting = config.weighting.label(locale)
+        ));
+    }
+    lines.push(tr!(locale, "vote-message-hint", id = status.proposal_id));
+    lines.join("\n")
+}
+
+/// Weighted totals of a finished vote, unless every ballot counted once
+pub fn weighted_tally(locale: &str, config: &VoteConfig, tally: &Tally) -> Option<String> {
+    (config.weighting != VoteWeighting::OnePersonOneVote).then(|| {
+        tr!(
+            locale,
+            "vote-weighted-tally",
+            weighting = config.weighting.label(locale),
+            yes = tally.yes_weight,
+            no = tally.no_weight,
+        )
+    })
+}
+
+/// Content of a finished proposal's message
+pub fn render_result(
+    locale: &str,
+    status: &VoteStatus,
+    config: &VoteConfig,
+    currency: &CurrencyConfig,
+    tally: &Tally,
+    passed: bool,
+) -> String {
+    let id = match (&status.action, passed) {
+        (ProposalAction::Poll { .. }, _) => "vote-message-result-poll",
+        (_, true) => "vote-message-result-passed",
+        (_, false) => "vote-message-result-failed",
+    };
+    let mut lines = vec![
+        header(locale, status, config, currency),
+        tr!(locale, id, yes = tally.yes_votes, no = tally.no_votes),
+    ];
+    lines.extend(weighted_tally(locale, config, tally));
+    lines.join("\n")
+}
+
+/// Content of a cancelled proposal's message
+pub fn render_cancelled(
+    locale: &str,
+    status: &VoteStatus,
+    config: &VoteConfig,
+    currency: &CurrencyConfig,
+) -> String {
+    format!(
+        "{}\n{}",
+        header(locale, status, config, currency),
+        tr!(locale, "vote-message-cancelled")
+    )
+}
+
+/// Replace the content of a proposal's public message, disabling its buttons
+/// once the vote is closed
+pub async fn edit(
+    http: &serenity::Http,
+    locale: &str,
+    status: &VoteStatus,
+    content: String,
+    closed: bool,
+) {
+    let (Some(channel_id), Some(message_id)) = (status.channel_id, status.message_id) else {
+        return;
+    };
+
+    let edit = serenity::EditMessage::new()
+        .content(content)
+        .components(components(locale, status.proposal_id, closed))
+        .allowed_mentions(serenity::CreateAllowedMentions::new());
+    let channel_id = serenity::ChannelId::new(channel_id);
+    let message_id = serenity::MessageId::new(message_id);
+    if let Err(e) = channel_id.edit_message(http, message_id, edit).await {
+        tracing::warn!("Failed to update vote message {}: {}", message_id, e);
+    }
+}
+
+/// Handle a press of a vote button
+pub async fn handle_component(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    data: &Data,
+) -> Result<(), Error> {
+    let Some((proposal_id, vote_yes)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let locale = i18n::interaction_locale(data, &interaction.locale, Some(guild_id));
+
+    let voter = interaction
+        .member
+        .as_ref()
+        .map_or_else(|| Voter::from_user(&interaction.user), Voter::from_member);
+    let status = match data.cast_vote(guild_id, Some(proposal_id), &voter, vote_yes) {
+        Ok(status) => status,
+        Err(e) => {
+            let reply = serenity::CreateInteractionResponseMessage::new()
+                .content(tr!(locale, "error-prefix", error = e.describe(locale)))
+                .ephemeral(true);
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Message(reply))
+                .await?;
+            return Ok(());
+        }
+    };
+    let proposal_id = status.proposal_id;
+    let confirmation = if vote_yes {
+        "vote-cast-yes"
+    } else {
+        "vote-cast-no"
+    };
+    let confirmation = tr!(locale, confirmation, id = proposal_id);
+
+    // Refresh the tallies in the public message, unless the vote has ended since
+    // and the message already shows its result
+    if data.get_proposal(guild_id, proposal_id).is_some() {
+        let guild_locale = i18n::guild_locale(data, guild_id);
+        let config = data.get_effective_vote_config(guild_id, status.action.kind());
+        let currency = data.get_currency(Some(guild_id));
+        let update = serenity::CreateInteractionResponseMessage::new()
+            .content(render(guild_locale, &status, &config, &currency))
+            .allowed_mentions(serenity::CreateAllowedMentions::new());
+        interaction
+            .create_response(
+                ctx,
+                serenity::CreateInteractionResponse::UpdateMessage(update),
+            )
+            .await?;
+        interaction
+            .create_followup(
+                ctx,
+                serenity::CreateInteractionResponseFollowup::new()
+                    .content(confirmation)
+                    .ephemeral(true),
+            )
+            .await?;
+    } else {
+        let reply = serenity::CreateInteractionResponseMessage::new()
+            .content(confirmation)
+            .ephemeral(true);
+        interaction
+            .create_response(ctx, serenity::CreateInteractionResponse::Message(reply))
+            .await?;
+    }
+
+    logging::log_command(
+        "vote_cast",
+        Some(guild_id.get()),
+        interaction.user.id.get(),
+        &format!(
+            "proposal: {proposal_id}, vote: {}, via: button",
+            vote_label(vote_yes, status.is_secret())
+        ),
+        true,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_id_round_trip() {
+        assert_eq!(custom_id(7, true), "vote:7:yes");
+        assert_eq!(parse_custom_id(&custom_id(7, true)), Some((7, true)));
+        assert_eq!(parse_custom_id(&custom_id(12, false)), Some((12, false)));
+
+        // Other components and malformed IDs are ignored
+        assert_eq!(parse_custom_id("leaderboard:next"), None);
+        assert_eq!(parse_custom_id("vote:7:maybe"), None);
+        assert_eq!(parse_custom_id("vote:x:yes"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let config = VoteConfig::default();
+        let currency = CurrencyConfig::default();
+        let mut status = VoteStatus::default();
+        let now = chrono::Utc::now();
+        status
+            .start(&config, 3, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        status.cast(&config, 2, false, 0, now).unwrap();
+
+        let open = render("en-US", &status, &config, &currency);
+        assert!(open.contains("Proposal #3"));
+        assert!(open.contains("1 yes / 1 no"));
+        assert!(open.contains(&format!("<t:{}:R>", status.end_time.unwrap().timestamp())));
+
+        let outcome = status.finish(&config, |_| 0, now).unwrap();
+        let result = render_result(
+            "en-US",
+            &status,
+            &config,
+            &currency,
+            &outcome.tally,
+            outcome.passed,
+        );
+        assert!(result.contains("FAILED"));
+        assert!(!result.contains("<t:"));
+        assert!(!result.contains("Weighted"));
+
+        // Weighted votes show their totals
+        let config = VoteConfig {
+            weighting: VoteWeighting::Quadratic,
+            ..config
+        };
+        let result = render_result(
+            "en-US",
+            &status,
+            &config,
+            &currency,
+            &outcome.tally,
+            outcome.passed,
+        );
+        assert!(result.contains("Weighted"));
+        assert!(result.contains("quadratic"));
+    }
+}
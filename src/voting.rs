@@ -0,0 +1,1609 @@
+//! Governance proposals and their vote lifecycle.
+//!
+//! Members propose typed actions ([`ProposalAction`]): resetting the server's
+//! balances, changing a vote setting, granting coins, adding or removing a giver
+//! role, or a poll without side effects. Each [`ProposalKind`] has its own
+//! thresholds and cooldown. Open proposals are keyed by their number, so any
+//! number of them, of any kinds, can run at once in a guild.
+//!
+//! Each proposal is always in one of four phases:
+//!
+//! - Idle: no vote is running and a new one can be started
+//! - Active: ballots are accepted until the end time
+//! - Ended: the end time has passed but the result hasn't been applied yet
+//! - Cooldown: a vote finished recently and a new one can't be started yet
+//!
+//! Admins can also cancel an Active or Ended vote without applying it, which
+//! only starts the cooldown if the kind's config says so, and extend an Active one.
+//!
+//! Who may vote is limited by each kind's eligibility rules (account age, time in
+//! the server, a role and a balance), and ballots are weighted by its
+//! [`VoteWeighting`] when the vote is tallied. A secret ballot keeps only salted
+//! hashes of its voters, forgotten once it closes, and hides the running tally.
+//!
+//! Finished and cancelled votes are archived as [`VoteRecord`]s, which
+//! [`VoteStats`] summarizes.
+//!
+//! Transitions are methods on [`VoteStatus`] that only touch the status itself.
+//! [`DataInner`](crate::data::DataInner) applies them under a single guild config
+//! guard and does follow-up work, like applying the proposal, after the guard is
+//! released, so no transition ever re-enters a map lock.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    data::CurrencyConfig,
+    i18n::{DEFAULT_LOCALE, tr},
+};
+use poise::serenity_prelude as serenity;
+
+/// Types of proposals, each with its own thresholds
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    poise::ChoiceParameter,
+)]
+pub enum ProposalKind {
+    Reset,
+    #[name = "Vote setting"]
+    Setting,
+    Grant,
+    #[name = "Giver role"]
+    GiverRole,
+    Poll,
+}
+
+impl ProposalKind {
+    /// Thresholds used until a server configures its own
+    pub fn default_config(self) -> VoteConfig {
+        match self {
+            ProposalKind::Reset => VoteConfig::default(),
+            ProposalKind::Setting | ProposalKind::Grant | ProposalKind::GiverRole => VoteConfig {
+                cooldown_hours: 1,
+                duration_minutes: 30,
+                min_votes: 5,
+                majority_percentage: 60,
+                ..VoteConfig::default()
+            },
+            ProposalKind::Poll => VoteConfig {
+                cooldown_hours: 0,
+                duration_minutes: 30,
+                min_votes: 1,
+                majority_percentage: 50,
+                ..VoteConfig::default()
+            },
+        }
+    }
+
+    /// Name of the proposal type in a locale
+    pub fn label(self, locale: &str) -> String {
+        let kind = match self {
+            ProposalKind::Reset => "reset",
+            ProposalKind::Setting => "setting",
+            ProposalKind::Grant => "grant",
+            ProposalKind::GiverRole => "giver-role",
+            ProposalKind::Poll => "poll",
+        };
+        tr!(locale, "proposal-kind", kind = kind)
+    }
+}
+
+/// A [`VoteConfig`] field that a proposal can change
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, poise::ChoiceParameter)]
+pub enum VoteField {
+    #[name = "Cooldown hours"]
+    CooldownHours,
+    #[name = "Duration minutes"]
+    DurationMinutes,
+    #[name = "Minimum votes"]
+    MinVotes,
+    #[name = "Majority percentage"]
+    MajorityPercentage,
+}
+
+impl VoteField {
+    /// Name of the setting in a locale
+    pub fn label(self, locale: &str) -> String {
+        let field = match self {
+            VoteField::CooldownHours => "cooldown-hours",
+            VoteField::DurationMinutes => "duration-minutes",
+            VoteField::MinVotes => "min-votes",
+            VoteField::MajorityPercentage => "majority",
+        };
+        tr!(locale, "vote-field", field = field)
+    }
+
+    /// Largest value the setting accepts
+    pub fn max(self) -> u32 {
+        match self {
+            VoteField::CooldownHours => MAX_COOLDOWN_HOURS,
+            VoteField::DurationMinutes => MAX_DURATION_MINUTES,
+            VoteField::MinVotes => u32::MAX,
+            VoteField::MajorityPercentage => 100,
+        }
+    }
+}
+
+/// Longest activity window a quorum can count, in days
+pub const MAX_ACTIVITY_DAYS: u32 = 90;
+
+/// Longest cooldown between votes, in hours (a year)
+pub const MAX_COOLDOWN_HOURS: u32 = 8760;
+
+/// Longest a vote can run, in minutes (30 days)
+pub const MAX_DURATION_MINUTES: u32 = 30 * 24 * 60;
+
+/// `time` moved by `duration`, saturating at the latest representable time
+/// instead of panicking when a setting is absurdly large
+pub fn saturating_add(time: DateTime<Utc>, duration: chrono::Duration) -> DateTime<Utc> {
+    time.checked_add_signed(duration)
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// How many ballots a vote needs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quorum {
+    /// A fixed number of ballots, `min_votes`
+    #[default]
+    Votes,
+    /// A percentage of the members active in the last `days` days
+    ActiveMembers { percent: u32, days: u32 },
+}
+
+/// The kind of [`Quorum`], as picked in commands
+#[derive(Clone, Copy, Debug, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum QuorumKind {
+    #[name = "Fixed number of votes"]
+    Votes,
+    #[name = "Percentage of active members"]
+    ActiveMembers,
+}
+
+impl Quorum {
+    /// Build a quorum from a command's choice. A percentage quorum needs a
+    /// percentage from 1 to 100 and a window of 1 to [`MAX_ACTIVITY_DAYS`] days.
+    pub fn from_choice(kind: QuorumKind, percent: Option<u32>, days: Option<u32>) -> Option<Self> {
+        match (kind, percent, days) {
+            (QuorumKind::Votes, _, _) => Some(Quorum::Votes),
+            (QuorumKind::ActiveMembers, Some(percent), Some(days))
+                if (1..=100).contains(&percent) && (1..=MAX_ACTIVITY_DAYS).contains(&days) =>
+            {
+                Some(Quorum::ActiveMembers { percent, days })
+            }
+            _ => None,
+        }
+    }
+
+    /// Ballots needed when `active_members` were active in the window, at least 1
+    pub fn required_votes(self, min_votes: u32, active_members: usize) -> u32 {
+        match self {
+            Quorum::Votes => min_votes,
+            Quorum::ActiveMembers { percent, .. } => {
+                let active = u64::try_from(active_members).unwrap_or(u64::MAX);
+                let needed = (active * u64::from(percent)).div_ceil(100);
+                u32::try_from(needed).unwrap_or(u32::MAX).max(1)
+            }
+        }
+    }
+
+    pub fn describe(self, locale: &str, min_votes: u32) -> String {
+        match self {
+            Quorum::Votes => tr!(locale, "quorum-votes", min_votes = min_votes),
+            Quorum::ActiveMembers { percent, days } => {
+                tr!(locale, "quorum-active", percent = percent, days = days)
+            }
+        }
+    }
+}
+
+/// How a passed reset changes the server's balances
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResetMode {
+    /// Set every balance to 0
+    #[default]
+    Zero,
+    /// Keep a percentage of every balance
+    Scale { percent: u32 },
+    /// Lower balances above a maximum to it
+    Cap { max: u32 },
+    /// Share all coins equally; what doesn't divide evenly goes to the treasury
+    Redistribute,
+    /// Move a percentage of every balance into the treasury
+    Tax { percent: u32 },
+}
+
+/// The kind of [`ResetMode`], as picked in commands
+#[derive(Clone, Copy, Debug, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum ResetModeKind {
+    #[name = "Reset to zero"]
+    Zero,
+    Scale,
+    Cap,
+    Redistribute,
+    Tax,
+}
+
+impl ResetMode {
+    /// Build a mode from a command's choice and amount. Scale and tax need a
+    /// percentage up to 100, cap needs a maximum.
+    pub fn from_choice(kind: ResetModeKind, amount: Option<u32>) -> Option<Self> {
+        match (kind, amount) {
+            (ResetModeKind::Zero, _) => Some(ResetMode::Zero),
+            (ResetModeKind::Redistribute, _) => Some(ResetMode::Redistribute),
+            (ResetModeKind::Scale, Some(percent)) if percent <= 100 => {
+                Some(ResetMode::Scale { percent })
+            }
+            (ResetModeKind::Cap, Some(max)) => Some(ResetMode::Cap { max }),
+            (ResetModeKind::Tax, Some(percent)) if percent <= 100 => {
+                Some(ResetMode::Tax { percent })
+            }
+            _ => None,
+        }
+    }
+
+    /// New balances after the reset, and the coins moved into the treasury
+    pub fn apply(self, balances: &BTreeMap<u64, u32>) -> (BTreeMap<u64, u32>, u32) {
+        let percent_of = |balance: u32, percent: u32| {
+            // Fits in u32 because the percentage is at most 100
+            (u64::from(balance) * u64::from(percent.min(100)) / 100) as u32
+        };
+        let map = |f: &dyn Fn(u32) -> u32| {
+            balances
+                .iter()
+                .map(|(&user_id, &balance)| (user_id, f(balance)))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        match self {
+            ResetMode::Zero => (map(&|_| 0), 0),
+            ResetMode::Scale { percent } => (map(&|balance| percent_of(balance, percent)), 0),
+            ResetMode::Cap { max } => (map(&|balance| balance.min(max)), 0),
+            ResetMode::Redistribute => {
+                let total: u64 = balances.values().map(|&balance| u64::from(balance)).sum();
+                let users = balances.len() as u64;
+                if users == 0 {
+                    return (BTreeMap::new(), 0);
+                }
+                // Each share is at most the largest balance, so it fits in u32
+                let share = (total / users) as u32;
+                let remainder = u32::try_from(total % users).unwrap_or(u32::MAX);
+                (map(&|_| share), remainder)
+            }
+            ResetMode::Tax { percent } => {
+                let taxed: u64 = balances
+                    .values()
+                    .map(|&balance| u64::from(percent_of(balance, percent)))
+                    .sum();
+                (
+                    map(&|balance| balance - percent_of(balance, percent)),
+                    u32::try_from(taxed).unwrap_or(u32::MAX),
+                )
+            }
+        }
+    }
+
+    /// Describe the mode in a locale
+    pub fn describe(&self, locale: &str, currency: &CurrencyConfig) -> String {
+        match self {
+            ResetMode::Zero => tr!(locale, "reset-mode-zero", plural = currency.plural.as_str()),
+            ResetMode::Scale { percent } => {
+                tr!(locale, "reset-mode-scale", percent = *percent)
+            }
+            ResetMode::Cap { max } => {
+                tr!(locale, "reset-mode-cap", max = currency.format(*max))
+            }
+            ResetMode::Redistribute => tr!(
+                locale,
+                "reset-mode-redistribute",
+                plural = currency.plural.as_str()
+            ),
+            ResetMode::Tax { percent } => {
+                tr!(locale, "reset-mode-tax", percent = *percent)
+            }
+        }
+    }
+}
+
+/// What happens when a proposal passes
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalAction {
+    /// Reset all balances in the server
+    #[default]
+    Reset,
+    /// Change balances with a [`ResetMode`] other than zeroing them
+    PartialReset { mode: ResetMode },
+    /// Change one threshold of a proposal type
+    Setting {
+        kind: ProposalKind,
+        field: VoteField,
+        value: u32,
+    },
+    /// Mint coins for a user
+    Grant { user_id: u64, amount: u32 },
+    /// Add or remove a giver role
+    GiverRole { role_id: u64, add: bool },
+    /// Ask a question, with no side effect
+    Poll { question: String },
+}
+
+impl ProposalAction {
+    /// A reset proposal with the given mode
+    pub fn reset(mode: ResetMode) -> Self {
+        match mode {
+            ResetMode::Zero => ProposalAction::Reset,
+            mode => ProposalAction::PartialReset { mode },
+        }
+    }
+
+    /// How balances change if this is a reset proposal
+    pub fn reset_mode(&self) -> Option<ResetMode> {
+        match self {
+            ProposalAction::Reset => Some(ResetMode::Zero),
+            ProposalAction::PartialReset { mode } => Some(*mode),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> ProposalKind {
+        match self {
+            ProposalAction::Reset | ProposalAction::PartialReset { .. } => ProposalKind::Reset,
+            ProposalAction::Setting { .. } => ProposalKind::Setting,
+            ProposalAction::Grant { .. } => ProposalKind::Grant,
+            ProposalAction::GiverRole { .. } => ProposalKind::GiverRole,
+            ProposalAction::Poll { .. } => ProposalKind::Poll,
+        }
+    }
+
+    /// Describe the proposal in a locale
+    pub fn describe(&self, locale: &str, currency: &CurrencyConfig) -> String {
+        match self {
+            ProposalAction::Reset => {
+                tr!(locale, "proposal-reset", plural = currency.plural.as_str())
+            }
+            ProposalAction::PartialReset { mode } => tr!(
+                locale,
+                "proposal-partial-reset",
+                mode = mode.describe(locale, currency)
+            ),
+            ProposalAction::Setting { kind, field, value } => tr!(
+                locale,
+                "proposal-setting",
+                field = field.label(locale),
+                kind = kind.label(locale),
+                value = *value,
+            ),
+            ProposalAction::Grant { user_id, amount } => tr!(
+                locale,
+                "proposal-grant",
+                amount = currency.format(*amount),
+                user = format!("<@{user_id}>"),
+            ),
+            ProposalAction::GiverRole { role_id, add } => tr!(
+                locale,
+                if *add {
+                    "proposal-giver-role-add"
+                } else {
+                    "proposal-giver-role-remove"
+                },
+                role = format!("<@&{role_id}>")
+            ),
+            ProposalAction::Poll { question } => {
+                tr!(locale, "proposal-poll", question = question.as_str())
+            }
+        }
+    }
+}
+
+/// How ballots count when a vote is tallied
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, poise::ChoiceParameter,
+)]
+pub enum VoteWeighting {
+    /// Every ballot counts once
+    #[default]
+    #[name = "One vote per person"]
+    OnePersonOneVote,
+    /// Ballots count by the voter's balance
+    #[name = "Balance-weighted"]
+    Balance,
+    /// Ballots count by the square root of the voter's balance
+    Quadratic,
+}
+
+impl VoteWeighting {
+    /// Weight of a ballot from a voter with this balance
+    pub fn weight(self, balance: u32) -> u64 {
+        match self {
+            VoteWeighting::OnePersonOneVote => 1,
+            VoteWeighting::Balance => u64::from(balance),
+            VoteWeighting::Quadratic => u64::from(balance).isqrt(),
+        }
+    }
+
+    /// Name of the weighting in a locale
+    pub fn label(self, locale: &str) -> String {
+        let mode = match self {
+            VoteWeighting::OnePersonOneVote => "one-person",
+            VoteWeighting::Balance => "balance",
+            VoteWeighting::Quadratic => "quadratic",
+        };
+        tr!(locale, "vote-weighting", mode = mode)
+    }
+}
+
+/// A member casting or starting a vote, as needed for eligibility checks
+#[derive(Clone, Debug)]
+pub struct Voter {
+    pub user_id: u64,
+    pub account_created: DateTime<Utc>,
+    /// When the member joined the server, if known
+    pub joined_at: Option<DateTime<Utc>>,
+    pub roles: Vec<u64>,
+}
+
+impl Voter {
+    pub fn from_member(member: &serenity::Member) -> Self {
+        Self {
+            user_id: member.user.id.get(),
+            account_created: *member.user.id.created_at(),
+            joined_at: member.joined_at.map(|joined| *joined),
+            roles: member.roles.iter().map(|role| role.get()).collect(),
+        }
+    }
+
+    /// A voter whose membership is unknown, such as outside the guild cache
+    pub fn from_user(user: &serenity::User) -> Self {
+        Self {
+            user_id: user.id.get(),
+            account_created: *user.id.created_at(),
+            joined_at: None,
+            roles: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteConfig {
+    pub cooldown_hours: u32,
+    pub duration_minutes: u32,
+    pub min_votes: u32,
+    pub majority_percentage: u32,
+    /// Minimum age of a voter's Discord account
+    #[serde(default)]
+    pub min_account_age_days: u32,
+    /// Minimum time a voter has been in the server
+    #[serde(default)]
+    pub min_member_days: u32,
+    /// Role a voter must have
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_role: Option<u64>,
+    /// Balance a voter must have in the server
+    #[serde(default)]
+    pub min_balance: u32,
+    #[serde(default)]
+    pub weighting: VoteWeighting,
+    /// Whether a cancelled vote starts the cooldown like a finished one
+    #[serde(default)]
+    pub cancel_cooldown: bool,
+    /// Whether votes are secret ballots
+    #[serde(default)]
+    pub secret: bool,
+    /// How many ballots a vote needs; `min_votes` unless a percentage is set
+    #[serde(default)]
+    pub quorum: Quorum,
+    /// Minutes before the end of a vote to send reminders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminder_minutes: Option<u32>,
+    /// Role pinged by reminders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminder_role: Option<u64>,
+}
+
+impl Default for VoteConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_hours: 24,      // Once per day
+            duration_minutes: 30,    // Half hour voting time
+            min_votes: 10,           // At least 10 votes
+            majority_percentage: 70, // 7/10 majority (70%)
+            min_account_age_days: 0,
+            min_member_days: 0,
+            required_role: None,
+            min_balance: 0,
+            weighting: VoteWeighting::OnePersonOneVote,
+            cancel_cooldown: false,
+            secret: false,
+            quorum: Quorum::Votes,
+            reminder_minutes: None,
+            reminder_role: None,
+        }
+    }
+}
+
+impl VoteConfig {
+    /// Change one field, capped at its [`VoteField::max`]
+    pub fn set(&mut self, field: VoteField, value: u32) {
+        let value = value.min(field.max());
+        match field {
+            VoteField::CooldownHours => self.cooldown_hours = value,
+            VoteField::DurationMinutes => self.duration_minutes = value,
+            VoteField::MinVotes => self.min_votes = value,
+            VoteField::MajorityPercentage => self.majority_percentage = value,
+        }
+    }
+
+    /// These thresholds with `min_votes` set by the quorum, given how many members
+    /// were active in its window
+    pub fn with_quorum(&self, active_members: usize) -> VoteConfig {
+        VoteConfig {
+            min_votes: self.quorum.required_votes(self.min_votes, active_members),
+            ..self.clone()
+        }
+    }
+
+    /// Whether a vote with this tally passes. The minimum counts ballots, the
+    /// majority counts their weight.
+    pub fn passes(&self, tally: &Tally) -> bool {
+        let total_votes = tally.yes_votes + tally.no_votes;
+        if total_votes == 0 || total_votes < self.min_votes as usize {
+            return false;
+        }
+        if tally.yes_weight + tally.no_weight == 0 {
+            return false;
+        }
+
+        tally.yes_percentage() >= f64::from(self.majority_percentage)
+    }
+
+    /// Whether a voter with this balance may vote
+    pub fn check_eligibility(
+        &self,
+        voter: &Voter,
+        balance: u32,
+        now: DateTime<Utc>,
+    ) -> Result<(), VoteError> {
+        let days = |since: DateTime<Utc>| (now - since).num_days();
+
+        if days(voter.account_created) < i64::from(self.min_account_age_days) {
+            return Err(VoteError::AccountTooNew {
+                days: self.min_account_age_days,
+            });
+        }
+        if self.min_member_days > 0
+            && voter
+                .joined_at
+                .is_none_or(|joined| days(joined) < i64::from(self.min_member_days))
+        {
+            return Err(VoteError::JoinedTooRecently {
+                days: self.min_member_days,
+            });
+        }
+        if let Some(role_id) = self.required_role {
+            if !voter.roles.contains(&role_id) {
+                return Err(VoteError::MissingRole { role_id });
+            }
+        }
+        if balance < self.min_balance {
+            return Err(VoteError::BalanceTooLow {
+                min_balance: self.min_balance,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Ballot counts and their weights
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tally {
+    pub yes_votes: usize,
+    pub no_votes: usize,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+}
+
+impl Tally {
+    /// Share of the weight that voted yes
+    pub fn yes_percentage(&self) -> f64 {
+        let total_weight = self.yes_weight + self.no_weight;
+        if total_weight == 0 {
+            return 0.0;
+        }
+        (self.yes_weight as f64 / total_weight as f64) * 100.0
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteStatus {
+    pub active: bool,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub initiator_id: Option<u64>,
+    pub yes_votes: Vec<u64>,
+    pub no_votes: Vec<u64>,
+    pub last_vote_time: Option<DateTime<Utc>>,
+    /// Channel the vote was started in, where the result is announced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<u64>,
+    /// Number members use to pick the proposal
+    #[serde(default)]
+    pub proposal_id: u32,
+    #[serde(default)]
+    pub action: ProposalAction,
+    /// Public message with the vote buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<u64>,
+    /// Salt for voter hashes, set when the vote is a secret ballot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+    /// Ballots of a secret vote, which leaves `yes_votes` and `no_votes` empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secret_ballots: Vec<SecretBallot>,
+    /// Whether the reminder before the end was sent
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub reminded: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// An anonymous ballot, known only by a salted hash of its voter
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretBallot {
+    pub voter_hash: String,
+    pub yes: bool,
+    /// Weight from the voter's balance when the ballot was cast, since the voter
+    /// can't be looked up when the vote is tallied
+    pub weight: u64,
+}
+
+/// Where a guild's vote is in its lifecycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VotePhase {
+    Idle,
+    Active { end_time: DateTime<Utc> },
+    Ended,
+    Cooldown { until: DateTime<Utc> },
+}
+
+/// Result of a finished vote
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoteOutcome {
+    pub tally: Tally,
+    pub passed: bool,
+}
+
+/// How an archived vote ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoteResult {
+    Passed,
+    Failed,
+    Cancelled,
+}
+
+/// A finished or cancelled vote, archived for history and stats
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteRecord {
+    pub proposal_id: u32,
+    pub action: ProposalAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiator_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+    /// When the vote was finished or cancelled
+    pub closed_at: DateTime<Utc>,
+    pub yes_votes: Vec<u64>,
+    pub no_votes: Vec<u64>,
+    pub tally: Tally,
+    /// Thresholds the vote ran under
+    pub config: VoteConfig,
+    pub result: VoteResult,
+}
+
+impl VoteRecord {
+    pub fn new(
+        status: &VoteStatus,
+        config: &VoteConfig,
+        tally: Tally,
+        result: VoteResult,
+        closed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            proposal_id: status.proposal_id,
+            action: status.action.clone(),
+            initiator_id: status.initiator_id,
+            start_time: status.start_time,
+            closed_at,
+            yes_votes: status.yes_votes.clone(),
+            no_votes: status.no_votes.clone(),
+            tally,
+            config: config.clone(),
+            result,
+        }
+    }
+
+    /// Number of ballots cast
+    pub fn turnout(&self) -> usize {
+        self.tally.yes_votes + self.tally.no_votes
+    }
+}
+
+/// Governance stats over a guild's archived votes
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VoteStats {
+    pub passed: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+    /// Share of decided votes that passed, in percent
+    pub pass_rate: f64,
+    /// Average ballots per decided vote
+    pub average_turnout: f64,
+    /// Users with the most ballots, most first
+    pub top_voters: Vec<(u64, usize)>,
+}
+
+impl VoteStats {
+    pub fn from_records(records: &[VoteRecord], top: usize) -> Self {
+        let mut stats = VoteStats::default();
+        let mut ballots: BTreeMap<u64, usize> = BTreeMap::new();
+        let mut turnout = 0;
+
+        for record in records {
+            match record.result {
+                VoteResult::Passed => stats.passed += 1,
+                VoteResult::Failed => stats.failed += 1,
+                VoteResult::Cancelled => {
+                    stats.cancelled += 1;
+                    continue;
+                }
+            }
+            turnout += record.turnout();
+            for user_id in record.yes_votes.iter().chain(&record.no_votes) {
+                *ballots.entry(*user_id).or_default() += 1;
+            }
+        }
+
+        let decided = stats.passed + stats.failed;
+        if decided > 0 {
+            stats.pass_rate = stats.passed as f64 / decided as f64 * 100.0;
+            stats.average_turnout = turnout as f64 / decided as f64;
+        }
+
+        let mut voters: Vec<_> = ballots.into_iter().collect();
+        // Most ballots first, then by user ID for a stable order
+        voters.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        voters.truncate(top);
+        stats.top_voters = voters;
+        stats
+    }
+}
+
+/// Random salt for a secret ballot's voter hashes
+fn new_salt() -> String {
+    let bytes: [u8; 16] = rand::rng().random();
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Reasons a vote can't be started or cast
+#[derive(Debug, PartialEq, Eq)]
+pub enum VoteError {
+    AlreadyActive,
+    Cooldown,
+    NotActive,
+    Ended,
+    UnknownProposal,
+    ChooseProposal,
+    AccountTooNew { days: u32 },
+    JoinedTooRecently { days: u32 },
+    MissingRole { role_id: u64 },
+    BalanceTooLow { min_balance: u32 },
+}
+
+impl VoteError {
+    /// Describe the error in a locale
+    pub fn describe(&self, locale: &str) -> String {
+        match self {
+            VoteError::AlreadyActive => tr!(locale, "vote-error-already-active"),
+            VoteError::Cooldown => tr!(locale, "vote-error-cooldown"),
+            VoteError::NotActive => tr!(locale, "vote-error-not-active"),
+            VoteError::Ended => tr!(locale, "vote-error-ended"),
+            VoteError::UnknownProposal => tr!(locale, "vote-error-unknown-proposal"),
+            VoteError::ChooseProposal => tr!(locale, "vote-error-choose-proposal"),
+            VoteError::AccountTooNew { days } => {
+                tr!(locale, "vote-error-account-too-new", days = *days)
+            }
+            VoteError::JoinedTooRecently { days } => {
+                tr!(locale, "vote-error-joined-too-recently", days = *days)
+            }
+            VoteError::MissingRole { role_id } => tr!(
+                locale,
+                "vote-error-missing-role",
+                role = format!("<@&{role_id}>")
+            ),
+            VoteError::BalanceTooLow { min_balance } => tr!(
+                locale,
+                "vote-error-balance-too-low",
+                min_balance = *min_balance
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for VoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.describe(DEFAULT_LOCALE))
+    }
+}
+
+impl std::error::Error for VoteError {}
+
+impl VoteStatus {
+    /// Whether no vote of this kind ever ran
+    pub fn is_unused(&self) -> bool {
+        !self.active && self.last_vote_time.is_none()
+    }
+
+    /// Phase of the vote at `now`
+    pub fn phase(&self, config: &VoteConfig, now: DateTime<Utc>) -> VotePhase {
+        if self.active {
+            return match self.end_time {
+                Some(end_time) if now < end_time => VotePhase::Active { end_time },
+                _ => VotePhase::Ended,
+            };
+        }
+
+        match self.cooldown_end(config) {
+            Some(until) if now < until => VotePhase::Cooldown { until },
+            _ => VotePhase::Idle,
+        }
+    }
+
+    /// When the cooldown after the last vote ends
+    pub fn cooldown_end(&self, config: &VoteConfig) -> Option<DateTime<Utc>> {
+        self.last_vote_time.map(|last| {
+            saturating_add(
+                last,
+                chrono::Duration::hours(i64::from(config.cooldown_hours)),
+            )
+        })
+    }
+
+    /// Idle → Active. The initiator automatically votes yes, except on a secret
+    /// ballot, where that would give their ballot away.
+    pub fn start(
+        &mut self,
+        config: &VoteConfig,
+        proposal_id: u32,
+        action: ProposalAction,
+        initiator_id: u64,
+        channel_id: u64,
+        now: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, VoteError> {
+        match self.phase(config, now) {
+            VotePhase::Idle => {}
+            VotePhase::Active { .. } | VotePhase::Ended => return Err(VoteError::AlreadyActive),
+            VotePhase::Cooldown { .. } => return Err(VoteError::Cooldown),
+        }
+
+        let end_time = saturating_add(
+            now,
+            chrono::Duration::minutes(i64::from(config.duration_minutes)),
+        );
+        *self = VoteStatus {
+            active: true,
+            start_time: Some(now),
+            end_time: Some(end_time),
+            initiator_id: Some(initiator_id),
+            yes_votes: vec![],
+            no_votes: vec![],
+            last_vote_time: None,
+            channel_id: Some(channel_id),
+            proposal_id,
+            action,
+            message_id: None,
+            salt: config.secret.then(new_salt),
+            secret_ballots: vec![],
+            reminded: false,
+        };
+        if !self.is_secret() {
+            self.record(config, initiator_id, true, 0);
+        }
+
+        Ok(end_time)
+    }
+
+    /// Whether the vote is a secret ballot
+    pub fn is_secret(&self) -> bool {
+        self.salt.is_some()
+    }
+
+    /// Whether a user has cast a ballot
+    pub fn has_voted(&self, user_id: u64) -> bool {
+        match &self.salt {
+            Some(salt) => {
+                let voter_hash = Self::voter_hash(salt, user_id);
+                self.secret_ballots
+                    .iter()
+                    .any(|ballot| ballot.voter_hash == voter_hash)
+            }
+            None => self.yes_votes.contains(&user_id) || self.no_votes.contains(&user_id),
+        }
+    }
+
+    /// When the reminder is due, if the vote is open and one is still to be sent.
+    /// Reminders that would come before the vote started are skipped.
+    pub fn reminder_time(&self, config: &VoteConfig) -> Option<DateTime<Utc>> {
+        if !self.active || self.reminded {
+            return None;
+        }
+        let minutes = config.reminder_minutes.filter(|minutes| *minutes > 0)?;
+        let time = self
+            .end_time?
+            .checked_sub_signed(chrono::Duration::minutes(i64::from(minutes)))?;
+        (self.start_time? < time).then_some(time)
+    }
+
+    /// Whether the reminder is due at `now`. A reminder after the end time is left
+    /// to the vote's expiry.
+    pub fn reminder_due(&self, config: &VoteConfig, now: DateTime<Utc>) -> bool {
+        self.reminder_time(config).is_some_and(|time| time <= now)
+            && self.end_time.is_some_and(|end| now < end)
+    }
+
+    /// Number of ballots cast so far
+    pub fn ballots(&self) -> usize {
+        if self.is_secret() {
+            self.secret_ballots.len()
+        } else {
+            self.yes_votes.len() + self.no_votes.len()
+        }
+    }
+
+    /// Hash of a voter for this vote's secret ballot, which only matches again
+    /// with the same salt
+    fn voter_hash(salt: &str, user_id: u64) -> String {
+        let digest = Sha256::new()
+            .chain_update(salt.as_bytes())
+            .chain_update(user_id.to_be_bytes())
+            .finalize();
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Drop a secret ballot's salt and voter hashes once it closes, so its ballots
+    /// can't be traced back to the voters
+    fn forget_voters(&mut self) {
+        self.salt = None;
+        self.secret_ballots.clear();
+    }
+
+    /// Store a ballot, replacing the user's earlier one
+    fn record(&mut self, config: &VoteConfig, user_id: u64, vote_yes: bool, balance: u32) {
+        if let Some(salt) = &self.salt {
+            let voter_hash = Self::voter_hash(salt, user_id);
+            self.secret_ballots
+                .retain(|ballot| ballot.voter_hash != voter_hash);
+            self.secret_ballots.push(SecretBallot {
+                voter_hash,
+                yes: vote_yes,
+                weight: config.weighting.weight(balance),
+            });
+            return;
+        }
+
+        // Remove user from both vote lists to avoid duplicate votes
+        self.yes_votes.retain(|id| *id != user_id);
+        self.no_votes.retain(|id| *id != user_id);
+
+        if vote_yes {
+            self.yes_votes.push(user_id);
+        } else {
+            self.no_votes.push(user_id);
+        }
+    }
+
+    /// Record a ballot while Active, replacing the user's earlier one. `balance`
+    /// weights the ballot of a secret vote.
+    pub fn cast(
+        &mut self,
+        config: &VoteConfig,
+        user_id: u64,
+        vote_yes: bool,
+        balance: u32,
+        now: DateTime<Utc>,
+    ) -> Result<(), VoteError> {
+        match self.phase(config, now) {
+            VotePhase::Active { .. } => {}
+            VotePhase::Ended => return Err(VoteError::Ended),
+            VotePhase::Idle | VotePhase::Cooldown { .. } => return Err(VoteError::NotActive),
+        }
+
+        self.record(config, user_id, vote_yes, balance);
+        Ok(())
+    }
+
+    /// Active or Ended → Idle, or Cooldown if cancelled votes count toward it.
+    /// The ballots are kept but never applied, except those of a secret ballot.
+    pub fn cancel(&mut self, config: &VoteConfig, now: DateTime<Utc>) -> Result<(), VoteError> {
+        if !self.active {
+            return Err(VoteError::NotActive);
+        }
+
+        self.active = false;
+        if config.cancel_cooldown {
+            self.last_vote_time = Some(now);
+        }
+        self.forget_voters();
+        Ok(())
+    }
+
+    /// Push back the end of an Active vote, returning the new end time
+    pub fn extend(
+        &mut self,
+        config: &VoteConfig,
+        minutes: u32,
+        now: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, VoteError> {
+        let end_time = match self.phase(config, now) {
+            VotePhase::Active { end_time } => end_time,
+            VotePhase::Ended => return Err(VoteError::Ended),
+            VotePhase::Idle | VotePhase::Cooldown { .. } => return Err(VoteError::NotActive),
+        };
+
+        let end_time = saturating_add(end_time, chrono::Duration::minutes(i64::from(minutes)));
+        self.end_time = Some(end_time);
+        // Remind again before the new end
+        self.reminded = false;
+        Ok(end_time)
+    }
+
+    /// Count the ballots, weighting them by the voters' balances. Secret ballots
+    /// keep the weight they were cast with.
+    pub fn tally(&self, weighting: VoteWeighting, balance: impl Fn(u64) -> u32) -> Tally {
+        if self.is_secret() {
+            let (yes, no): (Vec<&SecretBallot>, Vec<&SecretBallot>) =
+                self.secret_ballots.iter().partition(|ballot| ballot.yes);
+            let weigh = |ballots: &[&SecretBallot]| ballots.iter().map(|b| b.weight).sum();
+            return Tally {
+                yes_votes: yes.len(),
+                no_votes: no.len(),
+                yes_weight: weigh(&yes),
+                no_weight: weigh(&no),
+            };
+        }
+
+        let weigh = |voters: &[u64]| -> u64 {
+            voters
+                .iter()
+                .map(|user_id| weighting.weight(balance(*user_id)))
+                .sum()
+        };
+        Tally {
+            yes_votes: self.yes_votes.len(),
+            no_votes: self.no_votes.len(),
+            yes_weight: weigh(&self.yes_votes),
+            no_weight: weigh(&self.no_votes),
+        }
+    }
+
+    /// Active or Ended → Cooldown, tallying the ballots
+    pub fn finish(
+        &mut self,
+        config: &VoteConfig,
+        balance: impl Fn(u64) -> u32,
+        now: DateTime<Utc>,
+    ) -> Result<VoteOutcome, VoteError> {
+        if !self.active {
+            return Err(VoteError::NotActive);
+        }
+
+        let tally = self.tally(config.weighting, balance);
+        self.active = false;
+        self.last_vote_time = Some(now);
+        self.forget_voters();
+
+        Ok(VoteOutcome {
+            tally,
+            passed: config.passes(&tally),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> VoteConfig {
+        VoteConfig {
+            cooldown_hours: 1,
+            duration_minutes: 10,
+            min_votes: 2,
+            majority_percentage: 50,
+            ..VoteConfig::default()
+        }
+    }
+
+    /// One-person-one-vote tally
+    fn tally(yes_votes: usize, no_votes: usize) -> Tally {
+        Tally {
+            yes_votes,
+            no_votes,
+            yes_weight: yes_votes as u64,
+            no_weight: no_votes as u64,
+        }
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let config = config();
+        let now = Utc::now();
+        let mut status = VoteStatus::default();
+        assert_eq!(status.phase(&config, now), VotePhase::Idle);
+
+        let end_time = status
+            .start(&config, 1, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        assert_eq!(end_time, now + chrono::Duration::minutes(10));
+        assert_eq!(status.phase(&config, now), VotePhase::Active { end_time });
+        assert_eq!(
+            status.start(&config, 1, ProposalAction::Reset, 2, 10, now),
+            Err(VoteError::AlreadyActive)
+        );
+
+        // Changing a ballot replaces it
+        status.cast(&config, 2, true, 0, now).unwrap();
+        status.cast(&config, 2, false, 0, now).unwrap();
+        assert_eq!(status.yes_votes, vec![1]);
+        assert_eq!(status.no_votes, vec![2]);
+
+        // Ballots are refused once time is up, but the vote still needs finishing
+        assert_eq!(status.phase(&config, end_time), VotePhase::Ended);
+        assert_eq!(
+            status.cast(&config, 3, true, 0, end_time),
+            Err(VoteError::Ended)
+        );
+        assert_eq!(
+            status.start(&config, 1, ProposalAction::Reset, 3, 10, end_time),
+            Err(VoteError::AlreadyActive)
+        );
+
+        let outcome = status.finish(&config, |_| 0, end_time).unwrap();
+        assert_eq!(
+            outcome,
+            VoteOutcome {
+                tally: tally(1, 1),
+                passed: true,
+            }
+        );
+        assert_eq!(
+            status.finish(&config, |_| 0, end_time),
+            Err(VoteError::NotActive)
+        );
+
+        // Cooldown, then idle again
+        let until = end_time + chrono::Duration::hours(1);
+        assert_eq!(
+            status.phase(&config, end_time),
+            VotePhase::Cooldown { until }
+        );
+        assert_eq!(
+            status.start(&config, 1, ProposalAction::Reset, 1, 10, end_time),
+            Err(VoteError::Cooldown)
+        );
+        assert_eq!(
+            status.cast(&config, 1, true, 0, end_time),
+            Err(VoteError::NotActive)
+        );
+        assert_eq!(status.phase(&config, until), VotePhase::Idle);
+        assert!(
+            status
+                .start(&config, 1, ProposalAction::Reset, 1, 10, until)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_cancel_and_extend() {
+        let config = config();
+        let now = Utc::now();
+        let mut status = VoteStatus::default();
+        let end_time = status
+            .start(&config, 1, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+
+        let later = status.extend(&config, 15, now).unwrap();
+        assert_eq!(later, end_time + chrono::Duration::minutes(15));
+        assert_eq!(
+            status.phase(&config, end_time),
+            VotePhase::Active { end_time: later }
+        );
+        assert_eq!(status.extend(&config, 5, later), Err(VoteError::Ended));
+
+        // A cancelled vote doesn't start the cooldown by default
+        status.cancel(&config, later).unwrap();
+        assert_eq!(status.phase(&config, later), VotePhase::Idle);
+        assert_eq!(status.cancel(&config, later), Err(VoteError::NotActive));
+        assert_eq!(status.extend(&config, 5, later), Err(VoteError::NotActive));
+
+        let config = VoteConfig {
+            cancel_cooldown: true,
+            ..config
+        };
+        status
+            .start(&config, 2, ProposalAction::Reset, 1, 10, later)
+            .unwrap();
+        status.cancel(&config, later).unwrap();
+        assert_eq!(
+            status.phase(&config, later),
+            VotePhase::Cooldown {
+                until: later + chrono::Duration::hours(1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_huge_settings() {
+        let mut config = config();
+        config.set(VoteField::CooldownHours, u32::MAX);
+        config.set(VoteField::DurationMinutes, u32::MAX);
+        config.set(VoteField::MajorityPercentage, 150);
+        assert_eq!(config.cooldown_hours, MAX_COOLDOWN_HOURS);
+        assert_eq!(config.duration_minutes, MAX_DURATION_MINUTES);
+        assert_eq!(config.majority_percentage, 100);
+
+        // Values saved before the caps saturate instead of panicking
+        let config = VoteConfig {
+            cooldown_hours: u32::MAX,
+            ..config
+        };
+        let status = VoteStatus {
+            last_vote_time: Some(DateTime::<Utc>::MAX_UTC - chrono::Duration::days(1)),
+            ..Default::default()
+        };
+        assert_eq!(status.cooldown_end(&config), Some(DateTime::<Utc>::MAX_UTC));
+    }
+
+    #[test]
+    fn test_secret_ballot() {
+        const VOTER: u64 = 123_456_789_012_345_678;
+        let config = VoteConfig {
+            secret: true,
+            weighting: VoteWeighting::Balance,
+            ..config()
+        };
+        let now = Utc::now();
+        let mut status = VoteStatus::default();
+        status
+            .start(&config, 1, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        assert!(status.is_secret());
+        // The initiator's ballot isn't given away by casting it for them
+        assert_eq!(status.ballots(), 0);
+        assert!(!status.has_voted(1));
+
+        // Changing a ballot replaces it instead of counting twice
+        status.cast(&config, VOTER, true, 25, now).unwrap();
+        status.cast(&config, VOTER, false, 25, now).unwrap();
+        status.cast(&config, 3, true, 5, now).unwrap();
+        assert_eq!(status.ballots(), 2);
+        assert!(status.yes_votes.is_empty() && status.no_votes.is_empty());
+
+        // Only salted hashes of the voters are stored
+        let salt = status.salt.clone().unwrap();
+        let hash = VoteStatus::voter_hash(&salt, VOTER);
+        assert!(status.secret_ballots.iter().any(|b| b.voter_hash == hash));
+        let yaml = serde_yaml::to_string(&status).unwrap();
+        assert!(!yaml.contains(&VOTER.to_string()));
+        assert!(status.has_voted(VOTER));
+        assert!(!status.has_voted(4));
+
+        // Ballots keep the weight they were cast with
+        let tally = status.tally(config.weighting, |_| 0);
+        assert_eq!(tally.yes_votes, 1);
+        assert_eq!(tally.no_votes, 1);
+        assert_eq!(tally.yes_weight, 5);
+        assert_eq!(tally.no_weight, 25);
+
+        // The same voter hashes differently in another vote
+        let mut other = VoteStatus::default();
+        other
+            .start(&config, 2, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        assert_ne!(other.salt, status.salt);
+        assert_ne!(
+            VoteStatus::voter_hash(other.salt.as_deref().unwrap(), VOTER),
+            hash
+        );
+
+        // The salt and hashes are gone once the vote closes
+        let outcome = status.finish(&config, |_| 0, now).unwrap();
+        assert_eq!(outcome.tally, tally);
+        assert!(status.salt.is_none() && status.secret_ballots.is_empty());
+        other.cancel(&config, now).unwrap();
+        assert!(other.salt.is_none());
+    }
+
+    #[test]
+    fn test_quorum() {
+        let quorum = Quorum::from_choice(QuorumKind::ActiveMembers, Some(25), Some(30)).unwrap();
+        assert_eq!(
+            quorum,
+            Quorum::ActiveMembers {
+                percent: 25,
+                days: 30
+            }
+        );
+        assert_eq!(
+            Quorum::from_choice(QuorumKind::Votes, None, None),
+            Some(Quorum::Votes)
+        );
+        // A percentage quorum needs a valid percentage and window
+        assert_eq!(
+            Quorum::from_choice(QuorumKind::ActiveMembers, Some(25), None),
+            None
+        );
+        assert_eq!(
+            Quorum::from_choice(QuorumKind::ActiveMembers, Some(0), Some(30)),
+            None
+        );
+        assert_eq!(
+            Quorum::from_choice(
+                QuorumKind::ActiveMembers,
+                Some(25),
+                Some(MAX_ACTIVITY_DAYS + 1)
+            ),
+            None
+        );
+
+        // The share of active members is rounded up, and never below one ballot
+        assert_eq!(quorum.required_votes(10, 40), 10);
+        assert_eq!(quorum.required_votes(10, 41), 11);
+        assert_eq!(quorum.required_votes(10, 2), 1);
+        assert_eq!(quorum.required_votes(10, 0), 1);
+        assert_eq!(Quorum::Votes.required_votes(10, 1000), 10);
+
+        let config = VoteConfig { quorum, ..config() };
+        let resolved = config.with_quorum(12);
+        assert_eq!(resolved.min_votes, 3);
+        assert!(!resolved.passes(&tally(2, 0)));
+        assert!(resolved.passes(&tally(3, 0)));
+    }
+
+    #[test]
+    fn test_reminder_time() {
+        let config = VoteConfig {
+            reminder_minutes: Some(4),
+            ..config()
+        };
+        let now = Utc::now();
+        let mut status = VoteStatus::default();
+        assert_eq!(status.reminder_time(&config), None);
+
+        let end_time = status
+            .start(&config, 1, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        let due = end_time - chrono::Duration::minutes(4);
+        assert_eq!(status.reminder_time(&config), Some(due));
+        assert!(status.has_voted(1));
+        assert!(!status.has_voted(2));
+
+        // Sent reminders aren't due again until the vote is extended
+        status.reminded = true;
+        assert_eq!(status.reminder_time(&config), None);
+        status.extend(&config, 5, now).unwrap();
+        assert_eq!(
+            status.reminder_time(&config),
+            Some(due + chrono::Duration::minutes(5))
+        );
+
+        // No reminder for votes shorter than the reminder, or without one
+        let early = VoteConfig {
+            reminder_minutes: Some(60),
+            ..config.clone()
+        };
+        assert_eq!(status.reminder_time(&early), None);
+        let none = VoteConfig {
+            reminder_minutes: None,
+            ..config.clone()
+        };
+        assert_eq!(status.reminder_time(&none), None);
+
+        // Cancelled votes don't remind
+        status.cancel(&config, now).unwrap();
+        assert_eq!(status.reminder_time(&config), None);
+    }
+
+    #[test]
+    fn test_vote_stats() {
+        let config = config();
+        let now = Utc::now();
+        let record = |id, yes_votes: Vec<u64>, no_votes: Vec<u64>, result| VoteRecord {
+            proposal_id: id,
+            action: ProposalAction::Reset,
+            initiator_id: Some(1),
+            start_time: Some(now),
+            closed_at: now,
+            tally: tally(yes_votes.len(), no_votes.len()),
+            yes_votes,
+            no_votes,
+            config: config.clone(),
+            result,
+        };
+        let records = [
+            record(1, vec![1, 2, 3], vec![], VoteResult::Passed),
+            record(2, vec![1], vec![2], VoteResult::Failed),
+            record(3, vec![1], vec![4], VoteResult::Failed),
+            // Cancelled votes don't count toward the rates
+            record(4, vec![5, 6, 7, 8], vec![], VoteResult::Cancelled),
+        ];
+
+        let stats = VoteStats::from_records(&records, 2);
+        assert_eq!((stats.passed, stats.failed, stats.cancelled), (1, 2, 1));
+        assert!((stats.pass_rate - 100.0 / 3.0).abs() < 1e-9);
+        assert!((stats.average_turnout - 7.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.top_voters, vec![(1, 3), (2, 2)]);
+
+        assert_eq!(VoteStats::from_records(&[], 5), VoteStats::default());
+    }
+
+    #[test]
+    fn test_passes() {
+        let config = config();
+        assert!(!config.passes(&tally(0, 0)));
+        // Below the minimum number of votes
+        assert!(!config.passes(&tally(1, 0)));
+        assert!(config.passes(&tally(1, 1)));
+        assert!(!config.passes(&tally(1, 2)));
+
+        let no_minimum = VoteConfig {
+            min_votes: 0,
+            ..config.clone()
+        };
+        assert!(!no_minimum.passes(&tally(0, 0)));
+
+        // Enough ballots, but none of them carry weight
+        let weightless = Tally {
+            yes_weight: 0,
+            no_weight: 0,
+            ..tally(2, 1)
+        };
+        assert!(!config.passes(&weightless));
+    }
+
+    #[test]
+    fn test_reset_modes() {
+        let balances = BTreeMap::from([(1, 100), (2, 7), (3, 0)]);
+
+        let (after, treasury) = ResetMode::Zero.apply(&balances);
+        assert_eq!(after, BTreeMap::from([(1, 0), (2, 0), (3, 0)]));
+        assert_eq!(treasury, 0);
+
+        let (after, _) = ResetMode::Scale { percent: 50 }.apply(&balances);
+        assert_eq!(after, BTreeMap::from([(1, 50), (2, 3), (3, 0)]));
+
+        let (after, _) = ResetMode::Cap { max: 10 }.apply(&balances);
+        assert_eq!(after, BTreeMap::from([(1, 10), (2, 7), (3, 0)]));
+
+        // 107 coins over 3 users leaves 2 for the treasury
+        let (after, treasury) = ResetMode::Redistribute.apply(&balances);
+        assert_eq!(after, BTreeMap::from([(1, 35), (2, 35), (3, 35)]));
+        assert_eq!(treasury, 2);
+
+        let (after, treasury) = ResetMode::Tax { percent: 10 }.apply(&balances);
+        assert_eq!(after, BTreeMap::from([(1, 90), (2, 7), (3, 0)]));
+        assert_eq!(treasury, 10);
+
+        // Percentages above 100 and missing amounts are refused
+        assert_eq!(
+            ResetMode::from_choice(ResetModeKind::Scale, Some(101)),
+            None
+        );
+        assert_eq!(ResetMode::from_choice(ResetModeKind::Cap, None), None);
+        assert_eq!(
+            ResetMode::from_choice(ResetModeKind::Tax, Some(5)),
+            Some(ResetMode::Tax { percent: 5 })
+        );
+        assert_eq!(
+            ProposalAction::reset(ResetMode::Zero),
+            ProposalAction::Reset
+        );
+    }
+
+    #[test]
+    fn test_weighting() {
+        let config = config();
+        let now = Utc::now();
+        let mut status = VoteStatus::default();
+        status
+            .start(&config, 1, ProposalAction::Reset, 1, 10, now)
+            .unwrap();
+        status.cast(&config, 2, false, 0, now).unwrap();
+        status.cast(&config, 3, false, 0, now).unwrap();
+
+        // One whale against two small holders
+        let balance = |user_id: u64| if user_id == 1 { 100 } else { 4 };
+
+        let one_person = status.tally(VoteWeighting::OnePersonOneVote, balance);
+        assert_eq!(one_person, tally(1, 2));
+        assert!(!config.passes(&one_person));
+
+        let by_balance = status.tally(VoteWeighting::Balance, balance);
+        assert_eq!((by_balance.yes_weight, by_balance.no_weight), (100, 8));
+        assert!(config.passes(&by_balance));
+
+        let quadratic = status.tally(VoteWeighting::Quadratic, balance);
+        assert_eq!((quadratic.yes_weight, quadratic.no_weight), (10, 4));
+
+        // Finishing uses the configured weighting
+        let config = VoteConfig {
+            weighting: VoteWeighting::Balance,
+            ..config
+        };
+        let outcome = status.finish(&config, balance, now).unwrap();
+        assert_eq!(outcome.tally, by_balance);
+        assert!(outcome.passed);
+    }
+
+    #[test]
+    fn test_eligibility() {
+        let now = Utc::now();
+        let voter = Voter {
+            user_id: 1,
+            account_created: now - chrono::Duration::days(10),
+            joined_at: Some(now - chrono::Duration::days(2)),
+            roles: vec![5],
+        };
+
+        // No rules by default
+        let mut config = config();
+        assert_eq!(config.check_eligibility(&voter, 0, now), Ok(()));
+
+        config.min_account_age_days = 30;
+        assert_eq!(
+            config.check_eligibility(&voter, 0, now),
+            Err(VoteError::AccountTooNew { days: 30 })
+        );
+        config.min_account_age_days = 10;
+
+        config.min_member_days = 3;
+        assert_eq!(
+            config.check_eligibility(&voter, 0, now),
+            Err(VoteError::JoinedTooRecently { days: 3 })
+        );
+        // Members with an unknown join date are refused too
+        let unknown_join = Voter {
+            joined_at: None,
+            ..voter.clone()
+        };
+        config.min_member_days = 1;
+        assert!(config.check_eligibility(&unknown_join, 0, now).is_err());
+        assert_eq!(config.check_eligibility(&voter, 0, now), Ok(()));
+
+        config.required_role = Some(6);
+        assert_eq!(
+            config.check_eligibility(&voter, 0, now),
+            Err(VoteError::MissingRole { role_id: 6 })
+        );
+        config.required_role = Some(5);
+
+        config.min_balance = 50;
+        assert_eq!(
+            config.check_eligibility(&voter, 49, now),
+            Err(VoteError::BalanceTooLow { min_balance: 50 })
+        );
+        assert_eq!(config.check_eligibility(&voter, 50, now), Ok(()));
+    }
+}